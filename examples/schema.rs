@@ -0,0 +1,7 @@
+//! Prints the JSON Schema of `hyte::record::Record`, which is published as `schema/record.v1.json`.
+//!
+//! Run with `cargo run --example schema --features schema > schema/record.v1.json`.
+
+fn main() {
+    println!("{}", serde_json::to_string_pretty(&hyte::record::schema()).unwrap());
+}
@@ -0,0 +1,609 @@
+//! Module responsible for all items needed to compute Bayes factors for T-tests and to compare proportions and means
+//! with conjugate Bayesian models.
+//!
+//! Bayes factors use the JZS prior of Rouder et al. (2009), a Cauchy prior on the standardised effect size `δ`, and
+//! are computed from the same T statistic and sample sizes as the `t` module. Proportions are compared with a
+//! Beta-Binomial model, and means with a Normal-Gamma model, each returning the probability that B beats A, the
+//! expected loss of choosing either, and credible intervals.
+
+use std::f64::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+use statrs::distribution::{Continuous, ContinuousCDF, Normal, StudentsT};
+use statrs::function::beta::{beta_reg, inv_beta_reg};
+use crate::report::{self, Report, Reportable};
+use crate::t;
+use crate::utils::{mean, variance, MissingPolicy, Observation, Tails, ToPrimitive, clean, to_f64, TestType};
+
+/// The "medium" scale of the Cauchy prior on `δ`, `√2 / 2`, which is the default of most software.
+pub const MEDIUM: f64 = FRAC_1_SQRT_2;
+/// The "wide" scale of the Cauchy prior on `δ`, 1.
+pub const WIDE: f64 = 1.0;
+/// The "ultrawide" scale of the Cauchy prior on `δ`, `√2`.
+pub const ULTRAWIDE: f64 = SQRT_2;
+
+/// Spacing and furthest extent of the normal scores at which the posterior of A or B is evaluated when comparing them.
+const QUADRATURE_STEP: f64 = 0.01;
+const QUADRATURE_BOUND: f64 = 8.0;
+
+/// Test type names of each Bayes factor, comparison, and posterior.
+const ONE_SAMPLE: TestType = "(1-Sample) JZS Bayes Factor for Mean";
+const TWO_SAMPLE: TestType = "(2-Sample) JZS Bayes Factor for Mean";
+const PAIRED: TestType = "(Paired) JZS Bayes Factor for Mean Difference";
+const BETA_BINOMIAL: TestType = "Beta-Binomial Comparison of Proportions";
+const NORMAL_GAMMA_POSTERIOR: TestType = "Normal-Gamma Posterior of Mean";
+const NORMAL_GAMMA_COMPARISON: TestType = "Normal-Gamma Comparison of Means";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[ONE_SAMPLE, TWO_SAMPLE, PAIRED, BETA_BINOMIAL, NORMAL_GAMMA_POSTERIOR, NORMAL_GAMMA_COMPARISON]];
+
+/// A struct for storing the resulting Bayes factor from JZS Bayes factor T-tests.
+///
+/// `bf10` is the Bayes factor in favour of the alternative hypothesis, and `log_bf10` is its natural logarithm,
+/// accurate when `bf10` overflows. `prior_scale` is the scale of the Cauchy prior on `δ` under the alternative.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BayesFactorResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub df: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub bf10: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_bf10: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub prior_scale: f64,
+    pub hypotheses: String,
+    pub omitted: usize,
+}
+
+/// A struct for storing the comparison of the posteriors of two variants, A and B.
+///
+/// `probability_b_better` is the posterior probability that B's parameter exceeds A's. `expected_loss_a` is the
+/// expected amount by which B's parameter exceeds A's, counting only when it does, which is lost by choosing A;
+/// `expected_loss_b` is the same for choosing B. Credible intervals are equal-tailed.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ComparisonResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate_a: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate_b: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub credible_interval_a: (f64, f64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub credible_interval_b: (f64, f64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub credible_level: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub probability_b_better: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub expected_loss_a: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub expected_loss_b: f64,
+    pub omitted: usize,
+}
+
+/// A struct for storing the posterior of the mean of a sample under a Normal-Gamma model.
+///
+/// `estimate` is the posterior mean of `μ`, and `posterior` holds the updated parameters of the model.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PosteriorResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub credible_interval: (f64, f64),
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub credible_level: f64,
+    pub posterior: NormalGamma,
+    pub omitted: usize,
+}
+
+/// A Normal-Gamma distribution over the mean `μ` and precision `τ = 1 / σ²` of normally distributed data,
+/// with `τ ~ Gamma(alpha, beta)` (shape and rate) and `μ | τ ~ Normal(mean, 1 / (kappa * τ))`.
+///
+/// As a prior, `mean` is the prior guess of `μ`, worth `kappa` observations, and `alpha` and `beta` are worth
+/// `2 * alpha` observations with a sum of squared deviations of `2 * beta`. The marginal of `μ` is a Student's T
+/// distribution with `2 * alpha` degrees of freedom, centred at `mean`, with scale `√(beta / (alpha * kappa))`.
+///
+/// # Examples
+/// ```
+/// use hyte::bayes::NormalGamma;
+///
+/// fn main() {
+///     let prior = NormalGamma::new(10.0, 1.0, 1.0, 4.0);
+///
+///     assert_eq!(prior.kappa, 1.0);
+///     assert_eq!(NormalGamma::reference(), NormalGamma::new(0.0, 0.0, -0.5, 0.0));
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NormalGamma {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub mean: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub kappa: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub alpha: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub beta: f64,
+}
+
+impl NormalGamma {
+    /// Creates a Normal-Gamma distribution. Panics unless `kappa` and `beta` are at least 0 and `alpha` is at least -0.5.
+    pub fn new(mean: f64, kappa: f64, alpha: f64, beta: f64) -> NormalGamma {
+        if !(kappa >= 0.0 && alpha >= -0.5 && beta >= 0.0) {
+            panic!("\n[HYTE-Panic] A Normal-Gamma prior requires kappa and beta of at least 0, and alpha of at least -0.5!\n");
+        }
+        NormalGamma { mean, kappa, alpha, beta }
+    }
+
+    /// The improper reference prior, under which the credible interval of `μ` is the confidence interval of the T-test.
+    pub fn reference() -> NormalGamma {
+        NormalGamma { mean: 0.0, kappa: 0.0, alpha: -0.5, beta: 0.0 }
+    }
+
+    /// Returns the posterior after observing `data`.
+    fn update(&self, data: &[f64]) -> NormalGamma {
+        let n = data.len() as f64;
+        let data_mean = mean(data).unwrap();
+        let squares: f64 = data.iter().map(|x| (x - data_mean).powi(2)).sum();
+        let kappa = self.kappa + n;
+        NormalGamma {
+            mean: (self.kappa * self.mean + n * data_mean) / kappa,
+            kappa,
+            alpha: self.alpha + n / 2.0,
+            beta: self.beta + squares / 2.0 + self.kappa * n * (data_mean - self.mean).powi(2) / (2.0 * kappa),
+        }
+    }
+
+    /// Returns the marginal distribution of `μ`.
+    fn marginal(&self) -> Posterior {
+        if self.beta <= 0.0 { panic!("\n[HYTE-Panic] The data must vary when the prior has a beta of 0!\n") };
+        Posterior::StudentsT(self.mean, (self.beta / (self.alpha * self.kappa)).sqrt(), 2.0 * self.alpha)
+    }
+}
+
+/// The posterior of a parameter of one variant.
+enum Posterior {
+    /// `Beta(alpha, beta)`.
+    Beta(f64, f64),
+    /// Student's T distribution with a location, scale, and degrees of freedom.
+    StudentsT(f64, f64, f64),
+}
+
+impl Posterior {
+    fn mean(&self) -> f64 {
+        match *self {
+            Posterior::Beta(alpha, beta) => alpha / (alpha + beta),
+            Posterior::StudentsT(location, _, _) => location,
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        match *self {
+            Posterior::Beta(alpha, beta) => beta_reg(alpha, beta, x.clamp(0.0, 1.0)),
+            Posterior::StudentsT(location, scale, df) => StudentsT::new(0.0, 1.0, df).unwrap().cdf((x - location) / scale),
+        }
+    }
+
+    fn quantile(&self, probability: f64) -> f64 {
+        match *self {
+            Posterior::Beta(alpha, beta) => inv_beta_reg(alpha, beta, probability),
+            Posterior::StudentsT(location, scale, df) => location + scale * StudentsT::new(0.0, 1.0, df).unwrap().inverse_cdf(probability),
+        }
+    }
+
+    /// Returns `∫ cdf` from the lowest value up to `x`, which is the expected amount by which `x` exceeds the parameter.
+    fn partial_expectation(&self, x: f64) -> f64 {
+        match *self {
+            Posterior::Beta(alpha, beta) => {
+                let x = x.clamp(0.0, 1.0);
+                x * beta_reg(alpha, beta, x) - self.mean() * beta_reg(alpha + 1.0, beta, x)
+            },
+            Posterior::StudentsT(location, scale, df) => {
+                let standard = StudentsT::new(0.0, 1.0, df).unwrap();
+                let z = (x - location) / scale;
+                scale * (z * standard.cdf(z) + (df + z * z) / (df - 1.0) * standard.pdf(z))
+            },
+        }
+    }
+
+    fn credible_interval(&self, credible_level: f64) -> (f64, f64) {
+        let alpha = (1.0 - credible_level) / 2.0;
+        (self.quantile(alpha), self.quantile(1.0 - alpha))
+    }
+}
+
+/// Returns `P(y > x)` and the expected amount by which `x` exceeds `y`, by averaging over the quantiles of `x`
+/// at evenly spaced normal scores, weighted by the normal density, which suits posteriors that are nearly normal.
+fn expectations(x: &Posterior, y: &Posterior) -> (f64, f64) {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let nodes = (2.0 * QUADRATURE_BOUND / QUADRATURE_STEP).round() as usize;
+    let (mut weights, mut probability, mut shortfall) = (0.0, 0.0, 0.0);
+    for node in 0..=nodes {
+        let score = -QUADRATURE_BOUND + node as f64 * QUADRATURE_STEP;
+        let (weight, value) = (normal.pdf(score), x.quantile(normal.cdf(score)));
+        weights += weight;
+        probability += weight * (1.0 - y.cdf(value));
+        shortfall += weight * y.partial_expectation(value);
+    }
+    (probability / weights, shortfall / weights)
+}
+
+fn compare(a: Posterior, b: Posterior, credible_level: f64, test_type: TestType, omitted: usize) -> ComparisonResult {
+    if !(0.0..1.0).contains(&credible_level) { panic!("\n[HYTE-Panic] Credible level must be in the interval [0, 1)!\n") };
+    let spread = |posterior: &Posterior| posterior.quantile(0.75) - posterior.quantile(0.25);
+    let difference = b.mean() - a.mean();
+    // Rounding can leave a loss of 0 slightly negative
+    let non_negative = |loss: f64| if loss < 0.0 { 0.0 } else { loss };
+    // Averaging over the narrower posterior keeps the integrand smooth
+    let (probability_b_better, expected_loss_a, expected_loss_b) = if spread(&a) <= spread(&b) {
+        let (probability, loss_b) = expectations(&a, &b);
+        (probability, loss_b + difference, loss_b)
+    } else {
+        let (probability, loss_a) = expectations(&b, &a);
+        (1.0 - probability, loss_a, loss_a - difference)
+    };
+
+    ComparisonResult {
+        test_type,
+        estimate_a: a.mean(),
+        estimate_b: b.mean(),
+        credible_interval_a: a.credible_interval(credible_level),
+        credible_interval_b: b.credible_interval(credible_level),
+        credible_level,
+        probability_b_better,
+        expected_loss_a: non_negative(expected_loss_a),
+        expected_loss_b: non_negative(expected_loss_b),
+        omitted,
+    }
+}
+
+/// Returns the natural logarithm of the JZS Bayes factor of a T statistic with `df` degrees of freedom, where the
+/// standardised effect is estimated from `effective_size` observations, by integrating over the variance `g` of the
+/// normal prior on `δ`, which has an inverse-gamma prior, with the substitution `g = eᵘ`.
+fn log_jzs(statistic: f64, effective_size: f64, df: f64, prior_scale: f64) -> f64 {
+    if statistic.is_nan() { return f64::NAN };
+    let t_squared = statistic * statistic;
+    let null = (df + 1.0) / 2.0 * (t_squared / df).ln_1p();
+    let log_integrand = |u: f64| {
+        let g = u.exp();
+        let spread = effective_size * g;
+        -0.5 * spread.ln_1p() - (df + 1.0) / 2.0 * (t_squared / ((1.0 + spread) * df)).ln_1p() + null
+            + prior_scale.ln() - 0.5 * (2.0 * PI).ln() - 0.5 * u - prior_scale * prior_scale / (2.0 * g)
+    };
+    let step = 0.02;
+    let values: Vec<f64> = (0..5000).map(|index| log_integrand(-40.0 + step * index as f64)).collect();
+    let largest = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    largest + (values.iter().map(|value| (value - largest).exp()).sum::<f64>() * step).ln()
+}
+
+/// Formats a prior scale, such as `0.707` or `1`, for the statement of the hypotheses.
+fn scale_text(prior_scale: f64) -> String {
+    let text = format!("{:.3}", prior_scale);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn bayes_factor(statistic: f64, effective_size: f64, df: f64, prior_scale: f64, test_type: TestType, omitted: usize) -> BayesFactorResult {
+    if prior_scale.is_nan() || prior_scale <= 0.0 { panic!("\n[HYTE-Panic] The prior scale must be greater than 0!\n") };
+    let log_bf10 = log_jzs(statistic, effective_size, df, prior_scale);
+    BayesFactorResult {
+        test_type,
+        statistic,
+        df,
+        bf10: log_bf10.exp(),
+        log_bf10,
+        prior_scale,
+        hypotheses: format!("H₀: δ = 0 vs H₁: δ ~ Cauchy(0, {})", scale_text(prior_scale)),
+        omitted,
+    }
+}
+
+/// Computes the JZS Bayes factor of a 1-sample or paired T-test from its T statistic and sample size (the number of pairs).
+///
+/// This is the Bayes factor for the statistic of `t::test` or `t::test_paired`. The sample size must be at least 2.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes;
+///
+/// fn main() {
+///     let results = bayes::t_test_dataless(4.0621, 10, bayes::MEDIUM).unwrap();
+///
+///     assert_eq!(results.df, 9.0);
+///     assert!((results.bf10 - 17.26).abs() < 0.01);
+/// }
+/// ```
+pub fn t_test_dataless(statistic: f64, sample_size: impl ToPrimitive, prior_scale: f64) -> Option<BayesFactorResult> {
+    let n = to_f64(sample_size);
+    if n < 2.0 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    Some(bayes_factor(statistic, n, n - 1.0, prior_scale, ONE_SAMPLE, 0))
+}
+
+/// Computes the JZS Bayes factor of a 2-sample T-test from its pooled-variance T statistic and the two sample sizes.
+///
+/// The JZS prior assumes that both samples share a variance, so `statistic` is Student's rather than Welch's T
+/// statistic. Each sample size must be at least 2.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes;
+///
+/// fn main() {
+///     let results = bayes::t_test_two_samples_dataless(-1.8608, 10, 10, bayes::MEDIUM).unwrap();
+///
+///     assert_eq!(results.test_type, "(2-Sample) JZS Bayes Factor for Mean");
+///     assert!((results.bf10 - 1.266).abs() < 0.001);
+/// }
+/// ```
+pub fn t_test_two_samples_dataless(statistic: f64, sample_size1: impl ToPrimitive, sample_size2: impl ToPrimitive, prior_scale: f64) -> Option<BayesFactorResult> {
+    let (n1, n2) = (to_f64(sample_size1), to_f64(sample_size2));
+    if n1 < 2.0 || n2 < 2.0 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    Some(bayes_factor(statistic, n1 * n2 / (n1 + n2), n1 + n2 - 2.0, prior_scale, TWO_SAMPLE, 0))
+}
+
+/// Computes the JZS Bayes factor for the mean of a sample, given `expected_mean` under the null hypothesis, where
+/// `δ = (μ - expected_mean) / σ`.
+///
+/// `prior_scale` is the scale of the Cauchy prior on `δ`, such as `bayes::MEDIUM`. Unlike a p-value, the Bayes factor
+/// can also quantify evidence for the null hypothesis, when `bf10` is below 1. The sample must contain at least 2 observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+///     let results = bayes::t_test(data, 3, bayes::MEDIUM, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.hypotheses, "H₀: δ = 0 vs H₁: δ ~ Cauchy(0, 0.707)");
+///     // Too little data to favour either hypothesis
+///     assert!(results.bf10 > 1.0 / 3.0 && results.bf10 < 3.0);
+/// }
+/// ```
+pub fn t_test<Number: Observation, Number2: ToPrimitive>(data: impl AsRef<[Number]>, expected_mean: Number2, prior_scale: f64, missing: MissingPolicy) -> Option<BayesFactorResult> {
+    let results = t::test(data, expected_mean, Tails::BOTH, missing)?;
+    let mut bayes = t_test_dataless(results.statistic, results.df + 1.0, prior_scale)?;
+    bayes.omitted = results.omitted;
+    Some(bayes)
+}
+
+/// Computes the JZS Bayes factor for the mean difference of paired observations, where `δ` is the standardised mean difference.
+///
+/// Pairs with a missing observation are omitted together under `MissingPolicy::Omit`. There must be at least 2 pairs.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let before = vec![0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+///     let after = vec![1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+///     let results = bayes::t_test_paired(after, before, bayes::MEDIUM, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "(Paired) JZS Bayes Factor for Mean Difference");
+///     assert!((results.bf10 - 17.26).abs() < 0.01);
+/// }
+/// ```
+pub fn t_test_paired<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, prior_scale: f64, missing: MissingPolicy) -> Option<BayesFactorResult> {
+    let results = t::test_paired(data1, data2, Tails::BOTH, missing)?;
+    let mut bayes = t_test_dataless(results.statistic, results.df + 1.0, prior_scale)?;
+    bayes.test_type = PAIRED;
+    bayes.omitted = results.omitted;
+    Some(bayes)
+}
+
+/// Computes the JZS Bayes factor for the difference between the means of two independent samples, where
+/// `δ = (μ₁ - μ₂) / σ` and both samples share the variance `σ²`.
+///
+/// Each sample must contain at least 2 observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let group1 = vec![0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+///     let group2 = vec![1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+///     let results = bayes::t_test_two_samples(group1, group2, bayes::MEDIUM, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.df, 18.0);
+///     assert!((results.bf10 - 1.266).abs() < 0.001);
+/// }
+/// ```
+pub fn t_test_two_samples<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, prior_scale: f64, missing: MissingPolicy) -> Option<BayesFactorResult> {
+    let (data1, omitted1) = clean(data1.as_ref(), missing);
+    let (data2, omitted2) = clean(data2.as_ref(), missing);
+    if data1.is_empty() || data2.is_empty() { return None };
+    if data1.len() < 2 || data2.len() < 2 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    let (n1, n2) = (data1.len() as f64, data2.len() as f64);
+    let pooled = ((n1 - 1.0) * variance(&data1).unwrap() + (n2 - 1.0) * variance(&data2).unwrap()) / (n1 + n2 - 2.0);
+    let statistic = (mean(&data1).unwrap() - mean(&data2).unwrap()) / (pooled * (1.0 / n1 + 1.0 / n2)).sqrt();
+    let mut bayes = t_test_two_samples_dataless(statistic, n1, n2, prior_scale)?;
+    bayes.omitted = omitted1 + omitted2;
+    Some(bayes)
+}
+
+fn validate_counts(successes: u64, trials: u64) {
+    if successes > trials { panic!("\n[HYTE-Panic] Number of successes cannot exceed the number of trials!\n") };
+}
+
+/// Compares the proportions of successes of two variants, A and B, with a conjugate Beta-Binomial model.
+///
+/// Both proportions have the prior `Beta(prior.0, prior.1)`, such as `(1.0, 1.0)` for a uniform prior, so their
+/// posteriors are `Beta(prior.0 + successes, prior.1 + trials - successes)`. The probability that B beats A and the
+/// expected losses are integrated numerically. Returns `None` if either variant has no trials.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes;
+///
+/// fn main() {
+///     let results = bayes::beta_binomial(120, 1000, 145, 1000, (1.0, 1.0), 0.95).unwrap();
+///
+///     assert_eq!(results.estimate_b, 146.0 / 1002.0);
+///     assert!(results.probability_b_better > 0.95);
+///     assert!(results.expected_loss_b < results.expected_loss_a);
+/// }
+/// ```
+pub fn beta_binomial(successes_a: u64, trials_a: u64, successes_b: u64, trials_b: u64, prior: (f64, f64), credible_level: f64) -> Option<ComparisonResult> {
+    validate_counts(successes_a, trials_a);
+    validate_counts(successes_b, trials_b);
+    if !(prior.0 > 0.0 && prior.1 > 0.0) { panic!("\n[HYTE-Panic] The parameters of a Beta prior must be greater than 0!\n") };
+    if trials_a == 0 || trials_b == 0 { return None };
+    let posterior = |successes: u64, trials: u64| Posterior::Beta(prior.0 + successes as f64, prior.1 + (trials - successes) as f64);
+    Some(compare(posterior(successes_a, trials_a), posterior(successes_b, trials_b), credible_level, BETA_BINOMIAL, 0))
+}
+
+/// Updates a Normal-Gamma `prior` with a sample, and returns the posterior mean and credible interval of `μ`.
+///
+/// Under `NormalGamma::reference()`, the credible interval is the confidence interval of the 1-sample T-test.
+/// The sample must contain at least 2 observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes::{self, NormalGamma};
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![9.8, 10.4, 10.1, 9.6, 10.3, 10.0, 9.9, 10.5];
+///     let results = bayes::normal_gamma(&data, NormalGamma::new(10.0, 1.0, 1.0, 0.1), 0.95, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.posterior.kappa, 9.0);
+///     assert!(results.credible_interval.0 < 10.0 && 10.0 < results.credible_interval.1);
+/// }
+/// ```
+pub fn normal_gamma<Number: Observation>(data: impl AsRef<[Number]>, prior: NormalGamma, credible_level: f64, missing: MissingPolicy) -> Option<PosteriorResult> {
+    if !(0.0..1.0).contains(&credible_level) { panic!("\n[HYTE-Panic] Credible level must be in the interval [0, 1)!\n") };
+    let (data, omitted) = clean(data.as_ref(), missing);
+    if data.is_empty() { return None };
+    if data.len() < 2 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    let posterior = NormalGamma::new(prior.mean, prior.kappa, prior.alpha, prior.beta).update(&data);
+    let marginal = posterior.marginal();
+
+    Some(PosteriorResult {
+        test_type: NORMAL_GAMMA_POSTERIOR,
+        estimate: marginal.mean(),
+        credible_interval: marginal.credible_interval(credible_level),
+        credible_level,
+        posterior,
+        omitted,
+    })
+}
+
+/// Compares the means of two variants, A and B, by updating the same Normal-Gamma `prior` with each sample.
+///
+/// Each variant has its own variance. The probability that B beats A and the expected losses are integrated
+/// numerically from the Student's T marginal posteriors of the two means; an expected loss is infinite when a
+/// marginal has only 1 degree of freedom. Each sample must contain at least 2 observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bayes::{self, NormalGamma};
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let order_values_a = vec![31.5, 28.0, 35.2, 30.1, 27.9, 33.4, 29.8, 32.0, 30.7, 28.6];
+///     let order_values_b = vec![33.1, 36.4, 31.9, 35.0, 34.2, 32.8, 37.5, 33.9, 35.6, 34.0];
+///     let results = bayes::normal_gamma_compare(order_values_a, order_values_b, NormalGamma::reference(), 0.95, MissingPolicy::Propagate).unwrap();
+///
+///     assert!(results.probability_b_better > 0.99);
+/// }
+/// ```
+pub fn normal_gamma_compare<Number: Observation>(data_a: impl AsRef<[Number]>, data_b: impl AsRef<[Number]>, prior: NormalGamma, credible_level: f64, missing: MissingPolicy) -> Option<ComparisonResult> {
+    let a = normal_gamma(data_a, prior, credible_level, missing)?;
+    let b = normal_gamma(data_b, prior, credible_level, missing)?;
+    Some(compare(a.posterior.marginal(), b.posterior.marginal(), credible_level, NORMAL_GAMMA_COMPARISON, a.omitted + b.omitted))
+}
+
+/// Formats a Bayes factor, in scientific notation when it is very large or very small.
+fn factor(bf: f64) -> String {
+    if (0.001..1000.0).contains(&bf) { format!("{:.3}", bf) } else { format!("{:.3e}", bf) }
+}
+
+impl Reportable for BayesFactorResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("T test statistic", format!("{:.2}", self.statistic))
+            .row("Degrees of freedom", report::df(self.df))
+            .row("Bayes factor (BF₁₀)", factor(self.bf10))
+            .row("Prior scale", format!("{:.3}", self.prior_scale))
+            .apa(format!("t({}) = {:.2}, BF₁₀ = {}", report::df(self.df), self.statistic, factor(self.bf10)))
+    }
+}
+
+impl Reportable for ComparisonResult {
+    fn report(&self) -> Report {
+        let level = format!("{}%", self.credible_level * 100.0);
+        let interval = |(lower, upper): (f64, f64)| format!("[{:.4}, {:.4}]", lower, upper);
+        Report::new(self.test_type)
+            .row("Estimate of A", format!("{:.4}", self.estimate_a))
+            .row("Estimate of B", format!("{:.4}", self.estimate_b))
+            .row(&format!("{} credible interval of A", level), interval(self.credible_interval_a))
+            .row(&format!("{} credible interval of B", level), interval(self.credible_interval_b))
+            .row("P(B > A)", format!("{:.4}", self.probability_b_better))
+            .row("Expected loss of choosing A", format!("{:.4e}", self.expected_loss_a))
+            .row("Expected loss of choosing B", format!("{:.4e}", self.expected_loss_b))
+            .apa(format!("P(B > A) = {}", report::bounded(self.probability_b_better, 3)))
+    }
+}
+
+impl Reportable for PosteriorResult {
+    fn report(&self) -> Report {
+        let level = format!("{}%", self.credible_level * 100.0);
+        let (lower, upper) = self.credible_interval;
+        Report::new(self.test_type)
+            .row("Posterior mean", format!("{:.4}", self.estimate))
+            .row(&format!("{} credible interval", level), format!("[{:.4}, {:.4}]", lower, upper))
+            .row("Posterior degrees of freedom", report::df(2.0 * self.posterior.alpha))
+            .apa(format!("M = {:.2}, {} CrI [{:.2}, {:.2}]", self.estimate, level, lower, upper))
+    }
+}
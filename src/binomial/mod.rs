@@ -0,0 +1,289 @@
+//! Module responsible for all items needed to conduct exact binomial tests and construct confidence intervals for a proportion.
+
+use statrs::distribution::{Binomial, ContinuousCDF, Discrete, DiscreteCDF, Normal};
+use statrs::function::beta::inv_beta_reg;
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Tails, Conclusion, PValue, conclude, ln_sum_exp, LOG_SPACE_THRESHOLD, TestType};
+
+/// Relative tolerance used to decide whether two outcomes are equally likely, as in R's `binom.test`.
+const RELATIVE_ERROR: f64 = 1e-7;
+
+/// Methods for constructing a confidence interval for a binomial proportion.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Interval {
+    /// Exact interval obtained by inverting the binomial test; conservative.
+    ClopperPearson,
+    /// Score interval obtained by inverting the normal-approximation test.
+    Wilson,
+    /// Wilson score interval with a continuity correction (Newcombe, 1998).
+    WilsonCorrected,
+    /// Wald interval around a proportion shrunk towards 0.5 by adding `z²/2` successes and failures.
+    AgrestiCoull,
+    /// Equal-tailed credible interval from the Jeffreys `Beta(0.5, 0.5)` prior.
+    Jeffreys,
+}
+
+/// A struct for storing the resulting p-value and confidence interval from an exact binomial test.
+///
+/// `statistic` is the number of successes and `estimate` is the observed proportion of successes.
+/// `log_p` is the natural logarithm of the p-value, which remains finite even when `p` underflows to 0.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BinomialResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    pub trials: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub confidence_interval: (f64, f64),
+    pub hypotheses: String,
+}
+
+/// Test type names of the exact binomial test, with and without the mid-p correction.
+const EXACT: TestType = "Exact Binomial Test";
+const MID_P: TestType = "Exact Binomial Test (Mid-p)";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[EXACT, MID_P]];
+
+/// Computes the lower and upper bounds of a confidence interval, where each bound on its own excludes
+/// at most `alpha` of the probability.
+fn bounds(successes: u64, trials: u64, method: Interval, alpha: f64) -> (f64, f64) {
+    let x = successes as f64;
+    let n = trials as f64;
+    let estimate = x / n;
+    let z = Normal::new(0.0, 1.0).unwrap().inverse_cdf(1.0 - alpha);
+    let z_squared = z * z;
+
+    let (lower, upper) = match method {
+        Interval::ClopperPearson => (
+            if successes == 0 { 0.0 } else { inv_beta_reg(x, n - x + 1.0, alpha) },
+            if successes == trials { 1.0 } else { inv_beta_reg(x + 1.0, n - x, 1.0 - alpha) },
+        ),
+        Interval::Wilson => {
+            let denominator = 1.0 + z_squared / n;
+            let centre = (estimate + z_squared / (2.0 * n)) / denominator;
+            let half_width = z / denominator * (estimate * (1.0 - estimate) / n + z_squared / (4.0 * n * n)).sqrt();
+            (centre - half_width, centre + half_width)
+        }
+        Interval::WilsonCorrected => {
+            let denominator = 2.0 * (n + z_squared);
+            let centre = 2.0 * n * estimate + z_squared;
+            let lower = if successes == 0 { 0.0 } else {
+                (centre - 1.0 - z * (z_squared - 2.0 - 1.0 / n + 4.0 * estimate * (n * (1.0 - estimate) + 1.0)).sqrt()) / denominator
+            };
+            let upper = if successes == trials { 1.0 } else {
+                (centre + 1.0 + z * (z_squared + 2.0 - 1.0 / n + 4.0 * estimate * (n * (1.0 - estimate) - 1.0)).sqrt()) / denominator
+            };
+            (lower, upper)
+        }
+        Interval::AgrestiCoull => {
+            let adjusted_n = n + z_squared;
+            let adjusted_estimate = (x + z_squared / 2.0) / adjusted_n;
+            let half_width = z * (adjusted_estimate * (1.0 - adjusted_estimate) / adjusted_n).sqrt();
+            (adjusted_estimate - half_width, adjusted_estimate + half_width)
+        }
+        Interval::Jeffreys => (
+            if successes == 0 { 0.0 } else { inv_beta_reg(x + 0.5, n - x + 0.5, alpha) },
+            if successes == trials { 1.0 } else { inv_beta_reg(x + 0.5, n - x + 0.5, 1.0 - alpha) },
+        ),
+    };
+    (lower.max(0.0), upper.min(1.0))
+}
+
+fn validate(successes: u64, trials: u64, confidence_level: f64) {
+    if successes > trials { panic!("\n[HYTE-Panic] Number of successes cannot exceed the number of trials!\n") };
+    if !(0.0..1.0).contains(&confidence_level) { panic!("\n[HYTE-Panic] Confidence level must be in the interval [0, 1)!\n") };
+}
+
+/// Constructs a two-sided confidence interval for a binomial proportion at `confidence_level` using the chosen `method`.
+/// Returns `None` if there are no trials.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::binomial::{self, Interval};
+///
+/// fn main() {
+///     let (lower, upper) = binomial::interval(81, 263, Interval::Wilson, 0.95).unwrap();
+///
+///     assert_eq!((lower * 1e4).round() / 1e4, 0.2553);
+///     assert_eq!((upper * 1e4).round() / 1e4, 0.3662);
+/// }
+/// ```
+pub fn interval(successes: u64, trials: u64, method: Interval, confidence_level: f64) -> Option<(f64, f64)> {
+    if trials == 0 { return None };
+    validate(successes, trials, confidence_level);
+    Some(bounds(successes, trials, method, (1.0 - confidence_level) / 2.0))
+}
+
+/// Natural logarithm of the exact p-value, summed over the logarithms of the probabilities of the outcomes
+/// for p-values too small to be represented as an `f64`.
+fn ln_exact_p(distribution: &Binomial, successes: u64, trials: u64, tail: &Tails, mid_p: bool) -> f64 {
+    let ln_observed = distribution.ln_pmf(successes);
+    let ln_tail = |outcomes: std::ops::RangeInclusive<u64>| ln_sum_exp(outcomes.map(|outcome| distribution.ln_pmf(outcome)));
+    let (ln_p, ln_ties) = match tail {
+        Tails::LOWER => (ln_tail(0..=successes), ln_observed),
+        Tails::UPPER => (ln_tail(successes..=trials), ln_observed),
+        Tails::BOTH => {
+            let (most, least) = (ln_observed + RELATIVE_ERROR.ln_1p(), ln_observed + (-RELATIVE_ERROR).ln_1p());
+            let included: Vec<f64> = (0..=trials).map(|outcome| distribution.ln_pmf(outcome)).filter(|&ln_probability| ln_probability <= most).collect();
+            let ln_ties = ln_sum_exp(included.iter().copied().filter(|&ln_probability| ln_probability >= least));
+            (ln_sum_exp(included), ln_ties)
+        }
+    };
+    if mid_p { ln_p + (-0.5 * (ln_ties - ln_p).exp()).ln_1p() } else { ln_p }
+}
+
+fn exact_test(successes: u64, trials: u64, expected_probability: f64, alternative: Alternative, mid_p: bool, interval: Interval, confidence_level: f64) -> Option<BinomialResult> {
+    if trials == 0 { return None };
+    validate(successes, trials, confidence_level);
+    if !(0.0..=1.0).contains(&expected_probability) { panic!("\n[HYTE-Panic] Expected probability must be between 0 and 1!\n") };
+    let alternative = alternative.with_null(expected_probability);
+    let tail = alternative.tails();
+
+    let distribution = Binomial::new(expected_probability, trials).unwrap();
+    let observed = distribution.pmf(successes);
+    let half_observed = if mid_p { 0.5 * observed } else { 0.0 };
+    let p = match tail {
+        Tails::LOWER => distribution.cdf(successes) - half_observed,
+        Tails::UPPER => (if successes == 0 { 1.0 } else { distribution.sf(successes - 1) }) - half_observed,
+        Tails::BOTH => {
+            // Minimum-likelihood method: sum every outcome no more likely than the observed one
+            let mut p = 0.0;
+            let mut ties = 0.0;
+            for outcome in 0..=trials {
+                let probability = distribution.pmf(outcome);
+                if probability <= observed * (1.0 + RELATIVE_ERROR) {
+                    p += probability;
+                    if probability >= observed * (1.0 - RELATIVE_ERROR) { ties += probability };
+                }
+            }
+            if mid_p { p -= 0.5 * ties };
+            p.min(1.0)
+        }
+    };
+    let log_p = if p > LOG_SPACE_THRESHOLD { p.ln() } else { ln_exact_p(&distribution, successes, trials, &tail, mid_p) };
+
+    let alpha = 1.0 - confidence_level;
+    let confidence_interval = match alternative.tails() {
+        Tails::LOWER => (0.0, bounds(successes, trials, interval, alpha).1),
+        Tails::UPPER => (bounds(successes, trials, interval, alpha).0, 1.0),
+        Tails::BOTH => bounds(successes, trials, interval, alpha / 2.0),
+    };
+
+    Some(BinomialResult {
+        test_type: if mid_p { MID_P } else { EXACT },
+        statistic: successes as f64,
+        trials,
+        estimate: successes as f64 / trials as f64,
+        p,
+        log_p,
+        confidence_interval,
+        hypotheses: alternative.statement("π"),
+    })
+}
+
+/// Performs an exact binomial test that the probability of success is `expected_probability`, given
+/// `successes` out of `trials`. Returns `None` if there are no trials.
+///
+/// The two-sided p-value uses the minimum-likelihood method: it sums the probabilities of every outcome
+/// which is no more likely than the one observed, as in R's `binom.test`.
+///
+/// The confidence interval is constructed at `confidence_level` with the chosen `interval` method. As with
+/// `correlation::pearson`, it is one-sided when `tail` is `Tails::LOWER` or `Tails::UPPER`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::binomial::{self, Interval};
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let unwrapped_results = binomial::test(682, 925, 0.75, Tails::BOTH, Interval::ClopperPearson, 0.95);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Exact Binomial Test");
+///     assert_eq!((results.p * 1e4).round() / 1e4, 0.3825);
+///     assert_eq!((results.confidence_interval.0 * 1e4).round() / 1e4, 0.7077);
+///     assert_eq!((results.confidence_interval.1 * 1e4).round() / 1e4, 0.7654);
+/// }
+/// ```
+pub fn test(successes: u64, trials: u64, expected_probability: f64, alternative: impl Into<Alternative>, interval: Interval, confidence_level: f64) -> Option<BinomialResult> {
+    let results = exact_test(successes, trials, expected_probability, alternative.into(), false, interval, confidence_level)?;
+    Some(results)
+}
+
+/// Performs the same test as `binomial::test`, but reports the mid-p-value, which only counts half the
+/// probability of outcomes exactly as likely as the observed one. The mid-p-value is less conservative
+/// than the exact p-value, at the cost of not strictly guaranteeing the significance level.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::binomial::{self, Interval};
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let exact = binomial::test(8, 10, 0.5, Tails::UPPER, Interval::Wilson, 0.95).unwrap();
+///     let mid_p = binomial::mid_p_test(8, 10, 0.5, Tails::UPPER, Interval::Wilson, 0.95).unwrap();
+///
+///     assert_eq!(mid_p.test_type, "Exact Binomial Test (Mid-p)");
+///     assert!(mid_p.p < exact.p);
+/// }
+/// ```
+pub fn mid_p_test(successes: u64, trials: u64, expected_probability: f64, alternative: impl Into<Alternative>, interval: Interval, confidence_level: f64) -> Option<BinomialResult> {
+    let results = exact_test(successes, trials, expected_probability, alternative.into(), true, interval, confidence_level)?;
+    Some(results)
+}
+
+impl BinomialResult {
+    /// Concludes if a `BinomialResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `BinomialResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for BinomialResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for BinomialResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("Successes", format!("{} out of {}", self.statistic, self.trials))
+            .row("Estimate", format!("{:.4}", self.estimate))
+            .row("p-value", report::p_value(self.p))
+            .row("Confidence interval", format!("({:.4}, {:.4})", self.confidence_interval.0, self.confidence_interval.1))
+            .apa(format!("p̂ = {}, CI [{}, {}], {}", report::bounded(self.estimate, 2), report::bounded(self.confidence_interval.0, 2), report::bounded(self.confidence_interval.1, 2), report::apa_p(self.p)))
+    }
+}
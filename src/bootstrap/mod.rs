@@ -0,0 +1,559 @@
+//! Module responsible for all items needed to construct bootstrap confidence intervals and perform bootstrap-t tests.
+//!
+//! An interval takes a closure computing the statistic, and resamples the data in one of three ways: the observations
+//! of each sample independently (`interval`), overlapping blocks of a time series (`interval_blocks`), or whole
+//! clusters such as users (`interval_clusters`). Resamples are drawn as described by a `utils::Resampling`, so the
+//! same seed always gives the same interval.
+
+use std::collections::BTreeMap;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use statrs::distribution::{ContinuousCDF, Normal};
+use crate::report::{self, Report, Reportable};
+use crate::t::{self, TResult};
+use crate::utils::{Alternative, Tails, Conclusion, PValue, conclude, mean, variance, MissingPolicy, Observation, QuantileType, Resampling, ToPrimitive, clean, resample, sorted_quantile, to_f64, TestType};
+
+/// Methods for constructing a bootstrap confidence interval.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Interval {
+    /// Quantiles of the resampled statistics.
+    Percentile,
+    /// Quantiles of the resampled statistics reflected about the estimate, also known as the pivotal interval.
+    Basic,
+    /// Bootstrap-t interval, with the standard error of each resampled statistic estimated from the given number
+    /// of resamples of that resample, e.g. 50.
+    Studentized(usize),
+    /// Bias-corrected and accelerated percentile interval (Efron, 1987), with the acceleration estimated by the jackknife.
+    BCa,
+}
+
+/// A struct for storing the resulting estimate and confidence interval from bootstrap intervals.
+///
+/// `bias` is the mean of the resampled statistics minus `estimate`, and `standard_error` is their standard deviation.
+/// `skipped` counts the resamples left out of a studentized interval because the standard error estimated from their
+/// own resamples was 0 or undefined, and is 0 for every other method.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IntervalResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub bias: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub standard_error: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub confidence_level: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub confidence_interval: (f64, f64),
+    pub resamples: usize,
+    pub skipped: usize,
+    pub omitted: usize,
+}
+
+/// A struct for storing the resulting test statistic and p-value from bootstrap-t tests.
+///
+/// `statistic` is the T statistic of the observed data. `p` is the Monte Carlo estimate `(1 + extreme) / (1 + resamples)`
+/// from the T statistics of resamples drawn under the null hypothesis, and `standard_error` is its standard error.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BootstrapResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    pub resamples: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub standard_error: f64,
+    pub hypotheses: String,
+    pub omitted: usize,
+}
+
+/// Percentile, basic, studentized, and BCa test type names of each way of resampling.
+type TestTypes = [&'static str; 4];
+
+const INDEPENDENT: TestTypes = [
+    "Bootstrap Percentile Interval",
+    "Bootstrap Basic Interval",
+    "Bootstrap Studentized Interval",
+    "Bootstrap BCa Interval",
+];
+const BLOCKS: TestTypes = [
+    "Moving Block Bootstrap Percentile Interval",
+    "Moving Block Bootstrap Basic Interval",
+    "Moving Block Bootstrap Studentized Interval",
+    "Moving Block Bootstrap BCa Interval",
+];
+const CLUSTERS: TestTypes = [
+    "Cluster Bootstrap Percentile Interval",
+    "Cluster Bootstrap Basic Interval",
+    "Cluster Bootstrap Studentized Interval",
+    "Cluster Bootstrap BCa Interval",
+];
+
+/// Test type names of the bootstrap-t tests.
+const ONE_SAMPLE_T: TestType = "(1-Sample) Bootstrap-t Test for Mean";
+const TWO_SAMPLE_T: TestType = "(2-Sample) Bootstrap-t Test for Mean";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![INDEPENDENT, BLOCKS, CLUSTERS, [ONE_SAMPLE_T, TWO_SAMPLE_T]];
+
+/// Data together with the way it is resampled.
+enum Scheme {
+    /// Samples whose observations are drawn with replacement, independently of each other.
+    Independent(Vec<Vec<f64>>),
+    /// A series drawn as overlapping blocks of the given length.
+    Blocks(Vec<f64>, usize),
+    /// The observations of every sample in each cluster, drawn a whole cluster at a time.
+    Clusters(Vec<Vec<Vec<f64>>>),
+}
+
+/// Evaluates `statistic` on owned samples.
+fn evaluate(samples: &[Vec<f64>], statistic: &impl Fn(&[&[f64]]) -> f64) -> f64 {
+    let samples: Vec<&[f64]> = samples.iter().map(Vec::as_slice).collect();
+    statistic(&samples)
+}
+
+/// Concatenates the observations of each sample across `clusters`.
+fn pool(clusters: &[Vec<Vec<f64>>]) -> Vec<Vec<f64>> {
+    let samples = clusters.first().map_or(0, Vec::len);
+    (0..samples).map(|sample| clusters.iter().flat_map(|cluster| cluster[sample].iter().copied()).collect()).collect()
+}
+
+impl Scheme {
+    fn evaluate(&self, statistic: &impl Fn(&[&[f64]]) -> f64) -> f64 {
+        match self {
+            Scheme::Independent(samples) => evaluate(samples, statistic),
+            Scheme::Blocks(series, _) => statistic(&[series]),
+            Scheme::Clusters(clusters) => evaluate(&pool(clusters), statistic),
+        }
+    }
+
+    /// Draws a resample of the same size and kind.
+    fn draw(&self, rng: &mut ChaCha8Rng) -> Scheme {
+        match self {
+            Scheme::Independent(samples) => Scheme::Independent(samples.iter().map(|sample| {
+                (0..sample.len()).map(|_| sample[rng.gen_range(0..sample.len())]).collect()
+            }).collect()),
+            Scheme::Blocks(series, length) => {
+                let n = series.len();
+                let mut drawn = Vec::with_capacity(n + length);
+                while drawn.len() < n {
+                    let start = rng.gen_range(0..=n - length);
+                    drawn.extend_from_slice(&series[start..start + length]);
+                }
+                drawn.truncate(n);
+                Scheme::Blocks(drawn, *length)
+            },
+            Scheme::Clusters(clusters) => Scheme::Clusters((0..clusters.len()).map(|_| clusters[rng.gen_range(0..clusters.len())].clone()).collect()),
+        }
+    }
+
+    /// Evaluates `statistic` with each unit left out in turn: every observation of each sample, every
+    /// non-overlapping block of the series, or every cluster. The values are grouped by the sample left out of.
+    fn jackknife(&self, statistic: &impl Fn(&[&[f64]]) -> f64) -> Vec<Vec<f64>> {
+        match self {
+            Scheme::Independent(samples) => (0..samples.len()).map(|left_out| {
+                (0..samples[left_out].len()).map(|index| {
+                    let mut reduced = samples[left_out].clone();
+                    reduced.remove(index);
+                    let views: Vec<&[f64]> = samples.iter().enumerate().map(|(sample, data)| if sample == left_out { &reduced } else { data.as_slice() }).collect();
+                    statistic(&views)
+                }).collect()
+            }).collect(),
+            Scheme::Blocks(series, length) => vec![(0..series.len().div_ceil(*length)).map(|block| {
+                let reduced: Vec<f64> = series.chunks(*length).enumerate().filter(|&(index, _)| index != block).flat_map(|(_, chunk)| chunk.iter().copied()).collect();
+                statistic(&[&reduced])
+            }).collect()],
+            Scheme::Clusters(clusters) => vec![(0..clusters.len()).map(|left_out| {
+                let mut reduced = clusters.clone();
+                reduced.remove(left_out);
+                evaluate(&pool(&reduced), statistic)
+            }).collect()],
+        }
+    }
+
+    /// Constructs a confidence interval of `statistic`.
+    fn interval(&self, statistic: impl Fn(&[&[f64]]) -> f64 + Sync, method: Interval, confidence_level: f64, resampling: Resampling, test_types: TestTypes, omitted: usize) -> IntervalResult {
+        if !(0.0..1.0).contains(&confidence_level) { panic!("\n[HYTE-Panic] Confidence level must be in the interval [0, 1)!\n") };
+        let estimate = self.evaluate(&statistic);
+        // Each resample is paired with the standard error of its statistic, which only studentized intervals estimate
+        let replicates: Vec<(f64, f64)> = resample(&resampling, |rng, count| {
+            (0..count).map(|_| {
+                let drawn = self.draw(rng);
+                let standard_error = match method {
+                    Interval::Studentized(inner) => {
+                        let values: Vec<f64> = (0..inner).map(|_| drawn.draw(rng).evaluate(&statistic)).collect();
+                        variance(&values).map_or(f64::NAN, f64::sqrt)
+                    },
+                    _ => f64::NAN,
+                };
+                (drawn.evaluate(&statistic), standard_error)
+            }).collect::<Vec<_>>()
+        }).into_iter().flatten().collect();
+
+        let values: Vec<f64> = replicates.iter().map(|&(value, _)| value).collect();
+        let standard_error = variance(&values).map_or(f64::NAN, f64::sqrt);
+        let mut sorted = values.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let quantile = |sorted: &[f64], probability: f64| if probability.is_nan() { f64::NAN } else { sorted_quantile(sorted, probability, QuantileType::Type6) };
+        let alpha = (1.0 - confidence_level) / 2.0;
+
+        let mut skipped = 0;
+        let (confidence_interval, test_type) = match method {
+            Interval::Percentile => ((quantile(&sorted, alpha), quantile(&sorted, 1.0 - alpha)), test_types[0]),
+            Interval::Basic => ((2.0 * estimate - quantile(&sorted, 1.0 - alpha), 2.0 * estimate - quantile(&sorted, alpha)), test_types[1]),
+            Interval::Studentized(_) => {
+                // A resample whose own resamples all agree has no standard error to studentize by
+                let mut studentized: Vec<f64> = replicates.iter()
+                    .filter(|&&(_, error)| error > 0.0)
+                    .map(|&(value, error)| (value - estimate) / error)
+                    .collect();
+                skipped = replicates.len() - studentized.len();
+                studentized.sort_by(|a, b| a.total_cmp(b));
+                let interval = if studentized.is_empty() {
+                    (f64::NAN, f64::NAN)
+                } else {
+                    (estimate - quantile(&studentized, 1.0 - alpha) * standard_error, estimate - quantile(&studentized, alpha) * standard_error)
+                };
+                (interval, test_types[2])
+            },
+            Interval::BCa => {
+                let normal = Normal::new(0.0, 1.0).unwrap();
+                let below = values.iter().filter(|&&value| value < estimate).count() as f64;
+                let ties = values.iter().filter(|&&value| value == estimate).count() as f64;
+                let bias_correction = normal.inverse_cdf((below + 0.5 * ties) / values.len() as f64);
+                let acceleration = acceleration(&self.jackknife(&statistic));
+                let adjusted = |probability: f64| {
+                    let z = bias_correction + normal.inverse_cdf(probability);
+                    normal.cdf(bias_correction + z / (1.0 - acceleration * z))
+                };
+                ((quantile(&sorted, adjusted(alpha)), quantile(&sorted, adjusted(1.0 - alpha))), test_types[3])
+            },
+        };
+
+        IntervalResult {
+            test_type,
+            estimate,
+            bias: mean(&values).unwrap() - estimate,
+            standard_error,
+            confidence_level,
+            confidence_interval,
+            resamples: resampling.resamples,
+            skipped,
+            omitted,
+        }
+    }
+
+    /// Performs a bootstrap-t test, comparing the T statistic `observed` with those of resamples of this data, which
+    /// must already be shifted to satisfy the null hypothesis, given by `studentize`.
+    fn test(&self, observed: TResult, studentize: impl Fn(&[&[f64]]) -> f64 + Sync, alternative: Alternative, resampling: Resampling, test_type: TestType, omitted: usize) -> BootstrapResult {
+        let counts = resample(&resampling, |rng, count| {
+            let (mut greater, mut less) = (0, 0);
+            for _ in 0..count {
+                let statistic = self.draw(rng).evaluate(&studentize);
+                if statistic >= observed.statistic { greater += 1 };
+                if statistic <= observed.statistic { less += 1 };
+            }
+            (greater, less)
+        });
+        let (greater, less) = counts.into_iter().fold((0, 0), |(greater, less), counts| (greater + counts.0, less + counts.1));
+        let estimate = |extreme: usize| (extreme + 1) as f64 / (resampling.resamples + 1) as f64;
+        let p = match alternative.tails() {
+            _ if observed.statistic.is_nan() => f64::NAN,
+            Tails::UPPER => estimate(greater),
+            Tails::LOWER => estimate(less),
+            Tails::BOTH => (2.0 * estimate(greater).min(estimate(less))).min(1.0),
+        };
+
+        BootstrapResult {
+            test_type,
+            statistic: observed.statistic,
+            p,
+            resamples: resampling.resamples,
+            standard_error: (p * (1.0 - p) / resampling.resamples as f64).sqrt(),
+            hypotheses: observed.hypotheses,
+            omitted,
+        }
+    }
+}
+
+/// Estimates the acceleration of a BCa interval from jackknife values grouped by sample.
+fn acceleration(jackknife: &[Vec<f64>]) -> f64 {
+    let (mut skew, mut spread) = (0.0, 0.0);
+    for values in jackknife {
+        let center = mean(values).unwrap_or(0.0);
+        for value in values {
+            let influence = center - value;
+            skew += influence.powi(3);
+            spread += influence.powi(2);
+        }
+    }
+    if spread == 0.0 { 0.0 } else { skew / (6.0 * spread.powf(1.5)) }
+}
+
+/// Applies the missing-data policy to every sample, returning `None` if any is left empty.
+fn clean_samples<Number: Observation, Sample: AsRef<[Number]>>(samples: &[Sample], missing: MissingPolicy) -> Option<(Vec<Vec<f64>>, usize)> {
+    if samples.is_empty() { panic!("\n[HYTE-Panic] At least 1 sample is required!\n") };
+    let cleaned: Vec<(Vec<f64>, usize)> = samples.iter().map(|sample| clean(sample.as_ref(), missing)).collect();
+    if cleaned.iter().any(|(sample, _)| sample.is_empty()) { return None };
+    let omitted = cleaned.iter().map(|(_, omitted)| omitted).sum();
+    Some((cleaned.into_iter().map(|(sample, _)| sample).collect(), omitted))
+}
+
+/// Constructs a bootstrap confidence interval of `statistic`, which is computed from the samples in the order they are
+/// given, by drawing the observations of each sample with replacement, independently of the other samples.
+///
+/// Any statistic can be used, such as a median, a quantile, or a ratio of the means of two samples. Percentile and
+/// basic intervals only need the resampled statistics, studentized intervals resample every resample again, and BCa
+/// intervals also evaluate the statistic with each observation left out. Each sample must contain at least 2 observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bootstrap::{self, Interval};
+/// use hyte::utils::{median, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let load_times = vec![1.2, 0.9, 1.4, 3.8, 1.1, 1.0, 1.6, 1.3, 0.8, 2.9, 1.2, 1.5, 1.1, 4.2, 1.0];
+///     let statistic = |samples: &[&[f64]]| median(samples[0]).unwrap();
+///     let results = bootstrap::interval(&[load_times], statistic, Interval::BCa, 0.95, Resampling::seeded(42), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Bootstrap BCa Interval");
+///     assert_eq!(results.estimate, 1.2);
+///     assert!(results.confidence_interval.0 <= 1.2 && 1.2 <= results.confidence_interval.1);
+/// }
+/// ```
+pub fn interval<Number: Observation, Sample: AsRef<[Number]>>(samples: &[Sample], statistic: impl Fn(&[&[f64]]) -> f64 + Sync, method: Interval, confidence_level: f64, resampling: Resampling, missing: MissingPolicy) -> Option<IntervalResult> {
+    let (samples, omitted) = clean_samples(samples, missing)?;
+    if samples.iter().any(|sample| sample.len() < 2) { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    Some(Scheme::Independent(samples).interval(statistic, method, confidence_level, resampling, INDEPENDENT, omitted))
+}
+
+/// Constructs a moving block bootstrap confidence interval of `statistic`, which is computed from a time series.
+///
+/// Each resample joins blocks of `block_length` consecutive observations, starting at random positions, until it is as
+/// long as the series, so that autocorrelation within each block is kept. The block length should grow with the
+/// series and the strength of its autocorrelation; `n^(1/3)` is a common choice. BCa intervals leave out each
+/// non-overlapping block in turn. The block length must be less than the length of the series, and omitting missing
+/// observations joins the observations on either side of them.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bootstrap::{self, Interval};
+/// use hyte::utils::{mean, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let daily_signups = vec![52, 55, 61, 58, 50, 47, 49, 56, 63, 66, 60, 54, 51, 53, 59, 64, 67, 62, 57, 55, 58];
+///     let statistic = |series: &[f64]| mean(series).unwrap();
+///     let results = bootstrap::interval_blocks(daily_signups, 3, statistic, Interval::Percentile, 0.9, Resampling::seeded(7), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Moving Block Bootstrap Percentile Interval");
+///     assert!(results.confidence_interval.0 < results.estimate && results.estimate < results.confidence_interval.1);
+/// }
+/// ```
+pub fn interval_blocks<Number: Observation>(data: impl AsRef<[Number]>, block_length: usize, statistic: impl Fn(&[f64]) -> f64 + Sync, method: Interval, confidence_level: f64, resampling: Resampling, missing: MissingPolicy) -> Option<IntervalResult> {
+    let (series, omitted) = clean(data.as_ref(), missing);
+    if series.is_empty() { return None };
+    if block_length == 0 || block_length >= series.len() {
+        panic!("\n[HYTE-Panic] The block length must be at least 1 and less than the length of the series!\n");
+    }
+    Some(Scheme::Blocks(series, block_length).interval(|samples| statistic(samples[0]), method, confidence_level, resampling, BLOCKS, omitted))
+}
+
+/// Constructs a cluster bootstrap confidence interval of `statistic`, which is computed from the samples in the order
+/// they are given, by drawing whole clusters, such as users, sessions, or classrooms, with replacement.
+///
+/// `clusters` holds the cluster of every observation, in the same shape as `samples`. Every resample draws as many
+/// clusters as there are, and takes all of their observations from every sample, so observations of the same cluster
+/// stay together and samples describing the same rows, such as revenue and sessions, stay paired. This accounts for
+/// the correlation of observations within a cluster, as when users rather than their page views were randomised.
+/// Missing observations are omitted from each sample separately, together with their cluster, so rows shared by
+/// several samples should be complete. There must be at least 2 clusters.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bootstrap::{self, Interval};
+/// use hyte::utils::{MissingPolicy, Resampling};
+///
+/// fn main() {
+///     // Revenue and sessions of each visit, and the user who made it
+///     let revenue = vec![12.0, 0.0, 30.0, 5.0, 0.0, 0.0, 18.0, 22.0, 9.0, 0.0, 14.0];
+///     let sessions = vec![3.0, 1.0, 4.0, 2.0, 1.0, 2.0, 3.0, 5.0, 2.0, 1.0, 3.0];
+///     let users = vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 7, 8];
+///     let ratio = |samples: &[&[f64]]| samples[0].iter().sum::<f64>() / samples[1].iter().sum::<f64>();
+///     let results = bootstrap::interval_clusters(&[revenue, sessions], &[&users, &users], ratio, Interval::Percentile, 0.95, Resampling::seeded(3), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Cluster Bootstrap Percentile Interval");
+///     assert_eq!(results.estimate, 110.0 / 27.0);
+/// }
+/// ```
+pub fn interval_clusters<Number: Observation, Sample: AsRef<[Number]>, Clusters: AsRef<[usize]>>(samples: &[Sample], clusters: &[Clusters], statistic: impl Fn(&[&[f64]]) -> f64 + Sync, method: Interval, confidence_level: f64, resampling: Resampling, missing: MissingPolicy) -> Option<IntervalResult> {
+    if samples.len() != clusters.len() {
+        panic!("\n[HYTE-Panic] The number of samples ({}) and clusters ({}) do not match!\n", samples.len(), clusters.len());
+    }
+    let mut grouped: BTreeMap<usize, Vec<Vec<f64>>> = BTreeMap::new();
+    let mut omitted = 0;
+    for (index, (sample, labels)) in samples.iter().zip(clusters).enumerate() {
+        let (sample, labels) = (sample.as_ref(), labels.as_ref());
+        if sample.len() != labels.len() {
+            panic!("\n[HYTE-Panic] The lengths of a sample ({}) and its clusters ({}) do not match!\n", sample.len(), labels.len());
+        }
+        let (cleaned, missing_observations) = clean(sample, missing);
+        if cleaned.is_empty() { return None };
+        omitted += missing_observations;
+        let kept = labels.iter().zip(sample).filter(|(_, &x)| missing == MissingPolicy::Propagate || x.value().is_some());
+        for ((&label, _), value) in kept.zip(cleaned) {
+            grouped.entry(label).or_insert_with(|| vec![Vec::new(); samples.len()])[index].push(value);
+        }
+    }
+    if grouped.len() < 2 { panic!("\n[HYTE-Panic] The cluster bootstrap requires at least 2 clusters!\n") };
+    Some(Scheme::Clusters(grouped.into_values().collect()).interval(statistic, method, confidence_level, resampling, CLUSTERS, omitted))
+}
+
+/// Performs a bootstrap-t test for the mean of a sample, which does not assume that the data is normally distributed.
+///
+/// The T statistic of the data is computed with `t::test_dataless`, and compared with the T statistics of resamples
+/// of the data shifted to have a mean of `expected_mean`. The sample must contain at least 2 observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bootstrap;
+/// use hyte::utils::{Tails, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2, 2.4, 2.6, 2.5, 2.7];
+///     let results = bootstrap::t_test(data, 3, Tails::LOWER, Resampling::seeded(1), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "(1-Sample) Bootstrap-t Test for Mean");
+///     assert_eq!(results.hypotheses, "H₀: μ = 3.0 vs H₁: μ < 3.0");
+///     assert!(results.p < 0.05);
+/// }
+/// ```
+pub fn t_test<Number: Observation, Number2: ToPrimitive>(data: impl AsRef<[Number]>, expected_mean: Number2, alternative: impl Into<Alternative>, resampling: Resampling, missing: MissingPolicy) -> Option<BootstrapResult> {
+    let (data, omitted) = clean(data.as_ref(), missing);
+    if data.is_empty() { return None };
+    if data.len() < 2 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    let expected_mean = to_f64(expected_mean);
+    let alternative = alternative.into().with_null(expected_mean);
+    let studentize = |samples: &[&[f64]]| {
+        let sample = samples[0];
+        t::test_dataless(mean(sample).unwrap(), expected_mean, sample.len(), variance(sample).unwrap().sqrt(), alternative).unwrap()
+    };
+    let observed = studentize(&[&data]);
+    // Shift the data to satisfy the null hypothesis, keeping the shape of its distribution
+    let data_mean = mean(&data).unwrap();
+    let shifted = data.iter().map(|&x| x - data_mean + expected_mean).collect();
+    let scheme = Scheme::Independent(vec![shifted]);
+    Some(scheme.test(observed, |samples| studentize(samples).statistic, alternative, resampling, ONE_SAMPLE_T, omitted))
+}
+
+/// Performs a bootstrap-t test for the difference between the means of two samples, `μ₁ - μ₂`, which does not
+/// assume that either sample is normally distributed.
+///
+/// The Welch T statistic of the data is compared with those of resamples of each sample, shifted so that the
+/// difference between their means is the null value, which defaults to 0. Each sample must contain at least 2 observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::bootstrap;
+/// use hyte::utils::{Tails, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let group1 = vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18];
+///     let group2 = vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24];
+///     let results = bootstrap::t_test_two_samples(group1, group2, Tails::BOTH, Resampling::seeded(1), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "(2-Sample) Bootstrap-t Test for Mean");
+///     assert_eq!(results.p, 2.0 / 10000.0);
+/// }
+/// ```
+pub fn t_test_two_samples<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, alternative: impl Into<Alternative>, resampling: Resampling, missing: MissingPolicy) -> Option<BootstrapResult> {
+    let (data1, omitted1) = clean(data1.as_ref(), missing);
+    let (data2, omitted2) = clean(data2.as_ref(), missing);
+    if data1.is_empty() || data2.is_empty() { return None };
+    if data1.len() < 2 || data2.len() < 2 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    let alternative = alternative.into().or_null(0.0);
+    let observed = t::test_two_samples_stats(&data1.iter().copied().collect(), &data2.iter().copied().collect(), alternative).unwrap();
+    // Shift both samples to the pooled mean, then apart by the null difference
+    let (mean1, mean2) = (mean(&data1).unwrap(), mean(&data2).unwrap());
+    let pooled = (data1.iter().sum::<f64>() + data2.iter().sum::<f64>()) / (data1.len() + data2.len()) as f64;
+    let difference = alternative.null_value.unwrap();
+    let shifted1 = data1.iter().map(|&x| x - mean1 + pooled + difference).collect();
+    let shifted2 = data2.iter().map(|&x| x - mean2 + pooled).collect();
+    // Resamples of two constant samples have no variance, so their statistic is infinite, or undefined and never extreme
+    let studentize = |samples: &[&[f64]]| {
+        let (sample1, sample2) = (samples[0], samples[1]);
+        let error = (variance(sample1).unwrap() / sample1.len() as f64 + variance(sample2).unwrap() / sample2.len() as f64).sqrt();
+        (mean(sample1).unwrap() - mean(sample2).unwrap() - difference) / error
+    };
+    let scheme = Scheme::Independent(vec![shifted1, shifted2]);
+    Some(scheme.test(observed, studentize, alternative, resampling, TWO_SAMPLE_T, omitted1 + omitted2))
+}
+
+impl BootstrapResult {
+    /// Concludes if a `BootstrapResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `BootstrapResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for BootstrapResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for BootstrapResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("T test statistic", format!("{:.2}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .row("Resamples", self.resamples)
+            .row("Monte Carlo standard error", format!("{:.4}", self.standard_error))
+            .apa(format!("t = {:.2}, {}, {} resamples", self.statistic, report::apa_p(self.p), self.resamples))
+    }
+}
+
+impl Reportable for IntervalResult {
+    fn report(&self) -> Report {
+        let level = format!("{}%", self.confidence_level * 100.0);
+        let (lower, upper) = self.confidence_interval;
+        Report::new(self.test_type)
+            .row("Estimate", format!("{:.4}", self.estimate))
+            .row("Bias", format!("{:.4}", self.bias))
+            .row("Standard error", format!("{:.4}", self.standard_error))
+            .row(&format!("{} confidence interval", level), format!("[{:.4}, {:.4}]", lower, upper))
+            .row("Resamples", self.resamples)
+            .row("Skipped resamples", self.skipped)
+            .apa(format!("estimate = {:.2}, {} CI [{:.2}, {:.2}]", self.estimate, level, lower, upper))
+    }
+}
@@ -1,33 +1,68 @@
 //! Module responsible for all items needed to perform Pearson's Chi-squared tests.
 
-use statrs::distribution::{ChiSquared, ContinuousCDF};
-use crate::utils::{Matrix, Conclusion};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Matrix, Conclusion, PValue, MissingPolicy, Observation, chi_squared_tail, conclude, missing_error, TestType};
 
-fn contains_negative<Number: Into<f64> + Clone>(matrix: &[Vec<Number>]) -> bool {
-    matrix.iter().any(|row| row.iter().any(|num| num.clone().into() < 0.0))
+fn contains_negative(matrix: &[Vec<f64>]) -> bool {
+    matrix.iter().any(|row| row.iter().any(|&num| num < 0.0))
 }
 
-fn has_different_rows<Number: Into<f64> + Copy>(matrix: &[Vec<Number>]) -> bool {
-    if let Some(first_row_len) = matrix.first().map(|row| row.len()) {
-        matrix.iter().any(|row| row.len() != first_row_len)
+fn has_different_rows<Row: AsRef<[Number]>, Number>(matrix: &[Row]) -> bool {
+    if let Some(first_row_len) = matrix.first().map(|row| row.as_ref().len()) {
+        matrix.iter().any(|row| row.as_ref().len() != first_row_len)
     } else {
         panic!("\n[HYTE-Panic] You must not pass in an empty matrix!\n")
     }
 }
 
-fn compute_totals<Number: Into<f64> + Copy>(matrix: &Vec<Vec<Number>>) -> Totals<f64> {
+/// Applies `policy` to a contingency table, removing a whole row when any of its counts is missing.
+/// Returns the counts and the number of rows omitted.
+fn clean_table<Row: AsRef<[Number]>, Number: Observation>(matrix: &[Row], policy: MissingPolicy) -> (Vec<Vec<f64>>, usize) {
+    let rows: Vec<Vec<Option<f64>>> = matrix.iter().map(|row| row.as_ref().iter().map(|&x| x.value()).collect()).collect();
+    let missing = rows.iter().flatten().filter(|value| value.is_none()).count();
+    match policy {
+        MissingPolicy::Propagate => (rows.iter().map(|row| row.iter().map(|value| value.unwrap_or(f64::NAN)).collect()).collect(), 0),
+        MissingPolicy::Omit | MissingPolicy::Error => {
+            if policy == MissingPolicy::Error && missing > 0 { missing_error(missing) };
+            let kept: Vec<Vec<f64>> = rows.iter().filter_map(|row| row.iter().copied().collect()).collect();
+            let omitted = rows.len() - kept.len();
+            (kept, omitted)
+        }
+    }
+}
+
+/// Applies `policy` to the counts of a goodness of fit test, removing a missing count together with its
+/// probability, and rescaling the remaining probabilities to sum to 1. Returns the counts, their
+/// probabilities, and the number of counts omitted.
+fn clean_counts<Number: Observation>(counts: &[Number], probabilities: &[f64], policy: MissingPolicy) -> (Vec<f64>, Vec<f64>, usize) {
+    let values: Vec<Option<f64>> = counts.iter().map(|&x| x.value()).collect();
+    let missing = values.iter().filter(|value| value.is_none()).count();
+    match policy {
+        MissingPolicy::Propagate => (values.iter().map(|value| value.unwrap_or(f64::NAN)).collect(), probabilities.to_vec(), 0),
+        MissingPolicy::Omit | MissingPolicy::Error => {
+            if policy == MissingPolicy::Error && missing > 0 { missing_error(missing) };
+            let (kept, kept_probabilities): (Vec<f64>, Vec<f64>) = values.iter().zip(probabilities)
+                .filter_map(|(value, &probability)| value.map(|value| (value, probability)))
+                .unzip();
+            let total: f64 = kept_probabilities.iter().sum();
+            let rescaled = if missing > 0 { kept_probabilities.iter().map(|probability| probability / total).collect() } else { kept_probabilities };
+            (kept, rescaled, missing)
+        }
+    }
+}
+
+fn compute_totals(matrix: &[Vec<f64>]) -> Totals<f64> {
     let mut totals = Totals {
         column_totals: vec![0.0; matrix.first().map_or(0, Vec::len)],
         row_totals: vec![0.0; matrix.len()],
         grand_total: 0.0,
     };
 
-    for (i, row) in matrix.into_iter().enumerate() {
+    for (i, row) in matrix.iter().enumerate() {
         let mut row_total = 0.0;
-        for (j, &num) in row.into_iter().enumerate() {
-            let num_f64: f64 = num.into();
-            row_total += num_f64;
-            totals.column_totals[j] += num_f64;
+        for (j, &num) in row.iter().enumerate() {
+            row_total += num;
+            totals.column_totals[j] += num;
         }
         totals.row_totals[i] = row_total;
         totals.grand_total += row_total;
@@ -38,22 +73,44 @@ fn compute_totals<Number: Into<f64> + Copy>(matrix: &Vec<Vec<Number>>) -> Totals
 
 #[derive(Debug)]
 
-struct Totals<Number: Into<f64> + Copy> {
+struct Totals<Number> {
     column_totals: Vec<Number>,
     row_totals: Vec<Number>,
     grand_total: Number
 }
 
 /// A struct for storing the resulting test statistic and p-value from Pearson's Chi-squared tests.
+///
+/// `log_p` is the natural logarithm of the p-value, which remains finite even when `p` underflows to 0.
+/// `omitted` is the number of rows of a contingency table, or counts of a goodness of fit test, removed as missing.
 #[derive(Debug)]
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChiSquareResult {
-    pub test_type: &'static str,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
     pub statistic: f64,
     pub df: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
     pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    pub omitted: usize,
 }
 
+/// Test type names of each Chi-squared test.
+const INDEPENDENCE: TestType = "Pearson's Chi-squared Test of Independence";
+const GOODNESS_OF_FIT: TestType = "Pearson's Chi-squared Goodness Of Fit";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[INDEPENDENCE, GOODNESS_OF_FIT]];
+
 /// Calculates Chi-square statistic and p-value for a Pearson's Chi-squared test. 
 /// It returns a `ChiSquareResult` instance with the Chi-square statistic and p-value as fields `statistic` and `p` respectively.
 /// 
@@ -63,13 +120,20 @@ pub struct ChiSquareResult {
 /// 
 /// ***-IMPORTANT NOTE-*** on `observed_matrix` parameter:
 /// 
-/// - To perform a test of independence `"toi"`, you must pass a `Matrix::TwoDimensional(Vec<Vec<Number>>)` variant to `observed_matrix`.
-/// - To perform a goodness of fit test `"gof"`, you must apss a `Matrix::OneDimensional(Vec<Number>)` variant to `observed_matrix`.
+/// - To perform a test of independence `"toi"`, you must pass a `Matrix::TwoDimensional(Vec<Row>)` variant to `observed_matrix`.
+/// - To perform a goodness of fit test `"gof"`, you must apss a `Matrix::OneDimensional(Row)` variant to `observed_matrix`.
+/// - A `Row` may be owned or borrowed, e.g. `Vec<i32>`, `&[f64]` or `&Vec<Option<u32>>`.
 /// 
 /// ***-IMPORTANT NOTE-*** on `gof_probabilities` parameter:
 /// 
 /// - To perform a test of independence `"toi"`, you must pass an `Option::None` variant to `gof_probabiltiies`
-/// - To perform a goodness of fit test `"gof"`, you must pass an `Option::Some(Vec<f64>)` variant to `gof_probabilities`
+/// - To perform a goodness of fit test `"gof"`, you must pass an `Option::Some(&[f64])` variant to `gof_probabilities`
+/// 
+/// ***-IMPORTANT NOTE-*** on `missing` parameter:
+/// 
+/// - Under `MissingPolicy::Omit`, a row of a contingency table with a missing count is removed as a whole.
+/// - A missing count of a goodness of fit test is removed along with its probability, and the remaining probabilities are rescaled to sum to 1.
+/// - `None` is returned if no counts remain.
 /// 
 /// ## Test of Independence (Example)
 /// 
@@ -77,7 +141,7 @@ pub struct ChiSquareResult {
 /// 
 /// ```
 /// use hyte::chisquare;
-/// use hyte::utils::Matrix;
+/// use hyte::utils::{Matrix, MissingPolicy};
 ///
 /// fn main() {
 ///     let observed_frequencies = Matrix::TwoDimensional(vec![vec![762, 327, 468], 
@@ -85,8 +149,8 @@ pub struct ChiSquareResult {
 ///     let unwrapped_results = chisquare::test(
 ///         "toi", 
 ///         observed_frequencies, 
-///         None, 
-///         true
+///         None,
+///         MissingPolicy::Propagate
 ///     );
 ///
 ///     assert_ne!(unwrapped_results, None);
@@ -97,7 +161,7 @@ pub struct ChiSquareResult {
 ///     assert_eq!(results.test_type, "Pearson's Chi-squared Test of Independence");
 ///     assert_eq!(results.statistic, 30.070149095754672);
 ///     assert_eq!(results.df, 2);
-///     assert_eq!(results.p, 0.00000029535891832299654);
+///     assert_eq!(results.p, 0.00000029535891832117595);
 /// }
 /// ```
 /// 
@@ -107,14 +171,14 @@ pub struct ChiSquareResult {
 /// 
 /// ```
 /// use hyte::chisquare;
-/// use hyte::utils::Matrix;
+/// use hyte::utils::{Matrix, MissingPolicy};
 ///
 /// fn main() {
 ///     let unwrapped_results = chisquare::test(
 ///         "gof",
 ///         Matrix::OneDimensional(vec![30, 40, 30]),
-///         Some(vec![0.25, 0.5, 0.25]),
-///         true
+///         Some(&[0.25, 0.5, 0.25]),
+///         MissingPolicy::Propagate
 ///     );
 ///
 ///     assert_ne!(unwrapped_results, None);
@@ -125,134 +189,140 @@ pub struct ChiSquareResult {
 ///     assert_eq!(results.test_type, "Pearson's Chi-squared Goodness Of Fit");
 ///     assert_eq!(results.statistic, 4.0);
 ///     assert_eq!(results.df, 2);
-///     assert_eq!(results.p, 0.1353352832366128);
+///     assert_eq!(results.p, 0.13533528323661276);
 /// }
 /// ````
 /// 
-pub fn test<Number: Into<f64> + Copy>(
+pub fn test<Row: AsRef<[Number]>, Number: Observation>(
     test_type: &str, 
-    observed_matrix: Matrix<Number>, 
-    gof_probabilities: Option<Vec<f64>>, 
-    print_output: bool
+    observed_matrix: Matrix<Row>, 
+    gof_probabilities: Option<&[f64]>, 
+    missing: MissingPolicy,
 ) -> Option<ChiSquareResult> {
     match (test_type, observed_matrix) {
         ("toi", Matrix::TwoDimensional(matrix)) => {
-            if matrix.len() == 0 { panic!("\n[HYTE-Panic] You must not pass in an empty matrix!\n"); };
+            if matrix.is_empty() { panic!("\n[HYTE-Panic] You must not pass in an empty matrix!\n"); };
+            if has_different_rows(&matrix) {
+                panic!("\n[HYTE-Panic] You must not pass in a matrix with rows of different lengths!\n");
+            }
+            let (matrix, omitted) = clean_table(&matrix, missing);
             if contains_negative(&matrix) {
                 panic!("\n[HYTE-Panic] You must not pass in a matrix with a negative number!\n");
-            } else if has_different_rows(&matrix) {
-                panic!("\n[HYTE-Panic] You must not pass in a matrix with rows of different lengths!\n");
             }
-            toi(matrix, print_output)
+            if matrix.is_empty() { return None };
+            toi(matrix, omitted)
         },
         ("gof", Matrix::OneDimensional(matrix)) => {
-            if matrix.len() == 0 { panic!("\n[HYTE-Panic] You must not pass in an empty matrix!\n"); };
-            if matrix.iter().any(|&num| num.into() < 0.0) {
-                panic!("\n[HYTE-Panic] You must not pass in a vector with a negative number!\n");
-            }
-            if let Some(probabilities) = gof_probabilities.as_ref() {
-                if probabilities.iter().any(|&prob| prob < 0.0 || prob > 1.0) {
-                    panic!("\n[HYTE-Panic] You must not pass in a vector with a negative number!\n");
-                };
-                if probabilities.len() != matrix.len() {
-                    panic!(
-                        "[HYTE-Panic] The lengths of your observed vector ({}) and expected probabilities vector ({}) do not match!",
-                        matrix.len(),
-                        probabilities.len()
-                    );
-                };
-            } else {
+            let matrix = matrix.as_ref();
+            if matrix.is_empty() { panic!("\n[HYTE-Panic] You must not pass in an empty matrix!\n"); };
+            let Some(probabilities) = gof_probabilities else {
                 panic!("[HYTE-Panic] Expected probabilities must be provided for the goodness of fit test.");
+            };
+            if probabilities.iter().any(|&prob| !(0.0..=1.0).contains(&prob)) {
+                panic!("\n[HYTE-Panic] You must not pass in a vector with a negative number!\n");
+            };
+            if probabilities.len() != matrix.len() {
+                panic!(
+                    "[HYTE-Panic] The lengths of your observed vector ({}) and expected probabilities vector ({}) do not match!",
+                    matrix.len(),
+                    probabilities.len()
+                );
+            };
+            let (counts, probabilities, omitted) = clean_counts(matrix, probabilities, missing);
+            if counts.iter().any(|&num| num < 0.0) {
+                panic!("\n[HYTE-Panic] You must not pass in a vector with a negative number!\n");
             }
-            gof(matrix, gof_probabilities, print_output)
+            if counts.is_empty() { return None };
+            gof(counts, probabilities, omitted)
         },
         _ => panic!("\n[HYTE-Panic] Test type for a ChiSquare test must be \"toi\" or \"gof\"!\n"),
     }
 }
 
-fn toi<Number: Into<f64> + Copy>(matrix: Vec<Vec<Number>>, print_output: bool) -> Option<ChiSquareResult> {
+fn toi(matrix: Vec<Vec<f64>>, omitted: usize) -> Option<ChiSquareResult> {
     let totals = compute_totals(&matrix);
 
     let mut statistic: f64 = 0.0;
 
     for (i, row) in matrix.iter().enumerate() {
         for (j, &num) in row.iter().enumerate() {
-            let num_f64: f64 = num.into(); // Convert Number to f64
             let expected_frequency = (totals.row_totals[i] * totals.column_totals[j]) / totals.grand_total;
-            statistic += ((num_f64 - expected_frequency).powi(2)) / expected_frequency;
+            statistic += ((num - expected_frequency).powi(2)) / expected_frequency;
         }
     };
 
     let df = (&matrix.len() - 1) * (&matrix[0].len() - 1);
-    let chi = ChiSquared::new(df as f64).unwrap();
-    let p = 1.0 - chi.cdf(statistic);
+    let (p, log_p) = chi_squared_tail(statistic, df as f64);
 
     let results = ChiSquareResult {
-        test_type: "Pearson's Chi-squared Test of Independence",
+        test_type: INDEPENDENCE,
         statistic,
         df,
         p,
+        log_p,
+        omitted,
     };
-    if print_output {println!("\n------------------ HYTE ------------------\n\n{}\n\nX^2 test statistic = {:.2}\np-value = {:.3e}\nDegrees of freedom = {}\n\n------------------ HYTE ------------------\n", results.test_type, results.statistic, results.p, results.df);}
     
     Some(results)
 }
 
-fn gof<Number: Into<f64> + Copy>(matrix: Vec<Number>, gof_probabilities: Option<Vec<f64>>, print_output: bool) -> Option<ChiSquareResult> {
+fn gof(matrix: Vec<f64>, gof_probabilities: Vec<f64>, omitted: usize) -> Option<ChiSquareResult> {
     let mut total: f64 = 0.0;
     
     for &num in matrix.iter() {
-        total += num.into();
+        total += num;
     };
     
     let mut expected_frequencies: Vec<f64> = Vec::new();
     let mut statistic: f64 = 0.0;
     
-    for chance in gof_probabilities.unwrap() {
+    for chance in gof_probabilities {
         expected_frequencies.push(chance * total);
     };
 
     for (i, &num) in matrix.iter().enumerate() {
-        let num_f64: f64 = num.into();
-        statistic += ((num_f64 - expected_frequencies[i]).powi(2)) / expected_frequencies[i];
+        statistic += ((num - expected_frequencies[i]).powi(2)) / expected_frequencies[i];
     };
 
     let df = &matrix.len() - 1;
-    let chi = ChiSquared::new(df as f64).unwrap();
-    let p = 1.0 - chi.cdf(statistic);
+    let (p, log_p) = chi_squared_tail(statistic, df as f64);
 
     let results = ChiSquareResult {
-        test_type: "Pearson's Chi-squared Goodness Of Fit",
+        test_type: GOODNESS_OF_FIT,
         statistic,
         df,
         p,
+        log_p,
+        omitted,
     };
-    if print_output {println!("\n---------------- HYTE ----------------\n\n{}\n\nX^2 test statistic = {:.2}\np-value = {:.3e}\nDegrees of freedom = {}\n\n---------------- HYTE ----------------\n", results.test_type, results.statistic, results.p, results.df);}
     
     Some(results)
 }
 
 impl ChiSquareResult {
     /// Concludes if a `ChiSquareResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
-    pub fn conclude(&self, significance_level: f64, print_output: bool) -> Conclusion {
-        if self.p < significance_level {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = ({:.3e})\n\np-value < s. l, therefore reject H_0\n\nThere is sufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p, significance_level);};
-            Conclusion::Reject
-        } else {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = ({:.3e})\n\np-value > s. l, therefore do not reject H_0\n\nThere is insufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p, significance_level);};
-            Conclusion::DoNotReject
-        }
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
     }
 
     /// Concludes if a `ChiSquareResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
-    pub fn conclude_by_convention(&self, print_output: bool) -> Conclusion {
-        if self.p < 0.05 {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = 0.5\n\np-value < s. l, therefore reject H_0\n\nThere is sufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p);};
-            Conclusion::Reject
-        } else {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = 0.5\n\np-value > s. l, therefore do not reject H_0\n\nThere is insufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p);};
-            Conclusion::DoNotReject
-        }
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
     }
+}
 
+impl PValue for ChiSquareResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for ChiSquareResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .row("X^2 test statistic", format!("{:.2}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .row("Degrees of freedom", self.df)
+            .apa(format!("χ²({}) = {:.2}, {}", self.df, self.statistic, report::apa_p(self.p)))
+    }
 }
@@ -0,0 +1,734 @@
+//! Module responsible for all items needed to run tests from the `hyte` command-line binary.
+//!
+//! Columns are read from a CSV or TSV file, or from the standard input, and selected by header name or by
+//! 1-based index. Empty cells, `NA`, `NaN`, and `null` are missing observations. This module is only available
+//! with the `cli` feature.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use statrs::distribution::{ContinuousCDF, Exp, Normal, Uniform};
+use crate::{binomial, chisquare, correlation, ks, normality, poisson, registry, t, z};
+use crate::record::{Record, TestResult};
+use crate::report::{Format, Report, Reportable};
+use crate::utils::{Alternative, Conclusion, Matrix, MissingPolicy, PValue, Tails, clean, clean_rows};
+
+/// The exit code when the null hypothesis is not rejected.
+pub const EXIT_DO_NOT_REJECT: u8 = 0;
+/// The exit code when the null hypothesis is rejected.
+pub const EXIT_REJECT: u8 = 1;
+/// The exit code when the input could not be read or the test could not be performed.
+pub const EXIT_ERROR: u8 = 2;
+
+#[derive(Parser)]
+#[derive(Debug)]
+#[command(name = "hyte", version, about = "Run hypothesis tests on columns of CSV and TSV files.")]
+#[command(after_help = "Exit codes: 0 if the null hypothesis is not rejected, 1 if it is rejected, and 2 on errors.")]
+pub struct CommandLines {
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Subcommand)]
+#[derive(Debug)]
+pub enum SubCommands {
+    #[command(about = summary("z::test"), long_about = description("z::test"))]
+    Z(MeanArgs),
+    #[command(about = summary("t::test"), long_about = description("t::test"))]
+    T(MeanArgs),
+    #[command(about = summary("t::test_two_samples"), long_about = description("t::test_two_samples"))]
+    T2(TwoSampleArgs),
+    #[command(about = summary("t::test_paired"), long_about = description("t::test_paired"))]
+    Paired(TwoSampleArgs),
+    /// Pearson's Chi-squared test of independence on a contingency table of counts
+    #[command(long_about = description("chisquare::test"))]
+    ChisqToi(TableArgs),
+    /// Pearson's Chi-squared goodness of fit test on a column of counts
+    #[command(long_about = description("chisquare::test"))]
+    ChisqGof(GofArgs),
+    /// Test of normality of a column, Shapiro-Wilk by default
+    #[command(long_about = description("normality::shapiro_wilk"))]
+    Normality(NormalityArgs),
+    #[command(about = summary("ks::test"), long_about = description("ks::test"))]
+    Ks(KsArgs),
+    #[command(about = summary("ks::test_two_samples"), long_about = description("ks::test_two_samples"))]
+    Ks2(ColumnPairArgs),
+    /// Test of correlation between two columns, Pearson's by default
+    #[command(long_about = description("correlation::pearson"))]
+    Correlation(CorrelationArgs),
+    /// Exact binomial test of the proportion of 1s in a column of 0s and 1s
+    #[command(long_about = description("binomial::test"))]
+    Binomial(BinomialArgs),
+    /// Exact Poisson test of the rate of events in a column of counts
+    #[command(long_about = description("poisson::test"))]
+    Poisson(PoissonArgs),
+    /// Serve every test as a JSON endpoint over HTTP
+    #[cfg(feature = "serve")]
+    Serve(ServeArgs),
+    /// Run tests interactively on a loaded file, or replay an exported session
+    #[cfg(feature = "repl")]
+    Repl(ReplArgs),
+}
+
+/// Returns the summary of the test at `name` in the registry.
+fn summary(name: &str) -> &'static str {
+    registry::find(name).unwrap().summary
+}
+
+/// Returns the summary and assumptions of the test at `name` in the registry.
+fn description(name: &str) -> String {
+    registry::find(name).unwrap().description()
+}
+
+/// Where the data is read from, and how.
+#[derive(Args)]
+#[derive(Debug)]
+pub struct InputArgs {
+    /// CSV or TSV file to read, or `-` for the standard input
+    #[arg(short, long, value_name = "PATH", default_value = "-")]
+    pub file: String,
+    /// Field delimiter [default: a tab for .tsv and .tab files, otherwise a comma]
+    #[arg(short, long, value_name = "CHAR")]
+    pub delimiter: Option<char>,
+    /// Read the first row as data rather than as column names
+    #[arg(long)]
+    pub no_header: bool,
+    /// How missing observations are treated
+    #[arg(long, value_enum, default_value_t = MissingPolicy::Omit)]
+    pub missing: MissingPolicy,
+}
+
+/// How the result is concluded and printed.
+#[derive(Args)]
+#[derive(Debug)]
+pub struct OutputArgs {
+    /// Significance level the p-value is compared with, between 0 and 1
+    #[arg(short, long, default_value_t = 0.05, value_parser = significance_level)]
+    pub alpha: f64,
+    /// Output format
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+/// Parses `--alpha`, which must lie strictly between 0 and 1 as in `serve`.
+fn significance_level(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(alpha) if alpha > 0.0 && alpha < 1.0 => Ok(alpha),
+        _ => Err(format!("`{}` is not a number between 0 and 1", value)),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Markdown,
+    Latex,
+    Apa,
+    /// A versioned `record::Record`
+    Json,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct MeanArgs {
+    /// Column to test, by name or 1-based index
+    pub column: String,
+    /// Expected mean under the null hypothesis
+    #[arg(short, long)]
+    pub mean: f64,
+    /// Tail of the alternative hypothesis
+    #[arg(short, long, value_enum, default_value_t = Tails::BOTH)]
+    pub tail: Tails,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct TwoSampleArgs {
+    /// First column, by name or 1-based index
+    pub column1: String,
+    /// Second column, by name or 1-based index
+    pub column2: String,
+    /// Difference between the means under the null hypothesis
+    #[arg(long, default_value_t = 0.0)]
+    pub delta: f64,
+    /// Tail of the alternative hypothesis
+    #[arg(short, long, value_enum, default_value_t = Tails::BOTH)]
+    pub tail: Tails,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct TableArgs {
+    /// Columns of the contingency table, by name or 1-based index [default: every column]
+    pub columns: Vec<String>,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct GofArgs {
+    /// Column of observed counts, by name or 1-based index
+    pub column: String,
+    /// Comma-separated expected probabilities of each count [default: uniform]
+    #[arg(short, long, value_delimiter = ',')]
+    pub probabilities: Option<Vec<f64>>,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, ValueEnum)]
+pub enum NormalityMethod {
+    ShapiroWilk,
+    AndersonDarling,
+    DagostinoPearson,
+    JarqueBera,
+    /// The Lilliefors (Kolmogorov-Smirnov) test
+    Lilliefors,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct NormalityArgs {
+    /// Column to test, by name or 1-based index
+    pub column: String,
+    /// Test of normality
+    #[arg(long, value_enum, default_value_t = NormalityMethod::ShapiroWilk)]
+    pub method: NormalityMethod,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, ValueEnum)]
+pub enum DistributionName {
+    /// Takes the mean and standard deviation
+    Normal,
+    /// Takes the minimum and maximum
+    Uniform,
+    /// Takes the rate
+    Exponential,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct KsArgs {
+    /// Column to test, by name or 1-based index
+    pub column: String,
+    /// Fully specified distribution the column is compared with
+    #[arg(value_enum)]
+    pub distribution: DistributionName,
+    /// Parameters of the distribution
+    #[arg(required = true, num_args = 1..=2, allow_negative_numbers = true)]
+    pub parameters: Vec<f64>,
+    /// Tail of the alternative hypothesis
+    #[arg(short, long, value_enum, default_value_t = Tails::BOTH)]
+    pub tail: Tails,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct ColumnPairArgs {
+    /// First column, by name or 1-based index
+    pub column1: String,
+    /// Second column, by name or 1-based index
+    pub column2: String,
+    /// Tail of the alternative hypothesis
+    #[arg(short, long, value_enum, default_value_t = Tails::BOTH)]
+    pub tail: Tails,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, ValueEnum)]
+pub enum CorrelationMethod {
+    Pearson,
+    Spearman,
+    Kendall,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct CorrelationArgs {
+    /// First column, by name or 1-based index
+    pub x: String,
+    /// Second column, by name or 1-based index
+    pub y: String,
+    /// Coefficient of correlation
+    #[arg(long, value_enum, default_value_t = CorrelationMethod::Pearson)]
+    pub method: CorrelationMethod,
+    /// Level of the confidence interval of Pearson's r
+    #[arg(long, default_value_t = 0.95)]
+    pub confidence_level: f64,
+    /// Tail of the alternative hypothesis
+    #[arg(short, long, value_enum, default_value_t = Tails::BOTH)]
+    pub tail: Tails,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct BinomialArgs {
+    /// Column of 0s and 1s, by name or 1-based index, whose 1s are the successes
+    pub column: String,
+    /// Probability of a success under the null hypothesis
+    #[arg(short, long)]
+    pub probability: f64,
+    /// Use the mid-p variant, which is less conservative
+    #[arg(long)]
+    pub mid_p: bool,
+    /// Tail of the alternative hypothesis
+    #[arg(short, long, value_enum, default_value_t = Tails::BOTH)]
+    pub tail: Tails,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+pub struct PoissonArgs {
+    /// Column of event counts, by name or 1-based index
+    pub column: String,
+    /// Rate of events per unit of exposure under the null hypothesis
+    #[arg(short, long)]
+    pub rate: f64,
+    /// Column of the exposure of each count [default: 1 for every count]
+    #[arg(short, long, value_name = "COLUMN")]
+    pub exposure: Option<String>,
+    /// Tail of the alternative hypothesis
+    #[arg(short, long, value_enum, default_value_t = Tails::BOTH)]
+    pub tail: Tails,
+    #[command(flatten)]
+    pub input: InputArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+/// Where the HTTP service listens, and how much it accepts.
+#[cfg(feature = "serve")]
+#[derive(Args)]
+#[derive(Debug)]
+pub struct ServeArgs {
+    /// IP address to listen on
+    #[arg(
+        short('i'),
+        long = "ip",
+        env = "API_IP",
+        value_name = "address",
+        default_value = "0.0.0.0"
+    )]
+    pub listener_ip: String,
+
+    /// TCP port to listen on
+    #[arg(
+        short('p'),
+        long = "port",
+        env = "API_PORT",
+        value_name = "tcp",
+        default_value_t = 8000
+    )]
+    pub listener_port: u16,
+
+    /// Largest accepted request body, in bytes
+    #[arg(long, value_name = "bytes", default_value_t = crate::serve::DEFAULT_MAX_BODY_BYTES)]
+    pub max_body_bytes: usize,
+
+    /// Most trials of a binomial test, or events of an exact Poisson comparison
+    #[arg(long, value_name = "count", default_value_t = crate::serve::Limits::default().max_trials)]
+    pub max_trials: u64,
+
+    /// Largest expected count of an exact Poisson test
+    #[arg(long, value_name = "count", default_value_t = crate::serve::Limits::default().max_expected_count)]
+    pub max_expected_count: f64,
+
+    /// Most resamples of a bootstrap test
+    #[arg(long, value_name = "count", default_value_t = crate::serve::Limits::default().max_resamples)]
+    pub max_resamples: usize,
+
+    /// Number of threads answering requests
+    #[arg(long, value_name = "count", default_value_t = 4)]
+    pub threads: usize,
+}
+
+/// What an interactive session starts with.
+#[cfg(feature = "repl")]
+#[derive(Args)]
+#[derive(Debug)]
+pub struct ReplArgs {
+    /// CSV or TSV file to load when the session starts
+    pub file: Option<String>,
+    /// Run the commands of an exported session instead of reading them interactively
+    #[arg(short, long, value_name = "PATH")]
+    pub script: Option<String>,
+}
+
+/// The printed output of a command together with its conclusion, and the `Record` of its result.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Outcome {
+    pub output: String,
+    pub conclusion: Conclusion,
+    pub record: Record,
+}
+
+impl Outcome {
+    /// Returns `EXIT_REJECT` or `EXIT_DO_NOT_REJECT` depending on the conclusion.
+    pub fn exit_code(&self) -> u8 {
+        match self.conclusion {
+            Conclusion::Reject => EXIT_REJECT,
+            Conclusion::DoNotReject => EXIT_DO_NOT_REJECT,
+        }
+    }
+}
+
+/// A table of cells read from a CSV or TSV file, with the column names if it has a header.
+pub(crate) struct Table {
+    headers: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub(crate) fn read(input: &InputArgs, stdin: impl Read) -> Result<Table, String> {
+        let delimiter = input.delimiter.unwrap_or(if input.file.ends_with(".tsv") || input.file.ends_with(".tab") { '\t' } else { ',' });
+        if !delimiter.is_ascii() { return Err(format!("the delimiter `{}` is not an ASCII character", delimiter)) };
+        let source: Box<dyn Read + '_> = if input.file == "-" {
+            Box::new(stdin)
+        } else {
+            Box::new(File::open(&input.file).map_err(|error| format!("cannot open `{}`: {}", input.file, error))?)
+        };
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter as u8)
+            .has_headers(!input.no_header)
+            .flexible(true)
+            .from_reader(source);
+        let headers = if input.no_header {
+            None
+        } else {
+            Some(reader.headers().map_err(|error| error.to_string())?.iter().map(|name| name.trim().to_string()).collect())
+        };
+        let rows = reader.records()
+            .map(|record| record.map(|record| record.iter().map(str::to_string).collect()))
+            .collect::<Result<_, _>>()
+            .map_err(|error| error.to_string())?;
+        Ok(Table { headers, rows })
+    }
+
+    fn width(&self) -> usize {
+        self.headers.as_ref().map_or(0, Vec::len).max(self.rows.iter().map(Vec::len).max().unwrap_or(0))
+    }
+
+    #[cfg(feature = "repl")]
+    pub(crate) fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the name of every column, or its 1-based index if the table has no header.
+    #[cfg(feature = "repl")]
+    pub(crate) fn names(&self) -> Vec<String> {
+        (0..self.width()).map(|index| {
+            self.headers.as_ref().and_then(|headers| headers.get(index)).cloned().unwrap_or_else(|| (index + 1).to_string())
+        }).collect()
+    }
+
+    /// Returns the name and index of the column selected by `selector`, a header name or a 1-based index.
+    fn select(&self, selector: &str) -> Result<(String, usize), String> {
+        if let Some(index) = self.headers.as_ref().and_then(|headers| headers.iter().position(|name| name == selector)) {
+            return Ok((selector.to_string(), index));
+        }
+        match selector.parse::<usize>() {
+            Ok(number) if number >= 1 && number <= self.width() => {
+                let name = self.headers.as_ref().and_then(|headers| headers.get(number - 1)).cloned().unwrap_or_else(|| selector.to_string());
+                Ok((name, number - 1))
+            },
+            _ => Err(format!("there is no column named or numbered `{}`", selector)),
+        }
+    }
+
+    /// Returns the name and values of the column selected by `selector`, with missing cells as `None`.
+    pub(crate) fn column(&self, selector: &str) -> Result<(String, Vec<Option<f64>>), String> {
+        let (name, index) = self.select(selector)?;
+        let first_row = if self.headers.is_some() { 2 } else { 1 };
+        let values = self.rows.iter().enumerate().map(|(row, cells)| {
+            let cell = cells.get(index).map_or("", |cell| cell.trim());
+            match cell {
+                "" | "NA" | "NaN" | "nan" | "null" => Ok(None),
+                _ => cell.parse::<f64>().map(Some).map_err(|_| format!("row {}, column `{}`: `{}` is not a number", row + first_row, name, cell)),
+            }
+        }).collect::<Result<_, _>>()?;
+        Ok((name, values))
+    }
+}
+
+/// A distribution named on the command line, for `ks`.
+type Distribution = Box<dyn ContinuousCDF<f64, f64>>;
+
+/// Returns the sum of `values`, which must be whole numbers from 0 to `most`, such as outcomes or counts of events.
+fn count(values: &[f64], most: f64) -> Result<u64, String> {
+    if let Some(value) = values.iter().find(|&&value| !(0.0..=most).contains(&value) || value.fract() != 0.0) {
+        return Err(match value.is_nan() {
+            true => "missing observations cannot be counted; use `--missing omit`".to_string(),
+            false if most == 1.0 => format!("`{}` is not 0 or 1", value),
+            false => format!("`{}` is not a count", value),
+        });
+    }
+    Ok(values.iter().sum::<f64>() as u64)
+}
+
+/// Builds the `Alternative` for `tail` with an explicit `null_value`.
+fn alternative(tail: Tails, null_value: f64) -> Alternative {
+    match tail {
+        Tails::LOWER => Alternative::less(null_value),
+        Tails::UPPER => Alternative::greater(null_value),
+        Tails::BOTH => Alternative::two_sided(null_value),
+    }
+}
+
+/// Concludes `result` at the requested significance level and renders it, as a report with an extra
+/// `Conclusion` row or as a JSON `Record` built by `record`.
+fn finish<R: PValue + Reportable + Into<TestResult>>(result: Option<R>, output: &OutputArgs, record: impl FnOnce(Record) -> Record) -> Result<Outcome, String> {
+    let result = result.ok_or("there is not enough data to perform the test")?;
+    // A NaN p-value would otherwise be concluded as `DoNotReject` and exit with success
+    if result.p_value().is_nan() { return Err("the test has no p-value, as when missing observations are kept under `--missing propagate`".to_string()) };
+    let conclusion = crate::utils::conclude(result.p_value(), output.alpha);
+    let report = result.report().row("Conclusion", format!("{:?} at significance level {}", conclusion, output.alpha));
+    let record = record(Record::new(result)).conclude(output.alpha);
+    let output = render(report, &record, output.format)?;
+    Ok(Outcome { output, conclusion, record })
+}
+
+/// Renders `report` in `format`, or `record` as JSON.
+pub(crate) fn render(report: Report, record: &Record, format: OutputFormat) -> Result<String, String> {
+    let format = match format {
+        OutputFormat::Text => Format::Text,
+        OutputFormat::Markdown => Format::Markdown,
+        OutputFormat::Latex => Format::Latex,
+        OutputFormat::Apa => Format::Apa,
+        OutputFormat::Json => return serde_json::to_string_pretty(record).map_err(|error| error.to_string()),
+    };
+    Ok(report.render(format))
+}
+
+/// Reads the data for `command` from its file, or from `stdin` if the file is `-`, then performs and renders the test.
+/// Invalid input, including anything that makes the test itself panic, is returned as an `Err` message.
+pub fn execute(command: &SubCommands, stdin: impl Read) -> Result<Outcome, String> {
+    let input = match command {
+        SubCommands::Z(args) | SubCommands::T(args) => &args.input,
+        SubCommands::T2(args) | SubCommands::Paired(args) => &args.input,
+        SubCommands::ChisqToi(args) => &args.input,
+        SubCommands::ChisqGof(args) => &args.input,
+        SubCommands::Normality(args) => &args.input,
+        SubCommands::Ks(args) => &args.input,
+        SubCommands::Ks2(args) => &args.input,
+        SubCommands::Correlation(args) => &args.input,
+        SubCommands::Binomial(args) => &args.input,
+        SubCommands::Poisson(args) => &args.input,
+        #[cfg(feature = "serve")]
+        SubCommands::Serve(_) => return Err("`serve` does not read a table; run it with `cli::main`".to_string()),
+        #[cfg(feature = "repl")]
+        SubCommands::Repl(_) => return Err("`repl` does not read a table; run it with `cli::main`".to_string()),
+    };
+    let table = Table::read(input, stdin)?;
+    perform(command, &table)
+}
+
+/// Performs and renders the test of `command` on the columns of `table`, returning panics as `Err` messages.
+pub(crate) fn perform(command: &SubCommands, table: &Table) -> Result<Outcome, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| run(command, table))) {
+        Ok(outcome) => outcome,
+        Err(payload) => {
+            let message = payload.downcast_ref::<String>().map(String::as_str).or_else(|| payload.downcast_ref::<&str>().copied()).unwrap_or("the test failed");
+            Err(message.trim().trim_start_matches("[HYTE-Panic] ").to_string())
+        },
+    }
+}
+
+fn run(command: &SubCommands, table: &Table) -> Result<Outcome, String> {
+    match command {
+        SubCommands::Z(args) | SubCommands::T(args) => {
+            let (name, data) = table.column(&args.column)?;
+            let alternative = alternative(args.tail, args.mean);
+            let record = |record: Record| record.input(&name, &data).parameter("expected_mean", args.mean).alternative(alternative);
+            match command {
+                SubCommands::Z(_) => finish(z::test(&data, args.mean, alternative, args.input.missing), &args.output, record),
+                _ => finish(t::test(&data, args.mean, alternative, args.input.missing), &args.output, record),
+            }
+        },
+        SubCommands::T2(args) | SubCommands::Paired(args) => {
+            let (name1, data1) = table.column(&args.column1)?;
+            let (name2, data2) = table.column(&args.column2)?;
+            let alternative = alternative(args.tail, args.delta);
+            let result = match command {
+                SubCommands::T2(_) => t::test_two_samples(&data1, &data2, alternative, args.input.missing),
+                _ => t::test_paired(&data1, &data2, alternative, args.input.missing),
+            };
+            finish(result, &args.output, |record| record.input(&name1, &data1).input(&name2, &data2).parameter("delta", args.delta).alternative(alternative))
+        },
+        SubCommands::ChisqToi(args) => {
+            let selectors: Vec<String> = if args.columns.is_empty() { (1..=table.width()).map(|number| number.to_string()).collect() } else { args.columns.clone() };
+            let columns = selectors.iter().map(|selector| table.column(selector)).collect::<Result<Vec<_>, _>>()?;
+            let values: Vec<&[Option<f64>]> = columns.iter().map(|(_, values)| values.as_slice()).collect();
+            let rows: Vec<Vec<Option<f64>>> = (0..values.first().map_or(0, |column| column.len())).map(|row| values.iter().map(|column| column[row]).collect()).collect();
+            let result = chisquare::test("toi", Matrix::TwoDimensional(rows), None, args.input.missing);
+            finish(result, &args.output, |record| columns.iter().fold(record, |record, (name, values)| record.input(name, values)))
+        },
+        SubCommands::ChisqGof(args) => {
+            let (name, data) = table.column(&args.column)?;
+            let probabilities = args.probabilities.clone().unwrap_or_else(|| vec![1.0 / data.len() as f64; data.len()]);
+            let result = chisquare::test("gof", Matrix::OneDimensional(&data), Some(&probabilities), args.input.missing);
+            finish(result, &args.output, |record| record.input(&name, &data).input("probabilities", &probabilities))
+        },
+        SubCommands::Normality(args) => {
+            let (name, data) = table.column(&args.column)?;
+            let missing = args.input.missing;
+            let record = |record: Record| record.input(&name, &data);
+            match args.method {
+                NormalityMethod::ShapiroWilk => finish(normality::shapiro_wilk(&data, missing), &args.output, record),
+                NormalityMethod::AndersonDarling => finish(normality::anderson_darling(&data, missing), &args.output, record),
+                NormalityMethod::DagostinoPearson => finish(normality::dagostino_pearson(&data, missing), &args.output, record),
+                NormalityMethod::JarqueBera => finish(normality::jarque_bera(&data, missing), &args.output, record),
+                NormalityMethod::Lilliefors => finish(ks::lilliefors(&data, missing), &args.output, record),
+            }
+        },
+        SubCommands::Ks(args) => {
+            let (name, data) = table.column(&args.column)?;
+            let invalid = |error: statrs::StatsError| format!("invalid distribution: {}", error);
+            let (distribution, parameters): (Distribution, Vec<(&str, f64)>) = match (args.distribution, args.parameters.as_slice()) {
+                (DistributionName::Normal, &[mean, sd]) => (Box::new(Normal::new(mean, sd).map_err(invalid)?), vec![("mean", mean), ("sd", sd)]),
+                (DistributionName::Uniform, &[min, max]) => (Box::new(Uniform::new(min, max).map_err(invalid)?), vec![("min", min), ("max", max)]),
+                (DistributionName::Exponential, &[rate]) => (Box::new(Exp::new(rate).map_err(invalid)?), vec![("rate", rate)]),
+                _ => return Err("the normal and uniform distributions take 2 parameters, and the exponential distribution 1".to_string()),
+            };
+            let result = ks::test(&data, distribution.as_ref(), args.tail, args.input.missing);
+            finish(result, &args.output, |record| parameters.iter().fold(record.input(&name, &data), |record, &(parameter, value)| record.parameter(parameter, value)).alternative(args.tail))
+        },
+        SubCommands::Ks2(args) => {
+            let (name1, data1) = table.column(&args.column1)?;
+            let (name2, data2) = table.column(&args.column2)?;
+            let result = ks::test_two_samples(&data1, &data2, args.tail, args.input.missing);
+            finish(result, &args.output, |record| record.input(&name1, &data1).input(&name2, &data2).alternative(args.tail))
+        },
+        SubCommands::Correlation(args) => {
+            let (name_x, x) = table.column(&args.x)?;
+            let (name_y, y) = table.column(&args.y)?;
+            let missing = args.input.missing;
+            let inputs = |record: Record| record.input(&name_x, &x).input(&name_y, &y).alternative(args.tail);
+            match args.method {
+                CorrelationMethod::Pearson => finish(correlation::pearson(&x, &y, args.tail, args.confidence_level, missing), &args.output, |record| inputs(record).parameter("confidence_level", args.confidence_level)),
+                CorrelationMethod::Spearman => finish(correlation::spearman(&x, &y, args.tail, missing), &args.output, inputs),
+                CorrelationMethod::Kendall => finish(correlation::kendall(&x, &y, args.tail, missing), &args.output, inputs),
+            }
+        },
+        SubCommands::Binomial(args) => {
+            let (name, data) = table.column(&args.column)?;
+            let (outcomes, _) = clean(&data, args.input.missing);
+            let (successes, trials) = (count(&outcomes, 1.0)?, outcomes.len() as u64);
+            let alternative = alternative(args.tail, args.probability);
+            let result = match args.mid_p {
+                false => binomial::test(successes, trials, args.probability, alternative, binomial::Interval::ClopperPearson, 0.95),
+                true => binomial::mid_p_test(successes, trials, args.probability, alternative, binomial::Interval::ClopperPearson, 0.95),
+            };
+            finish(result, &args.output, |record| record.input(&name, &data).parameter("expected_probability", args.probability).alternative(alternative))
+        },
+        SubCommands::Poisson(args) => {
+            let (name, data) = table.column(&args.column)?;
+            let exposures = args.exposure.as_deref().map(|selector| table.column(selector)).transpose()?;
+            let (counts, exposure) = match &exposures {
+                Some((_, exposures)) => {
+                    let (kept, _) = clean_rows(&[&data, exposures], args.input.missing);
+                    (kept[0].clone(), kept[1].iter().sum())
+                },
+                None => {
+                    let (counts, _) = clean(&data, args.input.missing);
+                    let exposure = counts.len() as f64;
+                    (counts, exposure)
+                },
+            };
+            let alternative = alternative(args.tail, args.rate);
+            let result = poisson::test(count(&counts, f64::INFINITY)?, exposure, args.rate, alternative, 0.95);
+            finish(result, &args.output, |record| {
+                let record = record.input(&name, &data).parameter("rate", args.rate).parameter("exposure", exposure).alternative(alternative);
+                match &exposures {
+                    Some((exposure_name, exposures)) => record.input(exposure_name, exposures),
+                    None => record,
+                }
+            })
+        },
+        #[cfg(feature = "serve")]
+        SubCommands::Serve(_) => unreachable!(),
+        #[cfg(feature = "repl")]
+        SubCommands::Repl(_) => unreachable!(),
+    }
+}
+
+/// Parses the command line, runs the command on the standard input or a file, and prints the result.
+/// Returns `EXIT_REJECT`, `EXIT_DO_NOT_REJECT`, or `EXIT_ERROR` after printing the error to the standard error.
+pub fn main() -> u8 {
+    // Panics on invalid input are printed as errors by `perform`, so only other panics, which are bugs, reach the default hook
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let payload = info.payload();
+        let message = payload.downcast_ref::<String>().map(String::as_str).or_else(|| payload.downcast_ref::<&str>().copied());
+        if !message.is_some_and(|message| message.trim_start().starts_with("[HYTE-Panic]")) { default_hook(info) };
+    }));
+    let command = CommandLines::parse();
+    #[cfg(feature = "serve")]
+    if let SubCommands::Serve(args) = &command.subcommand {
+        return serve(args);
+    }
+    #[cfg(feature = "repl")]
+    if let SubCommands::Repl(args) = &command.subcommand {
+        return crate::repl::main(args.file.as_deref(), args.script.as_deref());
+    }
+    match execute(&command.subcommand, io::stdin().lock()) {
+        Ok(outcome) => {
+            println!("{}", outcome.output);
+            outcome.exit_code()
+        },
+        Err(message) => {
+            eprintln!("hyte: {}", message);
+            EXIT_ERROR
+        },
+    }
+}
+
+#[cfg(feature = "serve")]
+fn serve(args: &ServeArgs) -> u8 {
+    let limits = crate::serve::Limits { max_trials: args.max_trials, max_expected_count: args.max_expected_count, max_resamples: args.max_resamples };
+    match crate::serve::Server::bind((args.listener_ip.as_str(), args.listener_port), args.max_body_bytes) {
+        Ok(server) => {
+            let server = server.limits(limits);
+            eprintln!("hyte: listening on http://{}", server.local_addr());
+            server.run(args.threads);
+            EXIT_DO_NOT_REJECT
+        },
+        Err(error) => {
+            eprintln!("hyte: cannot listen on {}:{}: {}", args.listener_ip, args.listener_port, error);
+            EXIT_ERROR
+        },
+    }
+}
@@ -0,0 +1,546 @@
+//! Module responsible for all items needed to test the significance of correlations.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Tails, Conclusion, PValue, average_ranks, conclude, normal_tail, t_tail, symmetric_p, MissingPolicy, Observation, clean_rows, tailed, TestType};
+use crate::z::{self, ZResult};
+
+/// Largest sample size for which Spearman's rho uses an exact permutation p-value.
+const EXACT_SPEARMAN_LIMIT: usize = 9;
+
+/// Largest sample size for which Kendall's tau uses an exact p-value.
+const EXACT_KENDALL_LIMIT: usize = 50;
+
+/// A struct for storing the estimated correlation coefficient, test statistic, and p-value from correlation tests.
+///
+/// `confidence_interval` is only computed for Pearson's r. `log_p` is the natural logarithm of the p-value,
+/// which remains finite even when `p` underflows to 0.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CorrelationResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<(crate::utils::non_finite::Float, crate::utils::non_finite::Float)>"))]
+    pub confidence_interval: Option<(f64, f64)>,
+    pub hypotheses: String,
+    pub omitted: usize,
+}
+
+/// Lower-tailed, upper-tailed, and two-sided test type names of each correlation test.
+const PEARSON: [TestType; 3] = [
+    "Pearson's Product-Moment Correlation (Lower-Tailed)",
+    "Pearson's Product-Moment Correlation (Upper-Tailed)",
+    "Pearson's Product-Moment Correlation (Two-Sided)",
+];
+const PEARSON_FISHER: [TestType; 3] = [
+    "Pearson's Product-Moment Correlation (Lower-Tailed, Fisher z)",
+    "Pearson's Product-Moment Correlation (Upper-Tailed, Fisher z)",
+    "Pearson's Product-Moment Correlation (Two-Sided, Fisher z)",
+];
+const SPEARMAN_EXACT: [TestType; 3] = [
+    "Spearman's Rank Correlation (Lower-Tailed, Exact)",
+    "Spearman's Rank Correlation (Upper-Tailed, Exact)",
+    "Spearman's Rank Correlation (Two-Sided, Exact)",
+];
+const SPEARMAN: [TestType; 3] = [
+    "Spearman's Rank Correlation (Lower-Tailed)",
+    "Spearman's Rank Correlation (Upper-Tailed)",
+    "Spearman's Rank Correlation (Two-Sided)",
+];
+const KENDALL_EXACT: [TestType; 3] = [
+    "Kendall's Tau-b Rank Correlation (Lower-Tailed, Exact)",
+    "Kendall's Tau-b Rank Correlation (Upper-Tailed, Exact)",
+    "Kendall's Tau-b Rank Correlation (Two-Sided, Exact)",
+];
+const KENDALL: [TestType; 3] = [
+    "Kendall's Tau-b Rank Correlation (Lower-Tailed)",
+    "Kendall's Tau-b Rank Correlation (Upper-Tailed)",
+    "Kendall's Tau-b Rank Correlation (Two-Sided)",
+];
+const INDEPENDENT_DIFFERENCE: [TestType; 3] = [
+    "One-Sided Z-Test for Difference of Independent Correlations (Lower-Tailed)",
+    "One-Sided Z-Test for Difference of Independent Correlations (Upper-Tailed)",
+    "Two-Sided Z-Test for Difference of Independent Correlations",
+];
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![PEARSON, PEARSON_FISHER, SPEARMAN_EXACT, SPEARMAN, KENDALL_EXACT, KENDALL, INDEPENDENT_DIFFERENCE];
+
+/// Applies the missing-data policy pairwise, returning the complete pairs and the number of omitted pairs.
+fn paired_f64<Number: Observation>(x: &[Number], y: &[Number], minimum: usize, missing: MissingPolicy) -> Option<(Vec<f64>, Vec<f64>, usize)> {
+    if x.is_empty() || y.is_empty() { return None };
+    if x.len() != y.len() {
+        panic!("\n[HYTE-Panic] The lengths of x ({}) and y ({}) do not match!\n", x.len(), y.len());
+    }
+    let (mut columns, omitted) = clean_rows(&[x, y], missing);
+    let (y, x) = (columns.pop().unwrap(), columns.pop().unwrap());
+    if x.len() < minimum {
+        panic!("\n[HYTE-Panic] Correlation tests require at least {} pairs of observations!\n", minimum);
+    }
+    Some((x, y, omitted))
+}
+
+fn pearson_r(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let x_mean = x.iter().sum::<f64>() / n;
+    let y_mean = y.iter().sum::<f64>() / n;
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (&a, &b) in x.iter().zip(y) {
+        sxy += (a - x_mean) * (b - y_mean);
+        sxx += (a - x_mean).powi(2);
+        syy += (b - y_mean).powi(2);
+    }
+    if sxx == 0.0 || syy == 0.0 { panic!("\n[HYTE-Panic] Correlation is undefined when x or y has zero variance!\n") };
+    (sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Calculates the t statistic for a correlation coefficient, and its p-value and log p-value with `n - 2` degrees of freedom.
+fn correlation_t(r: f64, n: usize, tail: &Tails) -> (f64, (f64, f64)) {
+    let df = (n - 2) as f64;
+    let statistic = r * (df / (1.0 - r * r)).sqrt();
+    (statistic, symmetric_p(statistic, tail, |t| t_tail(t, df)))
+}
+
+/// Combines the exact lower and upper tail probabilities of a permutation distribution into a p-value and log p-value.
+fn one_sided_p(lower: f64, upper: f64, tail: &Tails) -> (f64, f64) {
+    let p = match tail {
+        Tails::LOWER => lower,
+        Tails::UPPER => upper,
+        Tails::BOTH => (2.0 * lower.min(upper)).min(1.0),
+    };
+    (p, p.ln())
+}
+
+/// Calculates Pearson's product-moment correlation coefficient r, its t statistic with `n - 2` degrees
+/// of freedom, and a confidence interval at `confidence_level` based on Fisher's z transform.
+///
+/// `Tails::UPPER` tests for a positive correlation and `Tails::LOWER` for a negative one.
+/// For one-sided tests the confidence interval is one-sided, matching the alternative.
+/// To test a non-zero correlation ρ₀, pass in an `Alternative` such as `Alternative::greater(0.3)`; the
+/// statistic is then Fisher's z, `(atanh(r) - atanh(ρ₀)) * sqrt(n - 3)`, compared with the standard normal.
+/// The confidence interval requires at least 4 pairs of observations.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::correlation;
+/// use hyte::utils::{Tails, MissingPolicy};
+///
+/// fn main() {
+///     let x = vec![44.4, 45.9, 41.9, 53.3, 44.7, 44.1, 50.7, 45.2, 60.1];
+///     let y = vec![ 2.6,  3.1,  2.5,  5.0,  3.6,  4.0,  5.2,  2.8,  3.8];
+///     let unwrapped_results = correlation::pearson(x, y, Tails::BOTH, 0.95, MissingPolicy::Propagate);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Pearson's Product-Moment Correlation (Two-Sided)");
+///     assert_eq!((results.estimate * 1e6).round() / 1e6, 0.571182);
+///     assert_eq!((results.statistic * 1e4).round() / 1e4, 1.8411);
+///     assert_eq!((results.p * 1e4).round() / 1e4, 0.1082);
+/// }
+/// ```
+pub fn pearson<Number: Observation>(x: impl AsRef<[Number]>, y: impl AsRef<[Number]>, alternative: impl Into<Alternative>, confidence_level: f64, missing: MissingPolicy) -> Option<CorrelationResult> {
+    let (x, y) = (x.as_ref(), y.as_ref());
+    let alternative = alternative.into().or_null(0.0);
+    let null_value = alternative.null_value.unwrap();
+    if !(-1.0 < null_value && null_value < 1.0) { panic!("\n[HYTE-Panic] Correlation coefficients must be between -1 and 1!\n") };
+    let tail = alternative.tails();
+    let (x, y, omitted) = paired_f64(x, y, if null_value == 0.0 { 3 } else { 4 }, missing)?;
+    if !(0.0..1.0).contains(&confidence_level) { panic!("\n[HYTE-Panic] Confidence level must be in the interval [0, 1)!\n") };
+    let n = x.len();
+    let estimate = pearson_r(&x, &y);
+    let (statistic, (p, log_p)) = if null_value == 0.0 {
+        correlation_t(estimate, n, &tail)
+    } else {
+        let statistic = (estimate.atanh() - null_value.atanh()) * ((n - 3) as f64).sqrt();
+        (statistic, symmetric_p(statistic, &tail, normal_tail))
+    };
+
+    let confidence_interval = if n > 3 {
+        let fisher_z = estimate.atanh();
+        let se = 1.0 / ((n - 3) as f64).sqrt();
+        let standard_normal = Normal::new(0.0, 1.0).unwrap();
+        Some(match tail {
+            Tails::LOWER => (-1.0, (fisher_z + standard_normal.inverse_cdf(confidence_level) * se).tanh()),
+            Tails::UPPER => ((fisher_z - standard_normal.inverse_cdf(confidence_level) * se).tanh(), 1.0),
+            Tails::BOTH => {
+                let q = standard_normal.inverse_cdf((1.0 + confidence_level) / 2.0);
+                ((fisher_z - q * se).tanh(), (fisher_z + q * se).tanh())
+            },
+        })
+    } else {
+        None
+    };
+
+    let test_type = tailed(if null_value == 0.0 { PEARSON } else { PEARSON_FISHER }, &tail);
+
+    let results = CorrelationResult {
+        test_type,
+        estimate,
+        statistic,
+        p,
+        log_p,
+        confidence_interval,
+        hypotheses: alternative.statement("ρ"),
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Sums `(rank - position)^2` over every permutation of `0..n`, counting how many are at least as large
+/// (`at_least`) and at most as large (`at_most`) as the observed sum of squared rank differences.
+fn spearman_permutation_counts(n: usize, observed: f64) -> (u64, u64, u64) {
+    // Heap's algorithm
+    let mut permutation: Vec<usize> = (0..n).collect();
+    let mut c = vec![0; n];
+    let squared_sum = |p: &[usize]| p.iter().enumerate().map(|(i, &v)| ((v as f64) - i as f64).powi(2)).sum::<f64>();
+    let tolerance = 1e-9;
+    let (mut at_least, mut at_most, mut total) = (0u64, 0u64, 0u64);
+    let mut tally = |s: f64| {
+        if s >= observed - tolerance { at_least += 1 };
+        if s <= observed + tolerance { at_most += 1 };
+        total += 1;
+    };
+    tally(squared_sum(&permutation));
+    let mut i = 0;
+    while i < n {
+        if c[i] < i {
+            if i % 2 == 0 { permutation.swap(0, i) } else { permutation.swap(c[i], i) };
+            tally(squared_sum(&permutation));
+            c[i] += 1;
+            i = 0;
+        } else {
+            c[i] = 0;
+            i += 1;
+        }
+    }
+    (at_least, at_most, total)
+}
+
+/// Calculates Spearman's rank correlation coefficient rho, i.e. Pearson's r of the average ranks.
+///
+/// For up to 9 pairs without ties, the p-value is exact, obtained by enumerating every permutation of the ranks.
+/// Otherwise it is approximated with a t distribution with `n - 2` degrees of freedom.
+/// `statistic` is always the t statistic of rho.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::correlation;
+/// use hyte::utils::{Tails, MissingPolicy};
+///
+/// fn main() {
+///     let x = vec![44.4, 45.9, 41.9, 53.3, 44.7, 44.1, 50.7, 45.2, 60.1];
+///     let y = vec![ 2.6,  3.1,  2.5,  5.0,  3.6,  4.0,  5.2,  2.8,  3.8];
+///     let results = correlation::spearman(x, y, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Spearman's Rank Correlation (Two-Sided, Exact)");
+///     assert_eq!(results.estimate, 0.6);
+///     assert_eq!((results.p * 1e4).round() / 1e4, 0.0968);
+/// }
+/// ```
+pub fn spearman<Number: Observation>(x: impl AsRef<[Number]>, y: impl AsRef<[Number]>, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<CorrelationResult> {
+    let (x, y) = (x.as_ref(), y.as_ref());
+    let alternative = alternative.into().only_null(0.0, "Spearman's rank correlation test");
+    let tail = alternative.tails();
+    let (x, y, omitted) = paired_f64(x, y, 3, missing)?;
+    let n = x.len();
+    let x_ranks = average_ranks(&x);
+    let y_ranks = average_ranks(&y);
+    let estimate = pearson_r(&x_ranks, &y_ranks);
+    let (statistic, approximate_p) = correlation_t(estimate, n, &tail);
+
+    let has_ties = x_ranks.iter().chain(&y_ranks).any(|rank| rank.fract() != 0.0);
+    let exact = n <= EXACT_SPEARMAN_LIMIT && !has_ties;
+
+    let (p, log_p) = if exact {
+        // Order y's ranks by x's ranks, so that the statistic only depends on a single permutation
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| x_ranks[a].total_cmp(&x_ranks[b]));
+        let observed: f64 = order.iter().enumerate().map(|(i, &j)| (y_ranks[j] - 1.0 - i as f64).powi(2)).sum();
+        let (at_least, at_most, total) = spearman_permutation_counts(n, observed);
+        // A small sum of squared differences corresponds to a large positive rho
+        one_sided_p(at_least as f64 / total as f64, at_most as f64 / total as f64, &tail)
+    } else {
+        approximate_p
+    };
+
+    let test_type = tailed(if exact { SPEARMAN_EXACT } else { SPEARMAN }, &tail);
+
+    let results = CorrelationResult {
+        test_type,
+        estimate,
+        statistic,
+        p,
+        log_p,
+        confidence_interval: None,
+        hypotheses: alternative.statement("ρ"),
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Sorts `values` with a merge sort, returning the number of swaps (inversions) performed.
+fn count_inversions(values: &mut [f64]) -> u64 {
+    let n = values.len();
+    if n < 2 { return 0 };
+    let mid = n / 2;
+    let mut inversions = count_inversions(&mut values[..mid]) + count_inversions(&mut values[mid..]);
+    let mut merged = Vec::with_capacity(n);
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < n {
+        if values[j] < values[i] {
+            merged.push(values[j]);
+            inversions += (mid - i) as u64;
+            j += 1;
+        } else {
+            merged.push(values[i]);
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&values[i..mid]);
+    merged.extend_from_slice(&values[j..n]);
+    values.copy_from_slice(&merged);
+    inversions
+}
+
+/// Returns the sizes of groups of tied values in sorted `values`.
+fn tie_sizes(sorted: &[f64]) -> Vec<u64> {
+    let mut sizes = Vec::new();
+    let mut run = 1;
+    for i in 1..=sorted.len() {
+        if i < sorted.len() && sorted[i] == sorted[i - 1] {
+            run += 1;
+        } else {
+            if run > 1 { sizes.push(run) };
+            run = 1;
+        }
+    }
+    sizes
+}
+
+/// Returns the number of permutations of `n` elements with exactly `k` inversions, for every `k`.
+fn inversion_counts(n: usize) -> Vec<f64> {
+    let max = n * (n - 1) / 2;
+    let mut counts = vec![0.0; max + 1];
+    counts[0] = 1.0;
+    for m in 2..=n {
+        let mut next = vec![0.0; max + 1];
+        let mut window = 0.0;
+        for k in 0..=max {
+            window += counts[k];
+            if k >= m { window -= counts[k - m] };
+            next[k] = window;
+        }
+        counts = next;
+    }
+    counts
+}
+
+/// Calculates Kendall's tau-b rank correlation coefficient, which accounts for ties, in O(n log n) time
+/// using Knight's algorithm.
+///
+/// For up to 50 pairs without ties, the p-value is exact. Otherwise it uses a normal approximation with
+/// a tie-corrected variance. `statistic` is always the standardised Z statistic of the difference between
+/// concordant and discordant pairs.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::correlation;
+/// use hyte::utils::{Tails, MissingPolicy};
+///
+/// fn main() {
+///     let x = vec![44.4, 45.9, 41.9, 53.3, 44.7, 44.1, 50.7, 45.2, 60.1];
+///     let y = vec![ 2.6,  3.1,  2.5,  5.0,  3.6,  4.0,  5.2,  2.8,  3.8];
+///     let results = correlation::kendall(x, y, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Kendall's Tau-b Rank Correlation (Two-Sided, Exact)");
+///     assert_eq!((results.estimate * 1e6).round() / 1e6, 0.444444);
+///     assert_eq!((results.p * 1e4).round() / 1e4, 0.1194);
+/// }
+/// ```
+pub fn kendall<Number: Observation>(x: impl AsRef<[Number]>, y: impl AsRef<[Number]>, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<CorrelationResult> {
+    let (x, y) = (x.as_ref(), y.as_ref());
+    let alternative = alternative.into().only_null(0.0, "Kendall's rank correlation test");
+    let tail = alternative.tails();
+    let (x, y, omitted) = paired_f64(x, y, 3, missing)?;
+    let n = x.len();
+
+    let mut pairs: Vec<(f64, f64)> = x.into_iter().zip(y).collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+    let sorted_x: Vec<f64> = pairs.iter().map(|p| p.0).collect();
+    let x_ties = tie_sizes(&sorted_x);
+    let mut joint_ties = 0u64;
+    let mut run = 1u64;
+    for i in 1..=n {
+        if i < n && pairs[i] == pairs[i - 1] {
+            run += 1;
+        } else {
+            joint_ties += run * (run - 1) / 2;
+            run = 1;
+        }
+    }
+
+    let mut sorted_y: Vec<f64> = pairs.iter().map(|p| p.1).collect();
+    let swaps = count_inversions(&mut sorted_y);
+    let y_ties = tie_sizes(&sorted_y);
+
+    let pairs_of = |t: &u64| t * (t - 1) / 2;
+    let n0 = (n * (n - 1) / 2) as f64;
+    let n1 = x_ties.iter().map(pairs_of).sum::<u64>() as f64;
+    let n2 = y_ties.iter().map(pairs_of).sum::<u64>() as f64;
+    let n3 = joint_ties as f64;
+    let s = n0 - n1 - n2 + n3 - 2.0 * swaps as f64;
+    if n0 == n1 || n0 == n2 { panic!("\n[HYTE-Panic] Correlation is undefined when x or y has zero variance!\n") };
+    let estimate = s / ((n0 - n1) * (n0 - n2)).sqrt();
+
+    let an = n as f64;
+    let tie_term = |ties: &[u64], f: fn(f64) -> f64| ties.iter().map(|&t| f(t as f64)).sum::<f64>();
+    let v0 = an * (an - 1.0) * (2.0 * an + 5.0);
+    let vt = tie_term(&x_ties, |t| t * (t - 1.0) * (2.0 * t + 5.0));
+    let vu = tie_term(&y_ties, |t| t * (t - 1.0) * (2.0 * t + 5.0));
+    let v1 = tie_term(&x_ties, |t| t * (t - 1.0)) * tie_term(&y_ties, |t| t * (t - 1.0));
+    let v2 = tie_term(&x_ties, |t| t * (t - 1.0) * (t - 2.0)) * tie_term(&y_ties, |t| t * (t - 1.0) * (t - 2.0));
+    let variance_s = (v0 - vt - vu) / 18.0 + v1 / (2.0 * an * (an - 1.0)) + v2 / (9.0 * an * (an - 1.0) * (an - 2.0));
+    let statistic = s / variance_s.sqrt();
+
+    let exact = n <= EXACT_KENDALL_LIMIT && x_ties.is_empty() && y_ties.is_empty();
+    let (p, log_p) = if exact {
+        // Without ties, `swaps` is the number of discordant pairs, distributed as the inversions of a random permutation
+        let counts = inversion_counts(n);
+        let total: f64 = counts.iter().sum();
+        let discordant = swaps as usize;
+        let at_most = counts[..=discordant].iter().sum::<f64>() / total;
+        let at_least = counts[discordant..].iter().sum::<f64>() / total;
+        // Few discordant pairs corresponds to a large positive tau
+        one_sided_p(at_least, at_most, &tail)
+    } else {
+        symmetric_p(statistic, &tail, normal_tail)
+    };
+
+    let test_type = tailed(if exact { KENDALL_EXACT } else { KENDALL }, &tail);
+
+    let results = CorrelationResult {
+        test_type,
+        estimate,
+        statistic,
+        p,
+        log_p,
+        confidence_interval: None,
+        hypotheses: alternative.statement("τ"),
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Tests whether two Pearson correlations `r1` and `r2`, estimated from independent samples of sizes
+/// `n1` and `n2`, differ. The correlations are Fisher z-transformed and their difference is passed to
+/// `z::test_dataless` with a standard error of `sqrt(1/(n1 - 3) + 1/(n2 - 3))`.
+///
+/// `Tails::UPPER` tests whether `r1` is greater than `r2`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::correlation;
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let unwrapped_results = correlation::compare_independent(0.5, 100, 0.3, 120, Tails::BOTH);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Two-Sided Z-Test for Difference of Independent Correlations");
+///     assert_eq!((results.statistic * 1e4).round() / 1e4, 1.7462);
+///     assert_eq!((results.p * 1e4).round() / 1e4, 0.0808);
+/// }
+/// ```
+pub fn compare_independent(r1: f64, n1: u32, r2: f64, n2: u32, alternative: impl Into<Alternative>) -> Option<ZResult> {
+    let alternative = alternative.into().only_null(0.0, "The comparison of independent correlations");
+    let tail = alternative.tails();
+    if !(-1.0..=1.0).contains(&r1) || !(-1.0..=1.0).contains(&r2) {
+        panic!("\n[HYTE-Panic] Correlation coefficients must be between -1 and 1!\n");
+    }
+    if n1 < 4 || n2 < 4 { panic!("\n[HYTE-Panic] Each sample must contain at least 4 pairs of observations!\n") };
+
+    let difference = r1.atanh() - r2.atanh();
+    let se = (1.0 / (n1 - 3) as f64 + 1.0 / (n2 - 3) as f64).sqrt();
+    let test_type = tailed(INDEPENDENT_DIFFERENCE, &tail);
+
+    // A sample size of 1 makes `z::test_dataless` use `se` directly as the standard error
+    let mut results = z::test_dataless(difference, 0.0, 1, se, tail)?;
+    results.test_type = test_type;
+    results.hypotheses = alternative.statement("ρ₁ - ρ₂");
+
+    Some(results)
+}
+
+impl CorrelationResult {
+    /// Concludes if a `CorrelationResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `CorrelationResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for CorrelationResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for CorrelationResult {
+    fn report(&self) -> Report {
+        let (label, symbol) = if self.test_type.starts_with("Spearman") {
+            ("T", "rₛ")
+        } else if self.test_type.starts_with("Kendall") {
+            ("Z", "τ")
+        } else if self.test_type.contains("Fisher z") {
+            ("Z", "r")
+        } else {
+            ("T", "r")
+        };
+        let mut summary = Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("Estimate", format!("{:.4}", self.estimate))
+            .row(&format!("{} test statistic", label), format!("{:.2}", self.statistic))
+            .row("p-value", report::p_value(self.p));
+        let mut apa = format!("{} = {}", symbol, report::bounded(self.estimate, 2));
+        if let Some((lower, upper)) = self.confidence_interval {
+            summary = summary.row("Confidence interval", format!("({:.4}, {:.4})", lower, upper));
+            apa.push_str(&format!(", CI [{}, {}]", report::bounded(lower, 2), report::bounded(upper, 2)));
+        }
+        summary.apa(format!("{}, {}", apa, report::apa_p(self.p)))
+    }
+}
@@ -0,0 +1,388 @@
+//! Module responsible for all items needed to perform equivalence, non-inferiority, and superiority tests.
+
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Direction, Tails, Conclusion, PValue, conclude, mean, variance, MissingPolicy, Observation, ToPrimitive, clean, clean_rows, to_f64, normal_tail, t_tail, symmetric_p, TestType};
+
+/// An enum for conveniently specifying the hypothesis about the difference `estimate = first - second`
+/// (or `mean - expected_mean`), which converts into an `Alternative` with margins.
+///
+/// Larger differences are assumed to be better. If smaller values are better, swap the two samples, or pass in
+/// an `Alternative` with `Direction::Less` and margins instead.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Hypothesis {
+    /// Two one-sided tests (TOST) that the difference lies strictly between the `(lower, upper)` margins.
+    Equivalence(f64, f64),
+    /// One-sided test that the difference is greater than `-margin`, i.e. no worse by more than `margin`.
+    NonInferiority(f64),
+    /// One-sided test that the difference is greater than `margin`, i.e. better by more than `margin`.
+    Superiority(f64),
+}
+
+impl From<Hypothesis> for Alternative {
+    fn from(hypothesis: Hypothesis) -> Alternative {
+        let (direction, margins) = match hypothesis {
+            Hypothesis::Equivalence(lower, upper) => (Direction::TwoSided, (lower, upper)),
+            Hypothesis::NonInferiority(margin) | Hypothesis::Superiority(margin) => {
+                if margin < 0.0 { panic!("\n[HYTE-Panic] Margin must not be a negative number!\n") };
+                let bound = if let Hypothesis::NonInferiority(_) = hypothesis { -margin } else { margin };
+                (Direction::Greater, (bound, f64::INFINITY))
+            }
+        };
+        Alternative { direction, null_value: None, margins: Some(margins) }
+    }
+}
+
+/// A struct for storing one of the one-sided tests which make up an equivalence test.
+///
+/// `bound` is the margin being tested against, expressed on the scale of the difference.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OneSidedTest {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub bound: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+}
+
+/// A struct for storing the resulting one-sided tests and confidence interval from equivalence tests.
+///
+/// `lower` tests that the difference is above its lower bound, and `upper` that it is below its upper bound.
+/// Both are present for equivalence tests, while a non-inferiority or superiority test only has the one matching
+/// its direction. `p` is the largest of the one-sided p-values, and `log_p` is its natural logarithm.
+/// `confidence_interval` has a confidence level of `1 - 2 * significance_level` (90% for a 0.05 level),
+/// so the hypothesis is accepted exactly when the interval lies within the margins.
+/// `df` is `None` for Z-tests.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EquivalenceResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub standard_error: f64,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub df: Option<f64>,
+    pub lower: Option<OneSidedTest>,
+    pub upper: Option<OneSidedTest>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub confidence_interval: (f64, f64),
+    pub hypotheses: String,
+    pub omitted: usize,
+}
+
+/// Test type names for equivalence, non-inferiority, and superiority tests respectively.
+type TestTypes = [&'static str; 3];
+
+const ONE_SAMPLE: TestTypes = [
+    "(1-Sample) Equivalence T-Test (TOST)",
+    "(1-Sample) Non-Inferiority T-Test",
+    "(1-Sample) Superiority T-Test",
+];
+const PAIRED: TestTypes = [
+    "(Paired) Equivalence T-Test (TOST)",
+    "(Paired) Non-Inferiority T-Test",
+    "(Paired) Superiority T-Test",
+];
+const POOLED: TestTypes = [
+    "(2-Sample) Pooled Equivalence T-Test (TOST)",
+    "(2-Sample) Pooled Non-Inferiority T-Test",
+    "(2-Sample) Pooled Superiority T-Test",
+];
+const WELCH: TestTypes = [
+    "(2-Sample) Welch Equivalence T-Test (TOST)",
+    "(2-Sample) Welch Non-Inferiority T-Test",
+    "(2-Sample) Welch Superiority T-Test",
+];
+const PROPORTIONS: TestTypes = [
+    "(2-Sample) Equivalence Z-Test for Proportions (TOST)",
+    "(2-Sample) Non-Inferiority Z-Test for Proportions",
+    "(2-Sample) Superiority Z-Test for Proportions",
+];
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![ONE_SAMPLE, PAIRED, POOLED, WELCH, PROPORTIONS];
+
+/// Performs the one-sided tests of `alternative` for a difference with a T distribution, or a standard normal
+/// distribution when `df` is `None`. The bounds are `alternative.null_value - baseline` plus the margins,
+/// where `baseline` is the value the difference is measured from.
+#[allow(clippy::too_many_arguments)]
+fn decide(estimate: f64, standard_error: f64, df: Option<f64>, alternative: Alternative, baseline: f64, significance_level: f64, test_types: TestTypes, parameter: &str) -> EquivalenceResult {
+    if !(significance_level > 0.0 && significance_level < 0.5) {
+        panic!("\n[HYTE-Panic] Significance level must be between 0 and 0.5!\n");
+    }
+    let (lower_margin, upper_margin) = match (alternative.margins, alternative.direction) {
+        (Some(margins), _) => margins,
+        (None, Direction::TwoSided) => panic!("\n[HYTE-Panic] Equivalence tests require margins or a one-sided alternative!\n"),
+        (None, _) => (0.0, 0.0),
+    };
+    if alternative.direction == Direction::TwoSided && lower_margin >= upper_margin {
+        panic!("\n[HYTE-Panic] The lower equivalence margin must be less than the upper margin!\n");
+    }
+    let shift = alternative.null_value.unwrap() - baseline;
+
+    let t_distribution;
+    let standard_normal;
+    let distribution: &dyn ContinuousCDF<f64, f64> = match df {
+        Some(df) => {
+            t_distribution = StudentsT::new(0.0, 1.0, df).unwrap();
+            &t_distribution
+        }
+        None => {
+            standard_normal = Normal::new(0.0, 1.0).unwrap();
+            &standard_normal
+        }
+    };
+    let quantile = distribution.inverse_cdf(1.0 - significance_level);
+    let upper_tail = |x: f64| match df {
+        Some(df) => t_tail(x, df),
+        None => normal_tail(x),
+    };
+
+    let one_sided_test = |bound: f64, tail: Tails| {
+        let statistic = (estimate - bound) / standard_error;
+        let (p, log_p) = symmetric_p(statistic, &tail, upper_tail);
+        OneSidedTest { bound, statistic, p, log_p }
+    };
+    let lower_test = || one_sided_test(shift + lower_margin, Tails::UPPER);
+    let upper_test = || one_sided_test(shift + upper_margin, Tails::LOWER);
+    // A one-sided test against a bound on the "worse" side of the null value is a non-inferiority test
+    let (test_type, lower, upper) = match alternative.direction {
+        Direction::TwoSided => (test_types[0], Some(lower_test()), Some(upper_test())),
+        Direction::Greater => (if lower_margin < 0.0 { test_types[1] } else { test_types[2] }, Some(lower_test()), None),
+        Direction::Less => (if upper_margin > 0.0 { test_types[1] } else { test_types[2] }, None, Some(upper_test())),
+    };
+    let p = lower.iter().chain(&upper).map(|test| test.p).fold(0.0, f64::max);
+    let log_p = lower.iter().chain(&upper).map(|test| test.log_p).fold(f64::NEG_INFINITY, f64::max);
+
+    EquivalenceResult {
+        test_type,
+        estimate,
+        standard_error,
+        df,
+        lower,
+        upper,
+        p,
+        log_p,
+        confidence_interval: (estimate - quantile * standard_error, estimate + quantile * standard_error),
+        hypotheses: alternative.statement(parameter),
+        omitted: 0,
+    }
+}
+
+fn one_sample(data: &[f64], expected_mean: f64, alternative: Alternative, significance_level: f64, test_types: TestTypes, parameter: &str) -> EquivalenceResult {
+    if data.len() < 2 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    let n = data.len() as f64;
+    let standard_error = (variance(data).unwrap() / n).sqrt();
+    if standard_error == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+    decide(mean(data).unwrap() - expected_mean, standard_error, Some(n - 1.0), alternative, expected_mean, significance_level, test_types, parameter)
+}
+
+/// Performs equivalence, non-inferiority, or superiority T-tests on the difference between the mean of
+/// `data` and `expected_mean`, with `n - 1` degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::equivalence::{self, Hypothesis};
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![99.8, 100.4, 100.1, 99.6, 100.3, 99.9, 100.2, 100.0, 99.7, 100.1];
+///     let unwrapped_results = equivalence::t_test(data, 100.0, Hypothesis::Equivalence(-0.5, 0.5), 0.05, MissingPolicy::Propagate);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "(1-Sample) Equivalence T-Test (TOST)");
+///     assert!(results.lower.unwrap().p < 0.05 && results.upper.unwrap().p < 0.05);
+///     assert!(results.confidence_interval.0 > -0.5 && results.confidence_interval.1 < 0.5);
+/// }
+/// ```
+pub fn t_test<Number: Observation, Number2: ToPrimitive>(data: impl AsRef<[Number]>, expected_mean: Number2, hypothesis: impl Into<Alternative>, significance_level: f64, missing: MissingPolicy) -> Option<EquivalenceResult> {
+    let (data, omitted) = clean(data.as_ref(), missing);
+    if data.is_empty() { return None };
+    let expected_mean = to_f64(expected_mean);
+    let alternative = hypothesis.into().keeping_margins(expected_mean, true);
+    let mut results = one_sample(&data, expected_mean, alternative, significance_level, ONE_SAMPLE, "μ");
+    results.omitted = omitted;
+    Some(results)
+}
+
+/// Performs equivalence, non-inferiority, or superiority T-tests on the mean of the paired differences
+/// `data1 - data2`, with `n - 1` degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::equivalence::{self, Hypothesis};
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let new = vec![12.1, 11.8, 12.4, 12.0, 11.9, 12.3, 12.2, 11.7];
+///     let old = vec![12.0, 11.9, 12.2, 12.1, 11.8, 12.1, 12.3, 11.8];
+///     let results = equivalence::paired_t_test(new, old, Hypothesis::NonInferiority(0.3), 0.05, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "(Paired) Non-Inferiority T-Test");
+///     assert_eq!(results.upper, None);
+///     assert!(results.p < 0.05);
+/// }
+/// ```
+pub fn paired_t_test<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, hypothesis: impl Into<Alternative>, significance_level: f64, missing: MissingPolicy) -> Option<EquivalenceResult> {
+    let (data1, data2) = (data1.as_ref(), data2.as_ref());
+    if data1.is_empty() || data2.is_empty() { return None };
+    if data1.len() != data2.len() {
+        panic!("\n[HYTE-Panic] The lengths of data1 ({}) and data2 ({}) do not match!\n", data1.len(), data2.len());
+    }
+    let (pairs, omitted) = clean_rows(&[data1, data2], missing);
+    let differences: Vec<f64> = pairs[0].iter().zip(&pairs[1]).map(|(x, y)| x - y).collect();
+    let alternative = hypothesis.into().keeping_margins(0.0, false);
+    let mut results = one_sample(&differences, 0.0, alternative, significance_level, PAIRED, "μ₁ - μ₂");
+    results.omitted = omitted;
+    Some(results)
+}
+
+/// Performs equivalence, non-inferiority, or superiority T-tests on the difference between the means of
+/// `data1` and `data2`. If `equal_variances` is `true`, the pooled variance is used with `n1 + n2 - 2`
+/// degrees of freedom, otherwise Welch's standard error and Welch-Satterthwaite degrees of freedom are used.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::equivalence::{self, Hypothesis};
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let a = vec![5.1, 4.9, 5.3, 5.0, 5.2, 4.8, 5.1, 5.0];
+///     let b = vec![5.0, 5.2, 4.9, 5.1, 5.1, 4.9, 5.0, 5.2];
+///     let results = equivalence::t_test_two_samples(a, b, Hypothesis::Equivalence(-0.3, 0.3), false, 0.05, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "(2-Sample) Welch Equivalence T-Test (TOST)");
+///     assert_eq!(results.conclude_by_convention(), hyte::utils::Conclusion::Reject);
+/// }
+/// ```
+pub fn t_test_two_samples<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, hypothesis: impl Into<Alternative>, equal_variances: bool, significance_level: f64, missing: MissingPolicy) -> Option<EquivalenceResult> {
+    let (data1, omitted1) = clean(data1.as_ref(), missing);
+    let (data2, omitted2) = clean(data2.as_ref(), missing);
+    if data1.is_empty() || data2.is_empty() { return None };
+    if data1.len() < 2 || data2.len() < 2 { panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n") };
+    let n1 = data1.len() as f64;
+    let n2 = data2.len() as f64;
+    let variance1 = variance(&data1).unwrap();
+    let variance2 = variance(&data2).unwrap();
+    if variance1 == 0.0 && variance2 == 0.0 { panic!("\n[HYTE-Panic] Both samples have zero variance, so the test statistic is undefined!\n") };
+    let estimate = mean(&data1).unwrap() - mean(&data2).unwrap();
+    let alternative = hypothesis.into().keeping_margins(0.0, false);
+
+    let mut results = if equal_variances {
+        let df = n1 + n2 - 2.0;
+        let pooled = ((n1 - 1.0) * variance1 + (n2 - 1.0) * variance2) / df;
+        decide(estimate, (pooled * (1.0 / n1 + 1.0 / n2)).sqrt(), Some(df), alternative, 0.0, significance_level, POOLED, "μ₁ - μ₂")
+    } else {
+        let (v1, v2) = (variance1 / n1, variance2 / n2);
+        let df = (v1 + v2).powi(2) / (v1 * v1 / (n1 - 1.0) + v2 * v2 / (n2 - 1.0));
+        decide(estimate, (v1 + v2).sqrt(), Some(df), alternative, 0.0, significance_level, WELCH, "μ₁ - μ₂")
+    };
+    results.omitted = omitted1 + omitted2;
+    Some(results)
+}
+
+/// Performs equivalence, non-inferiority, or superiority Z-tests on the difference between the proportions
+/// `successes1 / trials1` and `successes2 / trials2`, using the unpooled standard error.
+/// Returns `None` if either sample has no trials.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::equivalence::{self, Hypothesis};
+///
+/// fn main() {
+///     let results = equivalence::proportions(430, 500, 420, 500, Hypothesis::NonInferiority(0.05), 0.025).unwrap();
+///
+///     assert_eq!(results.test_type, "(2-Sample) Non-Inferiority Z-Test for Proportions");
+///     assert_eq!(results.df, None);
+///     assert!(results.p < 0.025);
+/// }
+/// ```
+pub fn proportions(successes1: u64, trials1: u64, successes2: u64, trials2: u64, hypothesis: impl Into<Alternative>, significance_level: f64) -> Option<EquivalenceResult> {
+    if trials1 == 0 || trials2 == 0 { return None };
+    if successes1 > trials1 || successes2 > trials2 { panic!("\n[HYTE-Panic] Number of successes cannot exceed the number of trials!\n") };
+    let p1 = successes1 as f64 / trials1 as f64;
+    let p2 = successes2 as f64 / trials2 as f64;
+    let standard_error = (p1 * (1.0 - p1) / trials1 as f64 + p2 * (1.0 - p2) / trials2 as f64).sqrt();
+    let alternative = hypothesis.into().keeping_margins(0.0, false);
+    let results = decide(p1 - p2, standard_error, None, alternative, 0.0, significance_level, PROPORTIONS, "π₁ - π₂");
+    Some(results)
+}
+
+impl EquivalenceResult {
+    /// Concludes if the null hypothesis of non-equivalence (or inferiority) of an `EquivalenceResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if the null hypothesis of non-equivalence (or inferiority) of an `EquivalenceResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for EquivalenceResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for EquivalenceResult {
+    fn report(&self) -> Report {
+        let mut summary = Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("Estimate", format!("{:.4}", self.estimate));
+        let mut apa = Vec::new();
+        for (side, test) in [("Lower", &self.lower), ("Upper", &self.upper)] {
+            if let Some(test) = test {
+                summary = summary.row(&format!("{} bound", side), format!("{:.4}, test statistic = {:.2}, p-value = {}", test.bound, test.statistic, report::p_value(test.p)));
+                let statistic = match self.df {
+                    Some(df) => format!("t({}) = {:.2}", report::df(df), test.statistic),
+                    None => format!("z = {:.2}", test.statistic),
+                };
+                apa.push(format!("{} bound {:.2}: {}, {}", side.to_lowercase(), test.bound, statistic, report::apa_p(test.p)));
+            }
+        }
+        summary = summary
+            .row("p-value", report::p_value(self.p))
+            .row("Confidence interval", format!("({:.4}, {:.4})", self.confidence_interval.0, self.confidence_interval.1));
+        if let Some(df) = self.df {
+            summary = summary.row("Degrees of freedom", report::df(df));
+        }
+        summary.apa(apa.join("; "))
+    }
+}
@@ -0,0 +1,313 @@
+//! Module responsible for all items needed to test the homogeneity of variances across groups.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Conclusion, PValue, average_ranks, conclude, mean, median, variance, chi_squared_tail, f_tail, MissingPolicy, Observation, clean, TestType};
+
+/// A struct for storing the resulting test statistic and p-value from homogeneity-of-variance tests.
+///
+/// `df2` is only present for tests whose statistic follows an F distribution (Levene and Brown-Forsythe).
+/// `log_p` is the natural logarithm of the p-value, which remains finite even when `p` underflows to 0.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VarianceResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    pub df1: usize,
+    pub df2: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    pub omitted: usize,
+}
+
+/// Test type names of each test for homogeneity of variance.
+const LEVENE: TestType = "Levene's Test for Homogeneity of Variance";
+const BROWN_FORSYTHE: TestType = "Brown-Forsythe Test for Homogeneity of Variance";
+const BARTLETT: TestType = "Bartlett's Test for Homogeneity of Variance";
+const FLIGNER_KILLEEN: TestType = "Fligner-Killeen Test for Homogeneity of Variance";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[LEVENE, BROWN_FORSYTHE, BARTLETT, FLIGNER_KILLEEN]];
+
+/// Applies the missing-data policy to each group, returning the cleaned groups and the total number of omitted observations.
+fn to_f64_groups<Number: Observation, Group: AsRef<[Number]>>(groups: &[Group], missing: MissingPolicy) -> Option<(Vec<Vec<f64>>, usize)> {
+    let (groups, omitted): (Vec<Vec<f64>>, Vec<usize>) = groups.iter().map(|group| clean(group.as_ref(), missing)).unzip();
+    if groups.is_empty() || groups.iter().any(Vec::is_empty) {
+        return None;
+    }
+    if groups.len() < 2 { panic!("\n[HYTE-Panic] You must pass in at least 2 groups!\n") };
+    if groups.iter().any(|group| group.len() < 2) {
+        panic!("\n[HYTE-Panic] Every group must contain at least 2 observations!\n");
+    }
+    Some((groups, omitted.iter().sum()))
+}
+
+/// Performs a one-way ANOVA F-test on absolute deviations from each group's centre.
+fn deviation_anova(groups: &[Vec<f64>], centre: fn(&[f64]) -> f64, test_type: &'static str, omitted: usize) -> VarianceResult {
+    let deviations: Vec<Vec<f64>> = groups.iter()
+        .map(|group| {
+            let c = centre(group);
+            group.iter().map(|&x| (x - c).abs()).collect()
+        })
+        .collect();
+
+    let k = deviations.len();
+    let total_n: usize = deviations.iter().map(Vec::len).sum();
+    let grand_mean = deviations.iter().flatten().sum::<f64>() / total_n as f64;
+
+    let mut between = 0.0;
+    let mut within = 0.0;
+    for group in &deviations {
+        let group_mean = mean(group).unwrap();
+        between += group.len() as f64 * (group_mean - grand_mean).powi(2);
+        within += group.iter().map(|&z| (z - group_mean).powi(2)).sum::<f64>();
+    }
+
+    if within == 0.0 { panic!("\n[HYTE-Panic] Every group has identical deviations from its centre, so the test statistic is undefined!\n") };
+
+    let df1 = k - 1;
+    let df2 = total_n - k;
+    let statistic = (df2 as f64 * between) / (df1 as f64 * within);
+    let (p, log_p) = f_tail(statistic, df1 as f64, df2 as f64);
+
+    VarianceResult {
+        test_type,
+        statistic,
+        df1,
+        df2: Some(df2),
+        p,
+        log_p,
+        omitted,
+    }
+}
+
+/// Calculates Levene's test statistic, a one-way ANOVA on absolute deviations from each group's mean,
+/// and its p-value from an F distribution with `k - 1` and `N - k` degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::homogeneity;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let groups = vec![
+///         vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18],
+///         vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24],
+///         vec![15, 30, 12, 28, 19, 25, 10, 33, 21, 17],
+///     ];
+///     let unwrapped_results = homogeneity::levene(groups, MissingPolicy::Propagate);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Levene's Test for Homogeneity of Variance");
+///     assert_eq!(results.df1, 2);
+///     assert_eq!(results.df2, Some(27));
+///     assert!(results.p < 0.001);
+/// }
+/// ```
+pub fn levene<Number: Observation, Group: AsRef<[Number]>>(groups: impl AsRef<[Group]>, missing: MissingPolicy) -> Option<VarianceResult> {
+    let (groups, omitted) = to_f64_groups(groups.as_ref(), missing)?;
+    let results = deviation_anova(&groups, |group| mean(group).unwrap(), LEVENE, omitted);
+    Some(results)
+}
+
+/// Calculates the Brown-Forsythe test statistic, a one-way ANOVA on absolute deviations from each group's median,
+/// and its p-value from an F distribution with `k - 1` and `N - k` degrees of freedom.
+/// It is more robust than Levene's test for skewed data.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::homogeneity;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let groups = vec![
+///         vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18],
+///         vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24],
+///     ];
+///     let results = homogeneity::brown_forsythe(groups, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Brown-Forsythe Test for Homogeneity of Variance");
+///     assert!(results.p > 0.05);
+/// }
+/// ```
+pub fn brown_forsythe<Number: Observation, Group: AsRef<[Number]>>(groups: impl AsRef<[Group]>, missing: MissingPolicy) -> Option<VarianceResult> {
+    let (groups, omitted) = to_f64_groups(groups.as_ref(), missing)?;
+    let results = deviation_anova(&groups, |group| median(group).unwrap(), BROWN_FORSYTHE, omitted);
+    Some(results)
+}
+
+/// Calculates Bartlett's test statistic and its p-value from a Chi-squared distribution with `k - 1` degrees of freedom.
+/// Bartlett's test is more powerful than Levene's test when the data are normal, but is sensitive to departures from normality.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::homogeneity;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let groups = vec![
+///         vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18],
+///         vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24],
+///     ];
+///     let results = homogeneity::bartlett(groups, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Bartlett's Test for Homogeneity of Variance");
+///     assert_eq!(results.df1, 1);
+///     assert_eq!(results.df2, None);
+/// }
+/// ```
+pub fn bartlett<Number: Observation, Group: AsRef<[Number]>>(groups: impl AsRef<[Group]>, missing: MissingPolicy) -> Option<VarianceResult> {
+    let (groups, omitted) = to_f64_groups(groups.as_ref(), missing)?;
+    let k = groups.len();
+    let total_n: usize = groups.iter().map(Vec::len).sum();
+    let df_within = (total_n - k) as f64;
+
+    let mut pooled = 0.0;
+    let mut log_sum = 0.0;
+    let mut reciprocal_sum = 0.0;
+    for group in &groups {
+        let df_group = (group.len() - 1) as f64;
+        let group_variance = variance(group).unwrap();
+        if group_variance == 0.0 { panic!("\n[HYTE-Panic] Bartlett's test requires every group to have a non-zero variance!\n") };
+        pooled += df_group * group_variance;
+        log_sum += df_group * group_variance.ln();
+        reciprocal_sum += 1.0 / df_group;
+    }
+    pooled /= df_within;
+
+    let correction = 1.0 + (reciprocal_sum - 1.0 / df_within) / (3.0 * (k - 1) as f64);
+    let statistic = (df_within * pooled.ln() - log_sum) / correction;
+    let df1 = k - 1;
+    let (p, log_p) = chi_squared_tail(statistic, df1 as f64);
+
+    let results = VarianceResult {
+        test_type: BARTLETT,
+        statistic,
+        df1,
+        df2: None,
+        p,
+        log_p,
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Calculates the Fligner-Killeen median-centred test statistic, a robust rank-based test which compares
+/// normal scores of the absolute deviations from each group's median. Its p-value comes from a
+/// Chi-squared distribution with `k - 1` degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::homogeneity;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let groups = vec![
+///         vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18],
+///         vec![15, 30, 12, 28, 19, 25, 10, 33, 21, 17],
+///     ];
+///     let results = homogeneity::fligner_killeen(groups, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Fligner-Killeen Test for Homogeneity of Variance");
+///     assert!(results.p < 0.01);
+/// }
+/// ```
+pub fn fligner_killeen<Number: Observation, Group: AsRef<[Number]>>(groups: impl AsRef<[Group]>, missing: MissingPolicy) -> Option<VarianceResult> {
+    let (groups, omitted) = to_f64_groups(groups.as_ref(), missing)?;
+    let k = groups.len();
+
+    let deviations: Vec<f64> = groups.iter()
+        .flat_map(|group| {
+            let m = median(group).unwrap();
+            group.iter().map(move |&x| (x - m).abs())
+        })
+        .collect();
+    let total_n = deviations.len();
+    let standard_normal = Normal::new(0.0, 1.0).unwrap();
+    let scores: Vec<f64> = average_ranks(&deviations).iter()
+        .map(|&rank| standard_normal.inverse_cdf((1.0 + rank / (total_n + 1) as f64) / 2.0))
+        .collect();
+
+    let score_mean = mean(&scores).unwrap();
+    let score_variance = variance(&scores).unwrap();
+    if score_variance == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+
+    let mut offset = 0;
+    let mut between = 0.0;
+    for group in &groups {
+        let group_sum: f64 = scores[offset..offset + group.len()].iter().sum();
+        between += group_sum * group_sum / group.len() as f64;
+        offset += group.len();
+    }
+
+    let statistic = (between - total_n as f64 * score_mean * score_mean) / score_variance;
+    let df1 = k - 1;
+    let (p, log_p) = chi_squared_tail(statistic, df1 as f64);
+
+    let results = VarianceResult {
+        test_type: FLIGNER_KILLEEN,
+        statistic,
+        df1,
+        df2: None,
+        p,
+        log_p,
+        omitted,
+    };
+
+    Some(results)
+}
+
+impl VarianceResult {
+    /// Concludes if a `VarianceResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `VarianceResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for VarianceResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for VarianceResult {
+    fn report(&self) -> Report {
+        let summary = Report::new(self.test_type);
+        match self.df2 {
+            Some(df2) => summary
+                .row("F test statistic", format!("{:.4}", self.statistic))
+                .row("p-value", report::p_value(self.p))
+                .row("Degrees of freedom", format!("({}, {})", self.df1, df2))
+                .apa(format!("F({}, {}) = {:.2}, {}", self.df1, df2, self.statistic, report::apa_p(self.p))),
+            None => summary
+                .row(if self.test_type == BARTLETT { "K^2 test statistic" } else { "X^2 test statistic" }, format!("{:.4}", self.statistic))
+                .row("p-value", report::p_value(self.p))
+                .row("Degrees of freedom", self.df1)
+                .apa(format!("χ²({}) = {:.2}, {}", self.df1, self.statistic, report::apa_p(self.p))),
+        }
+    }
+}
@@ -0,0 +1,477 @@
+//! Module responsible for all items needed to perform Kolmogorov-Smirnov tests.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+use statrs::function::factorial::ln_binomial;
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Direction, Tails, Conclusion, PValue, conclude, mean, statement, variance, MissingPolicy, Observation, clean, ln_sum_exp, tailed, TestType};
+
+/// Largest sample size for which the one-sample test uses exact p-values.
+const EXACT_ONE_SAMPLE_LIMIT: usize = 100;
+
+/// Largest product of sample sizes for which the two-sample test uses exact p-values.
+const EXACT_TWO_SAMPLE_LIMIT: usize = 10000;
+
+/// Exact two-sided one-sample p-values below this are found from the one-sided tail rather than `1 - P(D < d)`.
+const SMALL_P: f64 = 1e-5;
+
+/// A struct for storing the resulting test statistic and p-value from Kolmogorov-Smirnov tests.
+///
+/// `log_p` is the natural logarithm of the p-value, which remains finite even when `p` underflows to 0.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct KSResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    pub hypotheses: String,
+    pub omitted: usize,
+}
+
+/// Lower-tailed, upper-tailed, and two-sided test type names of each Kolmogorov-Smirnov test.
+const ONE_SAMPLE_EXACT: [TestType; 3] = [
+    "One-Sample Kolmogorov-Smirnov Test (Lower-Tailed, Exact)",
+    "One-Sample Kolmogorov-Smirnov Test (Upper-Tailed, Exact)",
+    "One-Sample Kolmogorov-Smirnov Test (Two-Sided, Exact)",
+];
+const ONE_SAMPLE_ASYMPTOTIC: [TestType; 3] = [
+    "One-Sample Kolmogorov-Smirnov Test (Lower-Tailed, Asymptotic)",
+    "One-Sample Kolmogorov-Smirnov Test (Upper-Tailed, Asymptotic)",
+    "One-Sample Kolmogorov-Smirnov Test (Two-Sided, Asymptotic)",
+];
+const TWO_SAMPLE_EXACT: [TestType; 3] = [
+    "Two-Sample Kolmogorov-Smirnov Test (Lower-Tailed, Exact)",
+    "Two-Sample Kolmogorov-Smirnov Test (Upper-Tailed, Exact)",
+    "Two-Sample Kolmogorov-Smirnov Test (Two-Sided, Exact)",
+];
+const TWO_SAMPLE_ASYMPTOTIC: [TestType; 3] = [
+    "Two-Sample Kolmogorov-Smirnov Test (Lower-Tailed, Asymptotic)",
+    "Two-Sample Kolmogorov-Smirnov Test (Upper-Tailed, Asymptotic)",
+    "Two-Sample Kolmogorov-Smirnov Test (Two-Sided, Asymptotic)",
+];
+const LILLIEFORS: TestType = "Lilliefors (Kolmogorov-Smirnov) Test for Normality";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![ONE_SAMPLE_EXACT, ONE_SAMPLE_ASYMPTOTIC, TWO_SAMPLE_EXACT, TWO_SAMPLE_ASYMPTOTIC, [LILLIEFORS]];
+
+/// Applies the missing-data policy and sorts what remains, returning the sorted data and the number of omitted observations.
+fn sorted_f64<Number: Observation>(data: &[Number], missing: MissingPolicy) -> (Vec<f64>, usize) {
+    let (mut sorted, omitted) = clean(data, missing);
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    (sorted, omitted)
+}
+
+/// Returns `(D+, D-)` for sorted data against a hypothesised CDF.
+fn one_sample_statistics(sorted: &[f64], cdf: impl Fn(f64) -> f64) -> (f64, f64) {
+    let n = sorted.len() as f64;
+    let mut d_plus: f64 = 0.0;
+    let mut d_minus: f64 = 0.0;
+    for (i, &x) in sorted.iter().enumerate() {
+        let f = cdf(x);
+        d_plus = d_plus.max((i + 1) as f64 / n - f);
+        d_minus = d_minus.max(f - i as f64 / n);
+    }
+    (d_plus, d_minus)
+}
+
+/// Asymptotic survival function of the Kolmogorov distribution, `P(K > lambda)`.
+fn kolmogorov_sf(lambda: f64) -> (f64, f64) {
+    // The series converges too slowly to be useful here, and the probability is 1 to double precision
+    if lambda < 0.2 { return (1.0, 0.0) };
+    let mut sum = 0.0;
+    for k in 1..=100 {
+        let k = k as f64;
+        let term = (-2.0 * k * k * lambda * lambda).exp();
+        sum += if k as u32 % 2 == 1 { term } else { -term };
+        if term < 1e-17 { break };
+    }
+    let p = (2.0 * sum).clamp(0.0, 1.0);
+    // Far in the tail only the first term matters, whose logarithm does not underflow
+    (p, if lambda > 3.0 { std::f64::consts::LN_2 - 2.0 * lambda * lambda } else { p.ln() })
+}
+
+/// Computes the asymptotic one-sided p-value `exp(-2 λ²)` and its natural logarithm.
+fn smirnov_asymptotic_sf(lambda: f64) -> (f64, f64) {
+    let log_p = -2.0 * lambda * lambda;
+    (log_p.exp(), log_p)
+}
+
+fn matrix_multiply(a: &[f64], b: &[f64], m: usize) -> Vec<f64> {
+    let mut c = vec![0.0; m * m];
+    for i in 0..m {
+        for k in 0..m {
+            let a_ik = a[i * m + k];
+            if a_ik == 0.0 { continue };
+            for j in 0..m {
+                c[i * m + j] += a_ik * b[k * m + j];
+            }
+        }
+    }
+    c
+}
+
+/// Raises `a` to the power `n`, returning the result along with a base-10 exponent to avoid overflow.
+fn matrix_power(a: &[f64], m: usize, n: usize) -> (Vec<f64>, i32) {
+    if n == 1 { return (a.to_vec(), 0) };
+    let (v, ev) = matrix_power(a, m, n / 2);
+    let b = matrix_multiply(&v, &v, m);
+    let (mut result, mut exponent) = if n.is_multiple_of(2) {
+        (b, 2 * ev)
+    } else {
+        (matrix_multiply(a, &b, m), 2 * ev)
+    };
+    if result[(m / 2) * m + m / 2] > 1e140 {
+        result.iter_mut().for_each(|x| *x *= 1e-140);
+        exponent += 140;
+    }
+    (result, exponent)
+}
+
+/// Exact CDF of the two-sided one-sample statistic, `P(D < d)`, by Marsaglia, Tsang and Wang (2003).
+fn kolmogorov_exact_cdf(n: usize, d: f64) -> f64 {
+    let nd = n as f64 * d;
+    if nd >= n as f64 { return 1.0 };
+    let k = nd as usize + 1;
+    let m = 2 * k - 1;
+    let h = k as f64 - nd;
+
+    let mut matrix = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 >= j { matrix[i * m + j] = 1.0 };
+        }
+    }
+    for i in 0..m {
+        matrix[i * m] -= h.powi(i as i32 + 1);
+        matrix[(m - 1) * m + i] -= h.powi((m - i) as i32);
+    }
+    if 2.0 * h - 1.0 > 0.0 {
+        matrix[(m - 1) * m] += (2.0 * h - 1.0).powi(m as i32);
+    }
+    for i in 0..m {
+        for j in 0..m {
+            if i + 1 > j {
+                for g in 1..=(i + 1 - j) {
+                    matrix[i * m + j] /= g as f64;
+                }
+            }
+        }
+    }
+
+    let (q, mut exponent) = matrix_power(&matrix, m, n);
+    let mut s = q[(k - 1) * m + k - 1];
+    for i in 1..=n {
+        s = s * i as f64 / n as f64;
+        if s < 1e-140 {
+            s *= 1e140;
+            exponent -= 140;
+        }
+    }
+    (s * 10f64.powi(exponent)).clamp(0.0, 1.0)
+}
+
+/// Exact one-sided one-sample p-value, `P(D+ >= d)`, by the Birnbaum-Tingey formula, and its natural logarithm.
+/// Every term of the sum is positive, so it is summed in log space and small p-values keep their precision.
+fn smirnov_exact_sf(n: usize, d: f64) -> (f64, f64) {
+    if d <= 0.0 { return (1.0, 0.0) };
+    // D+ reaches 1 only if every observation has probability 0 under the hypothesised distribution
+    if d >= 1.0 { return (0.0, f64::NEG_INFINITY) };
+    let an = n as f64;
+    let upper = (an * (1.0 - d)).floor() as u64;
+    let log_terms = (0..=upper).map(|j| {
+        let j_f64 = j as f64;
+        ln_binomial(n as u64, j)
+            // The last base is 0 when `n (1 - d)` is whole, but rounding can make it slightly negative
+            + (an - j_f64) * (1.0 - d - j_f64 / an).max(0.0).ln()
+            + (j_f64 - 1.0) * (d + j_f64 / an).ln()
+    });
+    let log_p = (d.ln() + ln_sum_exp(log_terms)).min(0.0);
+    (log_p.exp(), log_p)
+}
+
+/// Exact two-sided one-sample p-value, `P(D >= d)`, and its natural logarithm.
+///
+/// Beyond `d = 0.5` the events `D+ >= d` and `D- >= d` are disjoint, so the p-value is twice the Birnbaum-Tingey
+/// tail. Below it, the complement of the exact CDF is used unless it is small enough to have lost its precision,
+/// where the overlap of both events is negligible and twice the one-sided tail is used as well.
+fn kolmogorov_exact_sf(n: usize, d: f64) -> (f64, f64) {
+    let (one_sided, log_one_sided) = smirnov_exact_sf(n, d);
+    let twice = (2.0 * one_sided, log_one_sided + std::f64::consts::LN_2);
+    if d > 0.5 { return twice };
+    let p = 1.0 - kolmogorov_exact_cdf(n, d);
+    if p < SMALL_P { twice } else { (p, p.ln()) }
+}
+
+/// Exact two-sample p-value by counting the lattice paths which leave the band of the statistic, and its
+/// natural logarithm. `two_sided` controls whether `|F1 - F2|` or `F1 - F2` is bounded.
+///
+/// Each path leaving the band is counted once, at the first point outside it, by multiplying the paths which
+/// reach that point from inside the band by those from there to the end, so the tail is never found as
+/// `1 - P(inside)` and small p-values keep their precision.
+fn two_sample_exact_sf(n1: usize, n2: usize, d: f64, two_sided: bool) -> (f64, f64) {
+    // Guard against rounding so that paths attaining exactly `d` are counted as extreme
+    let q = d - 1e-7;
+    let outside = |i: usize, j: usize| {
+        let diff = i as f64 / n1 as f64 - j as f64 / n2 as f64;
+        if two_sided { diff.abs() >= q } else { diff >= q }
+    };
+    if outside(0, 0) { return (1.0, 0.0) };
+    // The share of all paths from (i, j) to the end; counts of paths fit in an `f64` within the exact limit
+    let ln_total = ln_binomial((n1 + n2) as u64, n1 as u64);
+    let ln_rest = |i: usize, j: usize| ln_binomial((n1 - i + n2 - j) as u64, (n1 - i) as u64) - ln_total;
+
+    // `inside[j]` counts the paths from the origin to (i, j) which stay inside the band
+    let mut inside = vec![0.0_f64; n2 + 1];
+    let mut log_exits = Vec::new();
+    for i in 0..=n1 {
+        for j in 0..=n2 {
+            let arriving = if i == 0 && j == 0 { 1.0 } else { inside[j] + if j > 0 { inside[j - 1] } else { 0.0 } };
+            if outside(i, j) {
+                if arriving > 0.0 { log_exits.push(arriving.ln() + ln_rest(i, j)) };
+                inside[j] = 0.0;
+            } else {
+                inside[j] = arriving;
+            }
+        }
+    }
+    let log_p = ln_sum_exp(log_exits).min(0.0);
+    (log_p.exp(), log_p)
+}
+
+/// Calculates the one-sample Kolmogorov-Smirnov statistic and p-value for data against a fully specified
+/// continuous distribution from `statrs`, passed as a `ContinuousCDF` trait object.
+///
+/// `Tails::BOTH` uses `D = max(D+, D-)`, `Tails::UPPER` uses `D+` (the empirical CDF lies above the
+/// hypothesised CDF), and `Tails::LOWER` uses `D-` (the empirical CDF lies below it).
+/// P-values are exact for samples of up to 100 observations and asymptotic otherwise.
+///
+/// If the parameters of a normal distribution are estimated from the same data, use `ks::lilliefors` instead.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::ks;
+/// use hyte::utils::{Tails, MissingPolicy};
+/// use statrs::distribution::Uniform;
+///
+/// fn main() {
+///     let data = vec![0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64, 0.05, -0.06, 0.64, -0.82, 0.37, 1.77, 1.09, -1.28, 2.36, 1.31, 1.05, -0.32, -0.4, 1.06, -2.47];
+///     let uniform = Uniform::new(0.0, 1.0).unwrap();
+///     let unwrapped_results = ks::test(data, &uniform, Tails::BOTH, MissingPolicy::Propagate);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "One-Sample Kolmogorov-Smirnov Test (Two-Sided, Exact)");
+///     assert_eq!(results.statistic, 0.38);
+///     assert!(results.p < 0.001);
+/// }
+/// ```
+pub fn test<Number: Observation>(data: impl AsRef<[Number]>, distribution: &dyn ContinuousCDF<f64, f64>, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<KSResult> {
+    let (sorted, omitted) = sorted_f64(data.as_ref(), missing);
+    if sorted.is_empty() { return None };
+    let alternative = alternative.into().without_null("The Kolmogorov-Smirnov test");
+    let tail = alternative.tails();
+    let n = sorted.len();
+    let (d_plus, d_minus) = one_sample_statistics(&sorted, |x| distribution.cdf(x));
+    let exact = n <= EXACT_ONE_SAMPLE_LIMIT;
+
+    let (statistic, (p, log_p)) = match tail {
+        Tails::BOTH => {
+            let d = d_plus.max(d_minus);
+            (d, if exact { kolmogorov_exact_sf(n, d) } else { kolmogorov_sf((n as f64).sqrt() * d) })
+        },
+        Tails::UPPER | Tails::LOWER => {
+            let d = if let Tails::UPPER = tail { d_plus } else { d_minus };
+            (d, if exact { smirnov_exact_sf(n, d) } else { smirnov_asymptotic_sf((n as f64).sqrt() * d) })
+        },
+    };
+    let test_type = tailed(if exact { ONE_SAMPLE_EXACT } else { ONE_SAMPLE_ASYMPTOTIC }, &tail);
+
+    let results = KSResult {
+        test_type,
+        statistic,
+        p,
+        log_p,
+        hypotheses: statement("F", "F₀", alternative.direction),
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Calculates the two-sample Kolmogorov-Smirnov statistic and p-value for two groups of data.
+///
+/// `Tails::BOTH` uses `D = max |F1 - F2|`, `Tails::UPPER` uses `D+ = max (F1 - F2)` (the empirical CDF of
+/// `data1` lies above that of `data2`), and `Tails::LOWER` uses `D- = max (F2 - F1)`.
+/// P-values are exact when the product of the sample sizes is at most 10000 and asymptotic otherwise.
+/// Exact p-values assume there are no ties between the samples.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::ks;
+/// use hyte::utils::{Tails, MissingPolicy};
+///
+/// fn main() {
+///     let group1 = vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18];
+///     let group2 = vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24];
+///     let results = ks::test_two_samples(group1, group2, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Two-Sample Kolmogorov-Smirnov Test (Two-Sided, Exact)");
+///     assert_eq!(results.statistic, 0.8);
+///     assert!(results.p < 0.01);
+/// }
+/// ```
+pub fn test_two_samples<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<KSResult> {
+    let (sorted1, omitted1) = sorted_f64(data1.as_ref(), missing);
+    let (sorted2, omitted2) = sorted_f64(data2.as_ref(), missing);
+    if sorted1.is_empty() || sorted2.is_empty() {
+        return None
+    };
+    let alternative = alternative.into().without_null("The Kolmogorov-Smirnov test");
+    let tail = alternative.tails();
+    let (n1, n2) = (sorted1.len(), sorted2.len());
+
+    // Walk both sorted samples, evaluating the empirical CDFs after each distinct value
+    let (mut i, mut j) = (0, 0);
+    let mut d_plus: f64 = 0.0;
+    let mut d_minus: f64 = 0.0;
+    while i < n1 && j < n2 {
+        let value = sorted1[i].min(sorted2[j]);
+        while i < n1 && sorted1[i] <= value { i += 1 };
+        while j < n2 && sorted2[j] <= value { j += 1 };
+        let diff = i as f64 / n1 as f64 - j as f64 / n2 as f64;
+        d_plus = d_plus.max(diff);
+        d_minus = d_minus.max(-diff);
+    }
+
+    let exact = n1 * n2 <= EXACT_TWO_SAMPLE_LIMIT;
+    let en = ((n1 * n2) as f64 / (n1 + n2) as f64).sqrt();
+
+    let (statistic, (p, log_p)) = match tail {
+        Tails::BOTH => {
+            let d = d_plus.max(d_minus);
+            (d, if exact { two_sample_exact_sf(n1, n2, d, true) } else { kolmogorov_sf(en * d) })
+        },
+        Tails::UPPER => (d_plus, if exact { two_sample_exact_sf(n1, n2, d_plus, false) } else { smirnov_asymptotic_sf(en * d_plus) }),
+        // D- for (data1, data2) is D+ for (data2, data1)
+        Tails::LOWER => (d_minus, if exact { two_sample_exact_sf(n2, n1, d_minus, false) } else { smirnov_asymptotic_sf(en * d_minus) }),
+    };
+    let test_type = tailed(if exact { TWO_SAMPLE_EXACT } else { TWO_SAMPLE_ASYMPTOTIC }, &tail);
+
+    let results = KSResult {
+        test_type,
+        statistic,
+        p,
+        log_p,
+        hypotheses: statement("F₁", "F₂", alternative.direction),
+        omitted: omitted1 + omitted2,
+    };
+
+    Some(results)
+}
+
+/// Calculates the Lilliefors-corrected Kolmogorov-Smirnov test for normality, where the mean and
+/// standard deviation of the normal distribution are estimated from the data. P-values use the
+/// approximation of Dallal and Wilkinson (1986). The sample size must be at least 5.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::ks;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+///     let results = ks::lilliefors(data, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Lilliefors (Kolmogorov-Smirnov) Test for Normality");
+///     assert!(results.p < 0.05);
+/// }
+/// ```
+pub fn lilliefors<Number: Observation>(data: impl AsRef<[Number]>, missing: MissingPolicy) -> Option<KSResult> {
+    let (sorted, omitted) = sorted_f64(data.as_ref(), missing);
+    if sorted.is_empty() { return None };
+    let n = sorted.len();
+    if n < 5 { panic!("\n[HYTE-Panic] Lilliefors test requires a sample size of at least 5!\n") };
+
+    let sd = variance(&sorted).unwrap().sqrt();
+    if sd == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+    let estimated = Normal::new(mean(&sorted).unwrap(), sd).unwrap();
+    let (d_plus, d_minus) = one_sample_statistics(&sorted, |x| estimated.cdf(x));
+    let statistic = d_plus.max(d_minus);
+
+    let an = n as f64;
+    let (kd, nd) = if n <= 100 { (statistic, an) } else { (statistic * (an / 100.0).powf(0.49), 100.0) };
+    // The approximation gives the logarithm of small p-values directly
+    let mut log_p = -7.01256 * kd * kd * (nd + 2.78019) + 2.99587 * kd * (nd + 2.78019).sqrt() - 0.122119
+        + 0.974598 / nd.sqrt() + 1.67997 / nd;
+    let mut p = log_p.exp();
+    if p > 0.1 {
+        let kk = (an.sqrt() - 0.01 + 0.85 / an.sqrt()) * statistic;
+        p = if kk <= 0.302 {
+            1.0
+        } else if kk <= 0.5 {
+            2.76773 - 19.828315 * kk + 80.709644 * kk.powi(2) - 138.55152 * kk.powi(3) + 81.218052 * kk.powi(4)
+        } else if kk <= 0.9 {
+            -4.901232 + 40.662806 * kk - 97.490286 * kk.powi(2) + 94.029866 * kk.powi(3) - 32.355711 * kk.powi(4)
+        } else if kk <= 1.31 {
+            6.198765 - 19.558097 * kk + 23.186922 * kk.powi(2) - 12.234627 * kk.powi(3) + 2.423045 * kk.powi(4)
+        } else {
+            0.0
+        };
+        log_p = p.clamp(0.0, 1.0).ln();
+    }
+
+    let results = KSResult {
+        test_type: LILLIEFORS,
+        statistic,
+        p: p.clamp(0.0, 1.0),
+        log_p,
+        hypotheses: statement("F", "Normal", Direction::TwoSided),
+        omitted,
+    };
+
+    Some(results)
+}
+
+impl KSResult {
+    /// Concludes if a `KSResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `KSResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for KSResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for KSResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("D test statistic", format!("{:.4}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .apa(format!("D = {}, {}", report::bounded(self.statistic, 2), report::apa_p(self.p)))
+    }
+}
@@ -8,15 +8,81 @@
 //! - 2-sample T-test
 //! - Pearson's Chi-squared test of independence
 //! - Pearson's Chi-squared test of goodness of fit
+//! - Shapiro-Wilk, Anderson-Darling, D'Agostino-Pearson, and Jarque-Bera tests for normality
+//! - 1-sample and 2-sample Kolmogorov-Smirnov tests, and the Lilliefors test for normality
+//! - Levene, Brown-Forsythe, Bartlett, and Fligner-Killeen tests for homogeneity of variance
+//! - Pearson's r, Spearman's rho, and Kendall's tau correlation tests
+//! - T-tests of linear regression coefficients and the overall F-test
+//! - Exact binomial test with Clopper-Pearson, Wilson, Agresti-Coull, and Jeffreys intervals
+//! - Exact and Z-tests of one Poisson rate and of the ratio of two Poisson rates
+//! - Equivalence (TOST), non-inferiority, and superiority T-tests and two-proportion Z-tests
+//! - Exact and Monte Carlo permutation tests of any statistic, including paired, sign-flip, and stratified designs
+//! - Percentile, basic, studentized, and BCa bootstrap intervals of any statistic, with block and cluster resampling, and bootstrap-t tests
+//! - JZS Bayes factors for T-tests, and Beta-Binomial and Normal-Gamma comparisons of two variants
+//! 
+//! Every tailed test accepts a `utils::Alternative`, which states the null value and direction of the hypotheses,
+//! or one of the `utils::Tails` variants. Additionally, p-values from multiple tests can be corrected for
+//! multiplicity with the `multiple` module.
+//! 
+//! Data can be passed in as a `Vec`, a borrowed `&Vec`, a slice, or an array, of any type implementing
+//! `utils::Observation`, so large columns never need to be cloned to run a test. `Observation` is implemented for
+//! every primitive integer and float and for `Option`s of them; a custom observation type needs its own `Observation` impl.
+//! Columns may also contain missing values, as NaNs or `None`s, which each test handles according to a `utils::MissingPolicy`.
+//! 
+//! Results are never printed by the library. Instead, every result implements `report::Reportable`, whose `Report`
+//! can be displayed as plain text, a Markdown table, a LaTeX `tabular`, or an APA-style sentence.
+//! 
+//! With the `serde` feature, every result can be serialised, and `record::Record` stores a result with its inputs,
+//! parameters, and conclusion in a versioned representation. The `schema` feature adds its JSON Schema.
+//! 
+//! The `cli` feature builds the `hyte` binary, which runs tests on columns of CSV and TSV files (see `cli`).
+//! The `serve` feature adds `hyte serve`, which exposes every test as a JSON endpoint over HTTP (see `serve`).
+//! The `repl` feature adds `hyte repl`, an interactive session for exploratory testing (see `repl`).
 //! 
 //! Source: [GitHub](https://www.github.com/abyanmajid/hyte)
 //! 
 //! v0.1.0 by Abyan Majid (kinderheim511)
 
 pub mod utils;
+pub mod report;
 pub mod chisquare;
 pub mod z;
 pub mod t;
+pub mod multiple;
+pub mod normality;
+pub mod ks;
+pub mod homogeneity;
+pub mod correlation;
+pub mod regression;
+pub mod binomial;
+pub mod poisson;
+pub mod equivalence;
+pub mod permutation;
+pub mod bootstrap;
+pub mod bayes;
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod record;
+#[cfg(feature = "cli")]
+pub mod cli;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(feature = "repl")]
+pub mod repl;
+
+/// Returns the logo and welcome line printed by `help` and by the `hyte repl` banner.
+pub(crate) fn banner() -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    format!(r#"
+ _             _        
+| |__   _   _ | |_  ___ 
+| '_ \ | | | || __|/ _ \
+| | | || |_| || |_|  __/
+|_| |_| \__, | \__|\___|
+        |___/          
+
+❤  Welcome to Hyte v{version}  ❤"#)
+}
 
 /// Prints a short manual for all utilities in `hyte` to the standard output.
 /// 
@@ -30,173 +96,183 @@ pub mod t;
 /// }
 /// ````
 pub fn help() {
-    let version = env!("CARGO_PKG_VERSION");
-    println!(r#"
- _             _        
-| |__   _   _ | |_  ___ 
-| '_ \ | | | || __|/ _ \
-| | | || |_| || |_|  __/
-|_| |_| \__, | \__|\___|
-        |___/          
-
-❤  Welcome to Hyte v{version}  ❤
+    let banner = banner();
+    let tests = registry::manual();
+    println!(r#"{banner}
 
 Documentation: 
 Source: https://www.github.com/abyanmajid/hyte
 
-➤  Z-Test for Mean
+{tests}➤  Test Registry
 
-    ➜  (FN) hyte::z::test
+    ➜  (CONST) hyte::registry::TESTS, hyte::registry::MODULES
             <>
-            Intended use: Perform Z-Test given data.
+            Intended use: Describe every test above at runtime; this manual, `hyte <test> --help`, and
+                          `GET /v1/tests` of `hyte serve` are generated from it.
             <>
-            args:
-                1. data: Vec<Number>, where Number: Into<f64>; accepts integer or float
-                2. expected_mean: Number: Into<f64>; accepts integer or float
-                3. tail: Tails::LOWER or Tails::UPPER or Tails::BOTH
-                4. print_output: bool
-            returns: Instance of struct `Z`
-                Fields:
-                    - test_type: &'static str,
-                    - statistic: f64,
-                    - p: f64,
+            fields of each `Test`:
+                - module: &'static str,
+                - function: &'static str,
+                - summary: &'static str,
+                - parameters: &'static [Parameter], (name, rust_type, shape, description)
+                - assumptions: &'static [&'static str],
+                - result: &'static str,
+                - fields: &'static [Field], (name, rust_type, description)
 
-    ➜  (FN) hyte::z::test_dataless
+    ➜  (FN) hyte::registry::find
             <>
-            Intended use: Perform Z-Test given numerical summaries.
+            Intended use: Look up a test by its path, e.g. "t::test_paired".
             <>
-            args:
-                1. observed_mean: Number: Into<f64>; accepts integer or float
-                2. expected_mean: Number: Into<f64>; accepts integer or float
-                3. sample_size: u32
-                4. pop_sd: Number: Into<f64>; accepts integer or float
-                5. tail: Tails::LOWER or Tails::UPPER or Tails::BOTH
-                6. print_output: bool
-            returns: Instance of struct `Z`
-                Fields:
-                    - test_type: &'static str,
-                    - statistic: f64,
-                    - p: f64,
+            returns: Option<&'static Test>
+
+➤  Reports
 
-    ➜  (METHOD) hyte::z::ZResult::conclude
+    ➜  (TRAIT) hyte::report::Reportable
             <>
-            Intended use: Conclude if a `ZResult` should be rejected, given a specified s. l
+            Intended use: Summarise any test result as a `Report`; nothing in hyte prints on its own.
             <>
-            args:
-                1. significance_level: f64,
-                2. print_output: bool,
-            returns: A `Conclusion` variant; one of `Reject` or `DoNotReject`
+            methods:
+                - report(&self) -> Report
 
-    ➜  (METHOD) hyte::z::ZResult::conclude_by_convention
+    ➜  (STRUCT) hyte::report::Report
             <>
-            Intended use: Conclude if a `ZResult` should be rejected with s. l = 0.05
+            Intended use: Display a test result, e.g. println!("{{}}", results.report()).
             <>
-            args:
-                1. significance_level: f64,
-                2. print_output: bool,
-            returns: A `Conclusion` variant; one of `Reject` or `DoNotReject`
+            methods:
+                - with_format(self, format: Format) -> Report
+                - render(&self, format: Format) -> String
+            fields:
+                - title: String,
+                - hypotheses: Option<String>,
+                - rows: Vec<(String, String)>,
+                - apa: String,
+                - format: Format,
+
+    ➜  (ENUM) hyte::report::Format
+            variants:
+                - Text
+                - Markdown
+                - Latex
+                - Apa, e.g. "t(7) = -1.73, p = .063, d = -0.61"
 
-➤  T-Test for Mean
+➤  Serialisation (features "serde" and "schema")
 
-    ➜  (FN) hyte::t::test
+    ➜  (STRUCT) hyte::record::Record
             <>
-            Intended use: Perform T-Test given data.
+            Intended use: Store a test result with its inputs, parameters and conclusion, e.g. in an experiment store.
             <>
-            args:
-                1. data: data: Vec<Number>, where Number: Into<f64>; accepts integer or float
-                2. expected_mean: Number: Into<f64>; accepts integer or float
-                3. tail: Tails::LOWER or Tails::UPPER or Tails::BOTH
-                4. print_output: bool
-            returns: Instance of struct `T`
-                Fields:
-                    - test_type: &'static str,
-                    - statistic: f64,
-                    - df: f64,
-                    - p: f64,
+            methods:
+                - Record::new(result: impl Into<TestResult>) -> Record
+                - input(self, name: &str, data: impl AsRef<[Number]>) -> Record, where Number: Observation
+                - parameter(self, name: &str, value: impl ToPrimitive) -> Record
+                - alternative(self, alternative: impl Into<Alternative>) -> Record
+                - conclude(self, significance_level: f64) -> Record
+            fields:
+                - version: u32, (record::VERSION; other versions are rejected when deserialised)
+                - inputs: BTreeMap<String, Vec<Option<f64>>>,
+                - parameters: BTreeMap<String, f64>,
+                - alternative: Option<Alternative>,
+                - significance_level: Option<f64>,
+                - conclusion: Option<Conclusion>,
+                - result: TestResult, (tagged with "kind", e.g. "z", "t", "chi_square", "ks", "qvalue")
 
-    ➜  (FN) hyte::t::test_dataless
+    ➜  (FN) hyte::record::schema
             <>
-            Intended use: Perform T-Test given numerical summaries.
+            Intended use: Return the JSON Schema of a `Record`, published as schema/record.v1.json.
             <>
-            args:
-                1. observed_mean: Number: Into<f64>; accepts integer or float
-                2. expected_mean: Number: Into<f64>; accepts integer or float
-                3. sample_size: u32
-                4. pop_sd: Number: Into<f64>; accepts integer or float
-                5. tail: Tails::LOWER or Tails::UPPER or Tails::BOTH
-                6. print_output: bool
-            returns: Instance of struct `T`
-                Fields:
-                    - test_type: &'static str,
-                    - statistic: f64,
-                    - df: f64,
-                    - p: f64,
+            returns: schemars::Schema
 
-    ➜  (METHOD) hyte::t::TResult::conclude
+➤  Command-line Binary (feature "cli")
+
+    ➜  (BIN) hyte <z|t|t2|paired|chisq-toi|chisq-gof|normality|ks|ks2|correlation|binomial|poisson> [COLUMNS] [OPTIONS]
             <>
-            Intended use: Conclude if a `TResult` should be rejected, given a specified s. l
+            Intended use: Run a test on columns of a CSV or TSV file, or of the standard input.
             <>
-            args:
-                1. significance_level: f64,
-                2. print_output: bool,
-            returns: A `Conclusion` variant; one of `Reject` or `DoNotReject`
+            tests:
+                normality <COLUMN> [--method <shapiro-wilk|anderson-darling|dagostino-pearson|jarque-bera|lilliefors>]
+                ks <COLUMN> <normal MEAN SD|uniform MIN MAX|exponential RATE>, ks2 <COLUMN1> <COLUMN2>
+                correlation <X> <Y> [--method <pearson|spearman|kendall>] [--confidence-level <LEVEL>]
+                binomial <COLUMN> --probability <P> [--mid-p]: the 1s of a column of 0s and 1s are the successes
+                poisson <COLUMN> --rate <RATE> [--exposure <COLUMN>]: each count has an exposure of 1 by default
+            options:
+                -f, --file <PATH>: file to read, or - for the standard input (default)
+                -d, --delimiter <CHAR>: defaults to a tab for .tsv and .tab files, otherwise a comma
+                --no-header: read the first row as data; columns are then selected by 1-based index
+                --missing <propagate|omit|error>: defaults to omit
+                -a, --alpha <LEVEL>: between 0 and 1, defaults to 0.05
+                -t, --tail <lower|upper|both>: defaults to both
+                -o, --format <text|markdown|latex|apa|json>: json prints a record::Record
+            exit codes: 0 = do not reject, 1 = reject, 2 = error
 
-    ➜  (METHOD) hyte::t::TResult::conclude_by_convention
+➤  HTTP Service (feature "serve")
+
+    ➜  (BIN) hyte serve [OPTIONS]
             <>
-            Intended use: Conclude if a `TResult` should be rejected with s. l = 0.05
+            Intended use: Serve every test as POST /v1/<module>/<function>, taking JSON and returning a record::Record.
             <>
-            args:
-                1. significance_level: f64,
-                2. print_output: bool,
-            returns: A `Conclusion` variant; one of `Reject` or `DoNotReject`
+            options:
+                -i, --ip <address>: defaults to 0.0.0.0, or the API_IP environment variable
+                -p, --port <tcp>: defaults to 8000, or the API_PORT environment variable
+                --max-body-bytes <bytes>: larger bodies are rejected with 413; defaults to 1048576
+                --max-trials, --max-expected-count, --max-resamples <count>: costlier requests are rejected with 422
+                --threads <count>: defaults to 4
+            other endpoints: GET /healthz, GET /openapi.json
+            errors: {{"error": {{"status": u16, "code": String, "message": String}}}}
 
-➤  Pearson's Chi-squared Test
-
-    ➜  (FN) hyte::chisquare::test
+    ➜  (FN) hyte::serve::handle
             <>
-            Intended use: Perform Pearson's Chi-squared Test given data.
+            Intended use: Answer a single request without any network access.
             <>
-            args:
-                1. test_type: &str; Expected: "toi" (Test of Independence) or "gof" (Goodness of Fit)
-                2. observed_matrix: Matrix<Number>, where
-                    i. Matrix<Number> is an enum with variants:
-                        - TwoDimensional(Vec<Vec<Number>>),
-                        - OneDimensional(Vec<Number>),
-                    ii. Number: Into<f64>; accepts integer or float
-                3. gof_probabilities: Option<Vec<f64>>; should be `None` iff test_type == "toi"
-                4. print_output: bool
-            returns: Instance of struct `ChiSquare`
-                Fields:
-                    - test_type: &'static str,
-                    - statistic: f64,
-                    - df: usize,
-                    - p: f64,
+            params:
+                - method: &str,
+                - url: &str, (path and query, e.g. "/v1/t/test?alpha=0.05")
+                - body: &[u8],
+            returns: Response {{ status: u16, body: String }}
 
-    ➜  (METHOD) hyte::chisquare::ChiSquareResult::conclude
+    ➜  (FN) hyte::serve::handle_within
             <>
-            Intended use: Conclude if a `ChiSquareResult` should be rejected, given a specified s. l
+            Intended use: Answer a single request like serve::handle, within the given serve::Limits.
             <>
-            args:
-                1. significance_level: f64,
-                2. print_output: bool,
-            returns: A `Conclusion` variant; one of `Reject` or `DoNotReject`
+            params:
+                - method: &str,
+                - url: &str,
+                - body: &[u8],
+                - limits: &Limits, (max_trials, max_expected_count and max_resamples)
+            returns: Response {{ status: u16, body: String }}
 
-    ➜  (METHOD) hyte::chisquare::ChiSquareResult::conclude_by_convention
+➤  Interactive Sessions (feature "repl")
+
+    ➜  (BIN) hyte repl [FILE] [--script <PATH>]
             <>
-            Intended use: Conclude if a `ChiSquareResult` should be rejected with s. l = 0.05
+            Intended use: Load a CSV or TSV file and run tests interactively, or replay an exported session.
             <>
-            args:
-                1. significance_level: f64,
-                2. print_output: bool,
-            returns: A `Conclusion` variant; one of `Reject` or `DoNotReject`
+            commands:
+                load <PATH>: load a file, with the same --delimiter and --no-header options as the tests
+                columns: list the columns of the loaded file
+                z, t, t2, paired, chisq-toi, chisq-gof, normality, ks, ks2, correlation, binomial, poisson: as on
+                the command line; `t a vs b` runs `t2 a b`
+                adjust <METHOD> [--level <LEVEL>], qvalues [--lambda <LAMBDA>]: correct every test run so far
+                clear: start a new family of tests to adjust
+                show [-o <FORMAT>]: show the previous result again
+                history, export <PATH>, help, quit
+            <>
+            Tab completes commands, options, option values, and the columns of the loaded file.
+
+    ➜  (STRUCT) hyte::repl::Session
+            methods:
+                - execute(&mut self, line: &str) -> Result<Option<String>, String>, (None once quit)
+                - complete(&self, line: &str, pos: usize) -> (usize, Vec<String>),
+                - history(&self) -> &[String],
+                - script(&self) -> String,
+                - run_script(&mut self, script: &str) -> Result<String, String>,
 
 ➤  Additional Utilities
 
     ➜  (ENUM) hyte::utils::Matrix
             variants:
-                - TwoDimensional(Vec<Vec<Number>>),
-                - OneDimensional(Vec<Number>), 
-            where Number: Into<f64>; accepts integer or float
+                - TwoDimensional(Vec<Row>),
+                - OneDimensional(Row), 
+            where Row: AsRef<[Number]>, e.g. Vec<i32> or &[f64], and Number: Observation
 
     ➜  (ENUM) hyte::utils::Tails
             variants:
@@ -204,43 +280,198 @@ Source: https://www.github.com/abyanmajid/hyte
                 - UPPER
                 - BOTH
 
+    ➜  (STRUCT) hyte::utils::Alternative
+            <>
+            Intended use: State the alternative hypothesis with its null value, and optionally margins.
+            <>
+            constructors:
+                - Alternative::less(null_value)
+                - Alternative::greater(null_value)
+                - Alternative::two_sided(null_value)
+                - Alternative::equivalence(null_value, lower_margin, upper_margin)
+                - Tails::LOWER.into() etc., which use the test's default null value
+            fields:
+                - direction: Direction::Less or Direction::Greater or Direction::TwoSided
+                - null_value: Option<f64>
+                - margins: Option<(f64, f64)>
+
+    ➜  (TRAIT) hyte::utils::ToPrimitive
+            <>
+            Intended use: Bound on numeric input; re-exported from `num-traits`.
+            <>
+            implemented by: every primitive integer and float, e.g. i32, i64, u64, usize, f32, f64
+
+    ➜  (TRAIT) hyte::utils::Observation
+            <>
+            Intended use: Bound on data which may contain missing values; NaN and None count as missing.
+            <>
+            implemented by: every primitive integer and float, and an Option of each, e.g. Option<f64>
+
+    ➜  (ENUM) hyte::utils::MissingPolicy
+            variants:
+                - Propagate (missing values become NaN, so the result is NaN)
+                - Omit (drop missing values; listwise for paired data and regression)
+                - Error (panic if any value is missing)
+
+    ➜  (STRUCT) hyte::utils::Resampling
+            <>
+            Intended use: Configure how permutation tests and bootstraps draw random resamples.
+            <>
+            constructors:
+                - Resampling::default(), with 9999 resamples, seed 0, and an exact limit of 10000
+                - Resampling::seeded(seed)
+            fields:
+                - resamples: usize
+                - seed: u64
+                - exact_limit: usize, (evaluate every relabelling when there are at most this many)
+                - parallel: bool, (draw resamples with rayon; requires the "parallel" feature)
+
     ➜  (ENUM) hyte::utils::Conclusion
             variants:
                 - Reject
                 - DoNotReject
 
+    ➜  (METHOD) conclude, conclude_by_convention
+            <>
+            Intended use: Conclude whether a result's null hypothesis is rejected, on every result with a p-value.
+            <>
+            methods:
+                - conclude(&self, significance_level: f64) -> Conclusion
+                - conclude_by_convention(&self) -> Conclusion, at a significance level of 0.05
+
+    ➜  (FN) hyte::binomial::interval
+            <>
+            Intended use: Construct a two-sided confidence interval for a proportion.
+            <>
+            args:
+                1. successes: u64
+                2. trials: u64
+                3. method: Interval
+                4. confidence_level: f64
+            returns: Option<(f64, f64)>
+
     ➜  (FN) hyte::utils::mean
             <>
             Intended use: Calculate the mean of a set of numbers.
             <>
             args:
                 1. numbers: &[Number], where
-                    i. Number: Into<f64>; accepts integer or float
+                    i. Number: ToPrimitive; accepts any primitive integer or float
             returns: Option<f64>
 
     ➜  (FN) hyte::utils::variance
             <>
-            Intended use: Calculate the mean of a set of numbers.
+            Intended use: Calculate the sample variance of a set of numbers.
             <>
             args:
                 1. numbers: &[Number], where
-                    i. Number: Into<f64>; accepts integer or float
+                    i. Number: ToPrimitive; accepts any primitive integer or float
+            returns: Option<f64>; None if there are fewer than 2 numbers
+
+    ➜  (FN) hyte::utils::median, hyte::utils::quantile, hyte::utils::quantiles
+            <>
+            Intended use: Calculate the median, or quantiles with one of the nine Hyndman-Fan definitions.
+            <>
+            args:
+                1. numbers: &[Number], where Number: ToPrimitive
+                2. probability: f64, or probabilities: &[f64] (not for `median`)
+                3. method: QuantileType::Type1 to QuantileType::Type9 (not for `median`)
+            returns: Option<f64>, or Option<Vec<f64>> for `quantiles`
+
+    ➜  (FN) hyte::utils::iqr, hyte::utils::mad
+            <>
+            Intended use: Calculate the interquartile range, or the median absolute deviation.
+            <>
+            args:
+                1. numbers: &[Number], where Number: ToPrimitive
+                2. method: QuantileType for `iqr`, or normal_consistent: bool for `mad`
+            returns: Option<f64>
+
+    ➜  (FN) hyte::utils::skewness, hyte::utils::kurtosis
+            <>
+            Intended use: Calculate the sample skewness or excess kurtosis.
+            <>
+            args:
+                1. numbers: &[Number], where Number: ToPrimitive
+                2. bias_corrected: bool
+            returns: Option<f64>
+
+    ➜  (FN) hyte::utils::trimmed_mean, hyte::utils::winsorised_mean
+            <>
+            Intended use: Calculate a mean which is robust to outliers.
+            <>
+            args:
+                1. numbers: &[Number], where Number: ToPrimitive
+                2. proportion: f64; in [0, 0.5), trimmed or replaced at each end
+            returns: Option<f64>
+
+    ➜  (FN) hyte::utils::geometric_mean, hyte::utils::harmonic_mean
+            <>
+            Intended use: Calculate the geometric or harmonic mean of positive numbers.
+            <>
+            args:
+                1. numbers: &[Number], where Number: ToPrimitive
+            returns: Option<f64>
+
+    ➜  (FN) hyte::utils::describe
+            <>
+            Intended use: Calculate every descriptive statistic above at once.
+            <>
+            args:
+                1. numbers: &[Number], where Number: ToPrimitive
+            returns: Option<Description>
+                Fields:
+                    - count: usize,
+                    - mean, min, q1, median, q3, max, iqr, mad, trimmed_mean, winsorised_mean: f64,
+                    - variance, sd, skewness, kurtosis, geometric_mean, harmonic_mean: Option<f64>,
+
+    ➜  (ENUM) hyte::utils::Weights
+            variants:
+                - Frequency; each weight counts repeated observations, so n = Σw
+                - Reliability; survey or importance weights, so n = Kish's effective sample size (Σw)² / Σw²
+
+    ➜  (FN) hyte::utils::weighted_mean, hyte::utils::weighted_variance, hyte::utils::effective_sample_size
+            <>
+            Intended use: Calculate the weighted mean, the weighted sample variance, or Kish's effective sample size.
+            <>
+            args:
+                1. numbers: &[Number], where Number: ToPrimitive (not for `effective_sample_size`)
+                2. weights: &[Weight], where Weight: ToPrimitive
+                3. kind: Weights (only for `weighted_variance`)
             returns: Option<f64>
 
+    ➜  (STRUCT) hyte::utils::RunningStats
+            <>
+            Intended use: Accumulate count, sum, mean and variance in a single numerically stable pass.
+            <>
+            methods:
+                - RunningStats::new(), or collect from an iterator
+                - push(value), extend(values)
+                - merge(&other); combines accumulators built over separate chunks
+                - count(), sum(), mean(), variance(), sd()
+
 "#);
 }
 
+#[cfg(test)]
+mod test_helpers {
+    /// Rounds `x` to `digits` decimal places, for comparing results with published values.
+    pub(crate) fn round(x: f64, digits: i32) -> f64 {
+        let factor = 10_f64.powi(digits);
+        (x * factor).round() / factor
+    }
+}
 
 #[cfg(test)]
 mod z_testcases {
     use super::*;
-    use crate::utils::{Tails, Conclusion};
+    use crate::utils::{Tails, Conclusion, MissingPolicy};
 
     #[test]
     fn test_with_typical_data() {
         let data = vec![2.0, 3.0, 4.0, 5.0, 6.0];
         let expected_mean = 4.0;
-        let result = z::test(data, expected_mean, Tails::BOTH, false).unwrap();
+        let result = z::test(data, expected_mean, Tails::BOTH, MissingPolicy::Propagate).unwrap();
         assert!(result.statistic >= -2.0 && result.statistic <= 2.0);
         assert!(result.p >= 0.0 && result.p <= 1.0);
     }
@@ -249,22 +480,35 @@ mod z_testcases {
     fn test_with_empty_data() {
         let data: Vec<f64> = Vec::new();
         let expected_mean = 0.0;
-        let result = z::test(data, expected_mean, Tails::BOTH, false);
+        let result = z::test(data, expected_mean, Tails::BOTH, MissingPolicy::Propagate);
         assert_eq!(result, None);
     }
 
     #[test]
+    #[should_panic(expected = "[HYTE-Panic] All values in the data are identical!")]
     fn test_with_zero_variance() {
         let data = vec![3.0, 3.0, 3.0, 3.0, 3.0];
         let expected_mean = 3.0;
-        let result = z::test(data, expected_mean, Tails::BOTH, false).unwrap();
-        assert!(result.statistic.is_nan());
-        assert!(result.p.is_nan());
+        z::test(data, expected_mean, Tails::BOTH, MissingPolicy::Propagate);
+    }
+
+    #[test]
+    fn test_dataless_extreme_statistics() {
+        // The upper tail is computed directly rather than as `1.0 - cdf`, which would give 0
+        let result = z::test_dataless(13.3, 0.0, 1, 1.0, Tails::UPPER).unwrap();
+        assert!((result.p / 1.157341628369033e-40 - 1.0).abs() < 1e-10);
+        assert!((result.log_p - result.p.ln()).abs() < 1e-10);
+        // Beyond the range of f64, only the log p-value is available
+        let result = z::test_dataless(40.0, 0.0, 1, 1.0, Tails::LOWER).unwrap();
+        assert_eq!(result.p, 1.0);
+        let result = z::test_dataless(40.0, 0.0, 1, 1.0, Tails::BOTH).unwrap();
+        assert_eq!(result.p, 0.0);
+        assert!((result.log_p - (-804.6084420137538 + std::f64::consts::LN_2)).abs() < 1e-8);
     }
 
     #[test]
     fn test_dataless_normal_case() {
-        let result = z::test_dataless(5.0, 4.5, 30, 1.0, Tails::UPPER, false).unwrap();
+        let result = z::test_dataless(5.0, 4.5, 30, 1.0, Tails::UPPER).unwrap();
         assert_eq!((result.statistic * 100.0).round() / 100.0, 2.74);
         assert_eq!((result.p * 100.0).round(), 0.0);
     }
@@ -272,40 +516,40 @@ mod z_testcases {
     #[test]
     #[should_panic]
     fn test_dataless_invalid_sample_size() {
-        z::test_dataless(5.0, 4.5, 0, 1.0, Tails::UPPER, false).unwrap();
+        z::test_dataless(5.0, 4.5, 0, 1.0, Tails::UPPER).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn test_dataless_negative_standard_deviation() {
-        z::test_dataless(5.0, 4.5, 30, -1.0, Tails::UPPER, false).unwrap();
+        z::test_dataless(5.0, 4.5, 30, -1.0, Tails::UPPER).unwrap();
     }
 
     #[test]
     fn conclude_with_reject() {
-        let z_result = z::ZResult { test_type: "Test", statistic: 2.5, p: 0.01 };
-        let conclusion = z_result.conclude(0.05, false);
+        let z_result = z::ZResult { test_type: "Test", statistic: 2.5, p: 0.01, log_p: 0.01_f64.ln(), hypotheses: String::new(), omitted: 0 };
+        let conclusion = z_result.conclude(0.05);
         assert_eq!(conclusion, Conclusion::Reject);
     }
 
     #[test]
     fn conclude_with_do_not_reject() {
-        let z_result = z::ZResult { test_type: "Test", statistic: 1.5, p: 0.10 };
-        let conclusion = z_result.conclude(0.05, false);
+        let z_result = z::ZResult { test_type: "Test", statistic: 1.5, p: 0.10, log_p: 0.10_f64.ln(), hypotheses: String::new(), omitted: 0 };
+        let conclusion = z_result.conclude(0.05);
         assert_eq!(conclusion, Conclusion::DoNotReject);
     }
 
     #[test]
     fn conclude_by_convention_with_reject() {
-        let z_result = z::ZResult { test_type: "Test", statistic: 2.5, p: 0.01 };
-        let conclusion = z_result.conclude_by_convention(false);
+        let z_result = z::ZResult { test_type: "Test", statistic: 2.5, p: 0.01, log_p: 0.01_f64.ln(), hypotheses: String::new(), omitted: 0 };
+        let conclusion = z_result.conclude_by_convention();
         assert_eq!(conclusion, Conclusion::Reject);
     }
 
     #[test]
     fn conclude_by_convention_with_do_not_reject() {
-        let z_result = z::ZResult { test_type: "Test", statistic: 1.5, p: 0.10 };
-        let conclusion = z_result.conclude_by_convention(false);
+        let z_result = z::ZResult { test_type: "Test", statistic: 1.5, p: 0.10, log_p: 0.10_f64.ln(), hypotheses: String::new(), omitted: 0 };
+        let conclusion = z_result.conclude_by_convention();
         assert_eq!(conclusion, Conclusion::DoNotReject);
     }
 }
@@ -313,13 +557,13 @@ mod z_testcases {
 #[cfg(test)]
 mod t_testcases {
     use super::*;
-    use crate::utils::{Tails, Conclusion};
+    use crate::utils::{Tails, Conclusion, MissingPolicy};
 
     // Test Cases for `test` function
     #[test]
     fn test_typical_case() {
         let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
-        let result = t::test(data, 3.0, Tails::LOWER, false);
+        let result = t::test(data, 3.0, Tails::LOWER, MissingPolicy::Propagate);
         assert!(result.is_some());
         let result = result.unwrap();
         assert_eq!(result.test_type, "(1-Sample) One-Sided T-Test for Mean (Lower-Tailed)");
@@ -328,29 +572,62 @@ mod t_testcases {
     #[test]
     fn test_empty_data() {
         let data = Vec::<f64>::new();
-        let result = t::test(data, 3.0, Tails::LOWER, false);
+        let result = t::test(data, 3.0, Tails::LOWER, MissingPolicy::Propagate);
         assert!(result.is_none());
     }
 
     // Test Cases for `test_dataless` function
     #[test]
     fn test_dataless_typical_case() {
-        let result = t::test_dataless(2.5, 3.0, 30, 0.5, Tails::UPPER, false);
+        let result = t::test_dataless(2.5, 3.0, 30, 0.5, Tails::UPPER);
         assert!(result.is_some());
         let result = result.unwrap();
         assert_eq!(result.test_type, "(1-Sample) One-Sided T-Test for Mean (Upper-Tailed)");
     }
 
+    #[test]
+    fn test_dataless_extreme_statistics() {
+        let result = t::test_dataless(60.0, 0.0, 11, 11_f64.sqrt(), Tails::UPPER).unwrap();
+        assert!((result.p / 2.009261425344203e-14 - 1.0).abs() < 1e-10);
+        let result = t::test_dataless(1e40, 0.0, 11, 11_f64.sqrt(), Tails::UPPER).unwrap();
+        assert_eq!(result.p, 0.0);
+        assert!((result.log_p - -911.616301631296).abs() < 1e-8);
+    }
+
     #[test]
     #[should_panic(expected = "[HYTE-Panic] Sample size must be greater than 0!")]
     fn test_dataless_zero_sample_size() {
-        t::test_dataless(2.5, 3.0, 0, 0.5, Tails::UPPER, false);
+        t::test_dataless(2.5, 3.0, 0, 0.5, Tails::UPPER);
     }
 
     #[test]
     #[should_panic(expected = "[HYTE-Panic] Standard deviation must not be a negative number!")]
     fn test_dataless_negative_standard_deviation() {
-        t::test_dataless(2.5, 3.0, 30, -0.5, Tails::UPPER, false);
+        t::test_dataless(2.5, 3.0, 30, -0.5, Tails::UPPER);
+    }
+
+    // Test Cases for `test_paired` function
+    #[test]
+    fn test_paired_typical_case() {
+        let result = t::test_paired(vec![72, 75, 68, 80, 77, 74], vec![70, 73, 69, 76, 74, 70], Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.test_type, "(Paired) Two-Sided T-Test for Mean Difference");
+        assert!((result.statistic - 3.0697030675746024).abs() < 1e-12);
+        assert!((result.p - 0.027793195443138623).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_paired_omits_incomplete_pairs() {
+        let result = t::test_paired(vec![Some(72.0), None, Some(68.0), Some(80.0)], vec![Some(70.0), Some(73.0), Some(69.0), None], crate::utils::Alternative::greater(-1.0), MissingPolicy::Omit).unwrap();
+        let expected = t::test(vec![2.0, -1.0], -1.0, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.omitted, 2);
+        assert_eq!(result.statistic, expected.statistic);
+        assert_eq!(result.hypotheses, "H₀: μ₁ - μ₂ = -1.0 vs H₁: μ₁ - μ₂ > -1.0");
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The lengths of data1 (2) and data2 (3) do not match!")]
+    fn test_paired_unequal_lengths() {
+        t::test_paired(vec![1, 2], vec![1, 2, 3], Tails::BOTH, MissingPolicy::Propagate);
     }
 
     // Test Cases for `test_two_samples` function
@@ -358,7 +635,7 @@ mod t_testcases {
     fn test_two_samples_typical_case() {
         let group1 = vec![20.0, 21.0, 22.0];
         let group2 = vec![23.0, 24.0, 25.0];
-        let result = t::test_two_samples(group1, group2, false);
+        let result = t::test_two_samples(group1, group2, Tails::BOTH, MissingPolicy::Propagate);
         assert!(result.is_some());
         let result = result.unwrap();
         assert_eq!(result.test_type, "(2-Sample) T-Test for Mean");
@@ -368,46 +645,46 @@ mod t_testcases {
     fn test_two_samples_empty_group() {
         let group1 = vec![];
         let group2 = vec![23.0, 24.0, 25.0];
-        let result = t::test_two_samples(group1, group2, false);
+        let result = t::test_two_samples(group1, group2, Tails::BOTH, MissingPolicy::Propagate);
         assert!(result.is_none());
     }
 
     // Test Cases for `TResult` methods
     #[test]
     fn conclude_reject() {
-        let result = t::TResult { test_type: "Test", statistic: 2.5, df: 29.0, p: 0.01 };
-        assert_eq!(result.conclude(0.05, false), Conclusion::Reject);
+        let result = t::TResult { test_type: "Test", statistic: 2.5, df: 29.0, p: 0.01, log_p: 0.01_f64.ln(), cohens_d: 0.5, hypotheses: String::new(), omitted: 0 };
+        assert_eq!(result.conclude(0.05), Conclusion::Reject);
     }
 
     #[test]
     fn conclude_do_not_reject() {
-        let result = t::TResult { test_type: "Test", statistic: 1.5, df: 29.0, p: 0.10 };
-        assert_eq!(result.conclude(0.05, false), Conclusion::DoNotReject);
+        let result = t::TResult { test_type: "Test", statistic: 1.5, df: 29.0, p: 0.10, log_p: 0.10_f64.ln(), cohens_d: 0.5, hypotheses: String::new(), omitted: 0 };
+        assert_eq!(result.conclude(0.05), Conclusion::DoNotReject);
     }
 
     #[test]
     fn conclude_by_convention_reject() {
-        let result = t::TResult { test_type: "Test", statistic: 2.5, df: 29.0, p: 0.01 };
-        assert_eq!(result.conclude_by_convention(false), Conclusion::Reject);
+        let result = t::TResult { test_type: "Test", statistic: 2.5, df: 29.0, p: 0.01, log_p: 0.01_f64.ln(), cohens_d: 0.5, hypotheses: String::new(), omitted: 0 };
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
     }
 
     #[test]
     fn conclude_by_convention_do_not_reject() {
-        let result = t::TResult { test_type: "Test", statistic: 1.5, df: 29.0, p: 0.10 };
-        assert_eq!(result.conclude_by_convention(false), Conclusion::DoNotReject);
+        let result = t::TResult { test_type: "Test", statistic: 1.5, df: 29.0, p: 0.10, log_p: 0.10_f64.ln(), cohens_d: 0.5, hypotheses: String::new(), omitted: 0 };
+        assert_eq!(result.conclude_by_convention(), Conclusion::DoNotReject);
     }
 }
 
 #[cfg(test)]
 mod chisquare_testcases {
     use super::*;
-    use crate::utils::{Matrix, Conclusion};
+    use crate::utils::{Matrix, Conclusion, MissingPolicy};
 
     // Test Cases for `test` function
     #[test]
     fn test_toi_typical_case() {
         let matrix = Matrix::TwoDimensional(vec![vec![10, 20], vec![20, 10]]);
-        let result = chisquare::test("toi", matrix, None, false);
+        let result = chisquare::test("toi", matrix, None, MissingPolicy::Propagate);
         assert!(result.is_some());
         let result = result.unwrap();
         assert_eq!(result.test_type, "Pearson's Chi-squared Test of Independence");
@@ -416,56 +693,2624 @@ mod chisquare_testcases {
     #[test]
     fn test_gof_typical_case() {
         let matrix = Matrix::OneDimensional(vec![10, 20, 30]);
-        let gof_probabilities = Some(vec![0.2, 0.3, 0.5]);
-        let result = chisquare::test("gof", matrix, gof_probabilities, false);
+        let gof_probabilities = Some(&[0.2, 0.3, 0.5][..]);
+        let result = chisquare::test("gof", matrix, gof_probabilities, MissingPolicy::Propagate);
         assert!(result.is_some());
         let result = result.unwrap();
         assert_eq!(result.test_type, "Pearson's Chi-squared Goodness Of Fit");
     }
 
+    #[test]
+    fn test_borrowed_rows() {
+        let owned = chisquare::test("toi", Matrix::TwoDimensional(vec![vec![10, 20], vec![20, 10]]), None, MissingPolicy::Propagate).unwrap();
+        let (first, second) = ([10, 20], vec![20, 10]);
+        let borrowed = chisquare::test("toi", Matrix::TwoDimensional(vec![&first[..], &second]), None, MissingPolicy::Propagate).unwrap();
+        assert_eq!(borrowed, owned);
+
+        let counts = vec![Some(10.0), Some(30.0)];
+        let probabilities = vec![0.2, 0.8];
+        let result = chisquare::test("gof", Matrix::OneDimensional(&counts), Some(&probabilities), MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.df, 1);
+    }
+
+    #[test]
+    fn test_toi_extreme_statistic() {
+        let matrix = Matrix::TwoDimensional(vec![vec![1000, 0], vec![0, 1000]]);
+        let result = chisquare::test("toi", matrix, None, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.statistic, 2000.0);
+        assert_eq!(result.p, 0.0);
+        assert!((result.log_p - -1004.026741958952).abs() < 1e-8);
+    }
+
     #[test]
     #[should_panic(expected = "[HYTE-Panic] You must not pass in an empty matrix!")]
     fn test_empty_matrix() {
-        let matrix: Matrix<f64> = utils::Matrix::TwoDimensional(vec![]);
-        chisquare::test("toi", matrix, None, false);
+        let matrix: Matrix<Vec<f64>> = utils::Matrix::TwoDimensional(vec![]);
+        chisquare::test("toi", matrix, None, MissingPolicy::Propagate);
     }
 
     #[test]
     #[should_panic(expected = "[HYTE-Panic] You must not pass in a matrix with a negative number!")]
     fn test_negative_number_in_matrix() {
         let matrix = Matrix::TwoDimensional(vec![vec![-1, 20], vec![20, 10]]);
-        chisquare::test("toi", matrix, None, false);
+        chisquare::test("toi", matrix, None, MissingPolicy::Propagate);
     }
 
     #[test]
     #[should_panic(expected = "[HYTE-Panic] You must not pass in a matrix with rows of different lengths!")]
     fn test_unequal_row_lengths() {
         let matrix = Matrix::TwoDimensional(vec![vec![10, 20], vec![30]]);
-        chisquare::test("toi", matrix, None, false);
+        chisquare::test("toi", matrix, None, MissingPolicy::Propagate);
+    }
+
+    #[test]
+    fn test_toi_omits_rows_with_missing_counts() {
+        let complete = chisquare::test("toi", Matrix::TwoDimensional(vec![vec![10.0, 20.0], vec![20.0, 10.0]]), None, MissingPolicy::Propagate).unwrap();
+        let matrix = Matrix::TwoDimensional(vec![vec![10.0, 20.0], vec![5.0, f64::NAN], vec![20.0, 10.0]]);
+        let result = chisquare::test("toi", matrix, None, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 1);
+        assert_eq!(result.statistic, complete.statistic);
+
+        let matrix = Matrix::TwoDimensional(vec![vec![Some(10), Some(20)], vec![None, Some(5)], vec![Some(20), Some(10)]]);
+        assert_eq!(chisquare::test("toi", matrix, None, MissingPolicy::Omit).unwrap().omitted, 1);
+
+        let matrix = Matrix::TwoDimensional(vec![vec![10.0, 20.0], vec![5.0, f64::NAN], vec![20.0, 10.0]]);
+        assert!(chisquare::test("toi", matrix, None, MissingPolicy::Propagate).unwrap().p.is_nan());
+    }
+
+    #[test]
+    fn test_gof_omits_missing_counts() {
+        let complete = chisquare::test("gof", Matrix::OneDimensional(vec![10, 30]), Some(&[0.2, 0.8][..]), MissingPolicy::Propagate).unwrap();
+        let matrix = Matrix::OneDimensional(vec![Some(10), None, Some(30)]);
+        let result = chisquare::test("gof", matrix, Some(&[0.1, 0.5, 0.4][..]), MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 1);
+        assert_eq!(result.df, 1);
+        assert!((result.statistic - complete.statistic).abs() < 1e-12);
+
+        let matrix = Matrix::OneDimensional(vec![None::<f64>, None]);
+        assert!(chisquare::test("gof", matrix, Some(&[0.5, 0.5][..]), MissingPolicy::Omit).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The data contains 1 missing observation!")]
+    fn test_missing_count_error() {
+        let matrix = Matrix::TwoDimensional(vec![vec![Some(10), Some(20)], vec![None, Some(5)]]);
+        chisquare::test("toi", matrix, None, MissingPolicy::Error);
     }
 
     // Test Cases for `ChiSquareResult` methods
     #[test]
     fn conclude_reject() {
-        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 10.5, df: 2, p: 0.01 };
-        assert_eq!(result.conclude(0.05, false), Conclusion::Reject);
+        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 10.5, df: 2, p: 0.01, log_p: 0.01_f64.ln(), omitted: 0 };
+        assert_eq!(result.conclude(0.05), Conclusion::Reject);
     }
 
     #[test]
     fn conclude_do_not_reject() {
-        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 2.5, df: 2, p: 0.10 };
-        assert_eq!(result.conclude(0.05, false), Conclusion::DoNotReject);
+        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 2.5, df: 2, p: 0.10, log_p: 0.10_f64.ln(), omitted: 0 };
+        assert_eq!(result.conclude(0.05), Conclusion::DoNotReject);
     }
 
     #[test]
     fn conclude_by_convention_reject() {
-        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 10.5, df: 2, p: 0.01 };
-        assert_eq!(result.conclude_by_convention(false), Conclusion::Reject);
+        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 10.5, df: 2, p: 0.01, log_p: 0.01_f64.ln(), omitted: 0 };
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
     }
 
     #[test]
     fn conclude_by_convention_do_not_reject() {
-        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 2.5, df: 2, p: 0.10 };
-        assert_eq!(result.conclude_by_convention(false), Conclusion::DoNotReject);
+        let result = chisquare::ChiSquareResult { test_type: "Test", statistic: 2.5, df: 2, p: 0.10, log_p: 0.10_f64.ln(), omitted: 0 };
+        assert_eq!(result.conclude_by_convention(), Conclusion::DoNotReject);
+    }
+}
+
+#[cfg(test)]
+mod multiple_testcases {
+    use super::*;
+    use crate::multiple::Correction;
+    use crate::utils::Tails;
+    use crate::test_helpers::round;
+
+
+    // Test Cases for `adjust` function
+    #[test]
+    fn adjust_bonferroni() {
+        let result = multiple::adjust(&[0.01, 0.02, 0.3], Correction::Bonferroni, 0.05).unwrap();
+        let adjusted: Vec<f64> = result.adjusted.iter().map(|&p| round(p, 6)).collect();
+        assert_eq!(adjusted, vec![0.03, 0.06, 0.9]);
+        assert_eq!(result.reject, vec![true, false, false]);
+    }
+
+    #[test]
+    fn adjust_holm() {
+        let result = multiple::adjust(&[0.01, 0.04, 0.03, 0.005], Correction::Holm, 0.05).unwrap();
+        let adjusted: Vec<f64> = result.adjusted.iter().map(|&p| round(p, 6)).collect();
+        assert_eq!(adjusted, vec![0.03, 0.06, 0.06, 0.02]);
+        assert_eq!(result.reject, vec![true, false, false, true]);
+    }
+
+    #[test]
+    fn adjust_hochberg() {
+        let result = multiple::adjust(&[0.01, 0.04, 0.03, 0.005], Correction::Hochberg, 0.05).unwrap();
+        let adjusted: Vec<f64> = result.adjusted.iter().map(|&p| round(p, 6)).collect();
+        assert_eq!(adjusted, vec![0.03, 0.04, 0.04, 0.02]);
+        assert_eq!(result.reject, vec![true, true, true, true]);
+    }
+
+    #[test]
+    fn adjust_benjamini_yekutieli() {
+        let result = multiple::adjust(&[0.01, 0.04, 0.03, 0.005], Correction::BenjaminiYekutieli, 0.05).unwrap();
+        let adjusted: Vec<f64> = result.adjusted.iter().map(|&p| round(p, 6)).collect();
+        // Benjamini-Hochberg adjusted p-values scaled by 1 + 1/2 + 1/3 + 1/4
+        assert_eq!(adjusted, vec![0.041667, 0.083333, 0.083333, 0.041667]);
+        assert_eq!(result.reject, vec![true, false, false, true]);
+    }
+
+    #[test]
+    fn adjust_caps_at_one() {
+        let result = multiple::adjust(&[0.5, 0.9], Correction::Bonferroni, 0.05).unwrap();
+        assert_eq!(result.adjusted, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn adjust_empty() {
+        assert!(multiple::adjust(&[], Correction::Holm, 0.05).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] P-values must be between 0 and 1!")]
+    fn adjust_invalid_p_value() {
+        multiple::adjust(&[0.01, 1.5], Correction::Holm, 0.05);
+    }
+
+    #[test]
+    fn adjust_results_from_z_tests() {
+        let results = vec![
+            z::test_dataless(5.0, 4.5, 30, 1.0, Tails::UPPER).unwrap(),
+            z::test_dataless(4.6, 4.5, 30, 1.0, Tails::UPPER).unwrap(),
+        ];
+        let corrected = multiple::adjust_results(&results, Correction::Bonferroni, 0.05).unwrap();
+        assert_eq!(corrected.adjusted[0], 2.0 * results[0].p);
+        assert_eq!(corrected.reject, vec![true, false]);
+    }
+
+    // Test Cases for `qvalues` function
+    #[test]
+    fn qvalues_pi0_estimate() {
+        let result = multiple::qvalues(&[0.01, 0.02, 0.7, 0.8], 0.5, 0.05).unwrap();
+        assert_eq!(result.pi0, 1.0);
+        let q: Vec<f64> = result.q.iter().map(|&p| round(p, 6)).collect();
+        assert_eq!(q, vec![0.04, 0.04, 0.8, 0.8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Lambda must be in the interval [0, 1)!")]
+    fn qvalues_invalid_lambda() {
+        multiple::qvalues(&[0.01, 0.02], 1.0, 0.05);
+    }
+}
+
+#[cfg(test)]
+mod normality_testcases {
+    use super::*;
+    use crate::utils::{Conclusion, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    // Test Cases for `shapiro_wilk` function
+    #[test]
+    fn shapiro_wilk_non_normal() {
+        let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+        let result = normality::shapiro_wilk(data, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 5), 0.78881);
+        assert_eq!(round(result.p, 6), 0.006704);
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
+    }
+
+    #[test]
+    fn shapiro_wilk_sample_size_three() {
+        let result = normality::shapiro_wilk(vec![1.0, 2.0, 4.0], MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 0.9643);
+        assert_eq!(round(result.p, 4), 0.6369);
+    }
+
+    #[test]
+    fn shapiro_wilk_normal_like() {
+        let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+        let result = normality::shapiro_wilk(data, MissingPolicy::Propagate).unwrap();
+        assert!(result.statistic > 0.95);
+        assert_eq!(result.conclude_by_convention(), Conclusion::DoNotReject);
+    }
+
+    #[test]
+    fn shapiro_wilk_empty_data() {
+        assert!(normality::shapiro_wilk(Vec::<f64>::new(), MissingPolicy::Propagate).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Shapiro-Wilk test requires a sample size between 3 and 5000!")]
+    fn shapiro_wilk_too_small() {
+        normality::shapiro_wilk(vec![1.0, 2.0], MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] All values in the data are identical!")]
+    fn shapiro_wilk_identical_values() {
+        normality::shapiro_wilk(vec![3.0, 3.0, 3.0, 3.0], MissingPolicy::Propagate);
+    }
+
+    // Test Cases for `anderson_darling` function
+    #[test]
+    fn anderson_darling_non_normal() {
+        let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+        let result = normality::anderson_darling(data, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 0.9468);
+        assert_eq!(round(result.p, 4), 0.0105);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Anderson-Darling test requires a sample size of at least 8!")]
+    fn anderson_darling_too_small() {
+        normality::anderson_darling(vec![1.0, 2.0, 3.0], MissingPolicy::Propagate);
+    }
+
+    // Test Cases for `dagostino_pearson` function
+    #[test]
+    fn dagostino_pearson_non_normal() {
+        let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+        let result = normality::dagostino_pearson(data, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 13.0343);
+        assert_eq!(result.conclude(0.01), Conclusion::Reject);
+    }
+
+    // Test Cases for `jarque_bera` function
+    #[test]
+    fn jarque_bera_non_normal() {
+        let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+        let result = normality::jarque_bera(data, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 6.9828);
+        assert_eq!(round(result.p, 10), round((-result.statistic / 2.0).exp(), 10));
+    }
+}
+
+#[cfg(test)]
+mod ks_testcases {
+    use super::*;
+    use crate::utils::{Tails, Conclusion, MissingPolicy};
+    use statrs::distribution::{ContinuousCDF, Normal};
+    use crate::test_helpers::round;
+
+
+    // Test Cases for `test` function
+    #[test]
+    fn test_two_sided_against_normal() {
+        let data = vec![0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64, 0.05, -0.06, 0.64, -0.82, 0.37, 1.77, 1.09, -1.28, 2.36, 1.31, 1.05, -0.32, -0.4, 1.06, -2.47];
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let result = ks::test(data, &normal, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 0.1741);
+        assert_eq!(result.conclude_by_convention(), Conclusion::DoNotReject);
+    }
+
+    #[test]
+    fn test_exact_critical_value() {
+        // The exact 5% critical value of D for n = 10 is 0.40925
+        let data: Vec<f64> = (1..=10).map(|i| i as f64 / 10.0 - 0.40925).collect();
+        let uniform = statrs::distribution::Uniform::new(0.0, 1.0).unwrap();
+        let result = ks::test(data, &uniform, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 5), 0.40925);
+        assert_eq!(round(result.p, 4), 0.05);
+    }
+
+    #[test]
+    fn test_one_sided_tails() {
+        let data = vec![0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64];
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let both = ks::test(data.clone(), &normal, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        let upper = ks::test(data.clone(), &normal, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        let lower = ks::test(data, &normal, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+        assert_eq!(both.statistic, upper.statistic.max(lower.statistic));
+        assert!(upper.p >= both.p / 2.0 || lower.p >= both.p / 2.0);
+    }
+
+    #[test]
+    fn test_asymptotic_for_large_samples() {
+        let data: Vec<f64> = (0..200).map(|i| (i as f64 + 0.5) / 200.0).collect();
+        let uniform = statrs::distribution::Uniform::new(0.0, 1.0).unwrap();
+        let result = ks::test(data, &uniform, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.test_type, "One-Sample Kolmogorov-Smirnov Test (Two-Sided, Asymptotic)");
+        assert_eq!(result.p, 1.0);
+    }
+
+    #[test]
+    fn test_exact_p_values_do_not_underflow() {
+        // Every observation lies above 6, so D- is about 1 - ε for ε = P(Z > 6), and P(D- >= D-) = ε^50
+        let data: Vec<f64> = (0..50).map(|i| 6.0 + i as f64 / 1000.0).collect();
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let log_epsilon = 50.0 * (1.0 - normal.cdf(6.0)).ln();
+        let both = ks::test(&data, &normal, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        let lower = ks::test(&data, &normal, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+        assert_eq!((both.p, lower.p), (0.0, 0.0));
+        assert_eq!(round(lower.log_p / log_epsilon, 5), 1.0);
+        assert_eq!(round((both.log_p - std::f64::consts::LN_2) / log_epsilon, 5), 1.0);
+    }
+
+    #[test]
+    fn test_empty_data() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        assert!(ks::test(Vec::<f64>::new(), &normal, Tails::BOTH, MissingPolicy::Propagate).is_none());
+    }
+
+    // Test Cases for `test_two_samples` function
+    #[test]
+    fn test_two_samples_exact() {
+        let group1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let group2 = vec![1.5, 2.5, 6.0, 7.0, 8.0];
+        let result = ks::test_two_samples(group1, group2, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.statistic, 0.6);
+        assert_eq!(round(result.p, 4), 0.3571);
+    }
+
+    #[test]
+    fn test_two_samples_one_sided() {
+        let group1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let group2 = vec![6.0, 7.0, 8.0, 9.0, 10.0];
+        let upper = ks::test_two_samples(group1.clone(), group2.clone(), Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        let lower = ks::test_two_samples(group1, group2, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+        assert_eq!(upper.statistic, 1.0);
+        assert_eq!(round(upper.p, 6), round(1.0 / 252.0, 6));
+        assert_eq!(lower.statistic, 0.0);
+        assert_eq!(lower.p, 1.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_two_samples_fully_separated() {
+        // Only the 2 paths along the edges of the lattice reach D = 1, out of C(80, 40)
+        let group1: Vec<f64> = (0..40).map(|i| i as f64).collect();
+        let group2: Vec<f64> = (40..80).map(|i| i as f64).collect();
+        let ln_paths = statrs::function::factorial::ln_binomial(80, 40);
+        let both = ks::test_two_samples(&group1, &group2, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        let upper = ks::test_two_samples(&group1, &group2, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        assert_eq!(both.test_type, "Two-Sample Kolmogorov-Smirnov Test (Two-Sided, Exact)");
+        assert_eq!(round(both.p / (std::f64::consts::LN_2 - ln_paths).exp(), 10), 1.0);
+        assert_eq!(round(both.log_p - std::f64::consts::LN_2 + ln_paths, 10), 0.0);
+        assert_eq!(round(upper.log_p + ln_paths, 10), 0.0);
+    }
+
+    #[test]
+    fn test_two_samples_empty_group() {
+        assert!(ks::test_two_samples(vec![], vec![1.0, 2.0], Tails::BOTH, MissingPolicy::Propagate).is_none());
+    }
+
+    // Test Cases for `lilliefors` function
+    #[test]
+    fn lilliefors_non_normal() {
+        let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+        let result = ks::lilliefors(data, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 0.2592);
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Lilliefors test requires a sample size of at least 5!")]
+    fn lilliefors_too_small() {
+        ks::lilliefors(vec![1.0, 2.0, 3.0], MissingPolicy::Propagate);
+    }
+}
+
+#[cfg(test)]
+mod homogeneity_testcases {
+    use super::*;
+    use crate::utils::{Conclusion, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    fn insect_sprays() -> Vec<Vec<i32>> {
+        vec![
+            vec![10, 7, 20, 14, 14, 12, 10, 23, 17, 20, 14, 13],
+            vec![11, 17, 21, 11, 16, 14, 17, 17, 19, 21, 7, 13],
+            vec![0, 1, 7, 2, 3, 1, 2, 1, 3, 0, 1, 4],
+            vec![3, 5, 12, 6, 4, 3, 5, 5, 5, 5, 2, 4],
+            vec![3, 5, 3, 5, 3, 6, 1, 1, 3, 2, 6, 4],
+            vec![11, 9, 15, 22, 15, 16, 13, 10, 26, 26, 24, 13],
+        ]
+    }
+
+    #[test]
+    fn levene_insect_sprays() {
+        let result = homogeneity::levene(insect_sprays(), MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 6.4554);
+        assert_eq!((result.df1, result.df2), (5, Some(66)));
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
+    }
+
+    #[test]
+    fn brown_forsythe_insect_sprays() {
+        let result = homogeneity::brown_forsythe(insect_sprays(), MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 4), 3.8214);
+        assert_eq!(round(result.p, 6), 0.004223);
+    }
+
+    #[test]
+    fn bartlett_insect_sprays() {
+        let result = homogeneity::bartlett(insect_sprays(), MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 2), 25.96);
+        assert_eq!((result.df1, result.df2), (5, None));
+        assert_eq!(round(result.p * 1e5, 3), 9.085);
+    }
+
+    #[test]
+    fn fligner_killeen_insect_sprays() {
+        let result = homogeneity::fligner_killeen(insect_sprays(), MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 3), 14.483);
+        assert_eq!(round(result.p, 5), 0.01282);
+    }
+
+    #[test]
+    fn levene_equal_spread() {
+        let groups = vec![vec![1.0, 2.0, 3.0, 4.0], vec![11.0, 12.0, 13.0, 14.0]];
+        let result = homogeneity::levene(groups, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.statistic, 0.0);
+        assert_eq!(result.p, 1.0);
+    }
+
+    #[test]
+    fn empty_group() {
+        let groups = vec![vec![1.0, 2.0, 3.0], vec![]];
+        assert!(homogeneity::levene(groups, MissingPolicy::Propagate).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] You must pass in at least 2 groups!")]
+    fn single_group() {
+        homogeneity::bartlett(vec![vec![1.0, 2.0, 3.0]], MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Every group must contain at least 2 observations!")]
+    fn group_with_one_observation() {
+        homogeneity::fligner_killeen(vec![vec![1.0, 2.0, 3.0], vec![4.0]], MissingPolicy::Propagate);
+    }
+}
+
+#[cfg(test)]
+mod correlation_testcases {
+    use super::*;
+    use crate::utils::{Tails, Conclusion, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    fn hollander_wolfe() -> (Vec<f64>, Vec<f64>) {
+        (
+            vec![44.4, 45.9, 41.9, 53.3, 44.7, 44.1, 50.7, 45.2, 60.1],
+            vec![2.6, 3.1, 2.5, 5.0, 3.6, 4.0, 5.2, 2.8, 3.8],
+        )
+    }
+
+    // Test Cases for `pearson` function
+    #[test]
+    fn pearson_two_sided() {
+        let (x, y) = hollander_wolfe();
+        let result = correlation::pearson(x, y, Tails::BOTH, 0.95, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.estimate, 7), 0.5711816);
+        assert_eq!(round(result.statistic, 4), 1.8411);
+        assert_eq!(round(result.p, 4), 0.1082);
+        let (lower, upper) = result.confidence_interval.unwrap();
+        assert_eq!((round(lower, 7), round(upper, 7)), (-0.1497426, 0.8955795));
+        assert_eq!(result.conclude_by_convention(), Conclusion::DoNotReject);
+    }
+
+    #[test]
+    fn pearson_one_sided_interval() {
+        let (x, y) = hollander_wolfe();
+        let result = correlation::pearson(x, y, Tails::UPPER, 0.95, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.p, 5), 0.05409);
+        assert_eq!(result.confidence_interval.unwrap().1, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The lengths of x (3) and y (2) do not match!")]
+    fn pearson_unequal_lengths() {
+        correlation::pearson(vec![1.0, 2.0, 3.0], vec![1.0, 2.0], Tails::BOTH, 0.95, MissingPolicy::Propagate);
+    }
+
+    #[test]
+    fn pearson_empty_data() {
+        assert!(correlation::pearson(Vec::<f64>::new(), vec![], Tails::BOTH, 0.95, MissingPolicy::Propagate).is_none());
+    }
+
+    // Test Cases for `spearman` function
+    #[test]
+    fn spearman_exact() {
+        let (x, y) = hollander_wolfe();
+        let result = correlation::spearman(x, y, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.estimate, 0.6);
+        assert_eq!(round(result.p, 4), 0.0968);
+    }
+
+    #[test]
+    fn spearman_with_ties_uses_approximation() {
+        let x = vec![1, 2, 2, 3, 4, 5, 5, 6, 7, 8];
+        let y = vec![2, 1, 3, 3, 5, 4, 6, 6, 8, 7];
+        let result = correlation::spearman(x, y, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.test_type, "Spearman's Rank Correlation (Two-Sided)");
+        assert_eq!(round(result.estimate, 4), 0.9325);
+    }
+
+    // Test Cases for `kendall` function
+    #[test]
+    fn kendall_exact() {
+        let (x, y) = hollander_wolfe();
+        let result = correlation::kendall(x, y, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.estimate, 7), 0.4444444);
+        assert_eq!(round(result.p, 4), 0.1194);
+    }
+
+    #[test]
+    fn kendall_tau_b_matches_pairwise_definition() {
+        let x = vec![1, 2, 2, 3, 4, 5, 5, 6, 7, 8];
+        let y = vec![2, 1, 3, 3, 5, 4, 6, 6, 8, 7];
+        let result = correlation::kendall(x.clone(), y.clone(), Tails::BOTH, MissingPolicy::Propagate).unwrap();
+
+        let (mut concordant, mut discordant, mut x_only, mut y_only) = (0.0_f64, 0.0, 0.0, 0.0);
+        for i in 0..x.len() {
+            for j in (i + 1)..x.len() {
+                let product = (x[i] - x[j]) * (y[i] - y[j]);
+                if product > 0 { concordant += 1.0 }
+                else if product < 0 { discordant += 1.0 }
+                else if x[i] == x[j] && y[i] != y[j] { x_only += 1.0 }
+                else if y[i] == y[j] && x[i] != x[j] { y_only += 1.0 };
+            }
+        }
+        let expected = (concordant - discordant)
+            / ((concordant + discordant + x_only) * (concordant + discordant + y_only)).sqrt();
+        assert_eq!(round(result.estimate, 12), round(expected, 12));
+        assert_eq!(result.test_type, "Kendall's Tau-b Rank Correlation (Two-Sided)");
+    }
+
+    // Test Cases for `compare_independent` function
+    #[test]
+    fn compare_independent_two_sided() {
+        let result = correlation::compare_independent(0.5, 100, 0.3, 120, Tails::BOTH).unwrap();
+        assert_eq!(round(result.statistic, 4), 1.7462);
+        assert_eq!(round(result.p, 4), 0.0808);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Correlation coefficients must be between -1 and 1!")]
+    fn compare_independent_invalid_correlation() {
+        correlation::compare_independent(1.5, 100, 0.3, 120, Tails::BOTH);
+    }
+}
+
+#[cfg(test)]
+mod regression_testcases {
+    use super::*;
+    use crate::utils::{Conclusion, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    fn cars() -> (Vec<i32>, Vec<i32>) {
+        (
+            vec![4, 4, 7, 7, 8, 9, 10, 10, 10, 11, 11, 12, 12, 12, 12, 13, 13, 13, 13, 14, 14, 14, 14, 15, 15, 15, 16, 16, 17, 17, 17, 18, 18, 18, 18, 19, 19, 19, 20, 20, 20, 20, 20, 22, 23, 24, 24, 24, 24, 25],
+            vec![2, 10, 4, 22, 16, 10, 18, 26, 34, 17, 28, 14, 20, 24, 28, 26, 34, 34, 46, 26, 36, 60, 80, 20, 26, 54, 32, 40, 32, 40, 50, 42, 56, 76, 84, 36, 46, 68, 32, 48, 52, 56, 64, 66, 54, 70, 92, 93, 120, 85],
+        )
+    }
+
+    #[test]
+    fn simple_cars_coefficients() {
+        let (speed, dist) = cars();
+        let result = regression::simple(speed, dist, 0.95, MissingPolicy::Propagate).unwrap();
+        let intercept = &result.coefficients[0];
+        let slope = &result.coefficients[1];
+        assert_eq!(round(intercept.estimate, 4), -17.5791);
+        assert_eq!(round(intercept.standard_error, 4), 6.7584);
+        assert_eq!(round(intercept.p, 4), 0.0123);
+        assert_eq!(round(slope.estimate, 4), 3.9324);
+        assert_eq!(round(slope.statistic, 3), 9.464);
+        assert_eq!((round(slope.confidence_interval.0, 4), round(slope.confidence_interval.1, 4)), (3.0970, 4.7679));
+    }
+
+    #[test]
+    fn simple_cars_overall_fit() {
+        let (speed, dist) = cars();
+        let result = regression::simple(speed, dist, 0.95, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.r_squared, 4), 0.6511);
+        assert_eq!(round(result.adjusted_r_squared, 4), 0.6438);
+        assert_eq!(round(result.statistic, 2), 89.57);
+        assert_eq!((result.df1, result.df2), (1, 48));
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
+    }
+
+    #[test]
+    fn simple_cars_diagnostics() {
+        let (speed, dist) = cars();
+        let result = regression::simple(speed, dist, 0.95, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.durbin_watson, 4), 1.6762);
+        assert_eq!(round(result.breusch_pagan.statistic, 4), 3.2149);
+        assert_eq!(result.breusch_pagan.df, 1);
+        assert_eq!(round(result.breusch_pagan.p, 5), 0.07297);
+        assert_eq!(round(result.residuals.iter().sum::<f64>(), 8), 0.0);
+    }
+
+    #[test]
+    fn linear_multiple_predictors() {
+        let hours = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let sleep = vec![8.0, 7.0, 7.0, 6.0, 8.0, 6.0, 7.0, 6.0];
+        // score = 10 + 3 * hours + 2 * sleep exactly, apart from a small perturbation
+        let score: Vec<f64> = hours.iter().zip(&sleep).enumerate()
+            .map(|(i, (h, s))| 10.0 + 3.0 * h + 2.0 * s + if i % 2 == 0 { 0.01 } else { -0.01 })
+            .collect();
+        let result = regression::linear(vec![hours, sleep], score, 0.95, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.coefficients.len(), 3);
+        assert_eq!(round(result.coefficients[1].estimate, 1), 3.0);
+        assert_eq!(round(result.coefficients[2].estimate, 1), 2.0);
+        assert_eq!((result.df1, result.df2), (2, 5));
+    }
+
+    #[test]
+    fn linear_empty_data() {
+        assert!(regression::linear(Vec::<Vec<f64>>::new(), vec![1.0, 2.0], 0.95, MissingPolicy::Propagate).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Predictors must not be perfectly collinear!")]
+    fn linear_collinear_predictors() {
+        let x1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let x2 = vec![2.0, 4.0, 6.0, 8.0, 10.0];
+        regression::linear(vec![x1, x2], vec![1.0, 3.0, 2.0, 5.0, 4.0], 0.95, MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Regression requires more observations than coefficients!")]
+    fn linear_too_few_observations() {
+        regression::simple(vec![1.0, 2.0], vec![3.0, 4.0], 0.95, MissingPolicy::Propagate);
+    }
+}
+#[cfg(test)]
+mod binomial_testcases {
+    use super::*;
+    use binomial::Interval;
+    use utils::{Tails, Conclusion};
+    use crate::test_helpers::round;
+
+
+    fn rounded_interval(interval: (f64, f64)) -> (f64, f64) {
+        (round(interval.0, 4), round(interval.1, 4))
+    }
+
+    #[test]
+    fn two_sided_minimum_likelihood() {
+        let result = binomial::test(682, 925, 0.75, Tails::BOTH, Interval::ClopperPearson, 0.95).unwrap();
+        assert_eq!(round(result.p, 4), 0.3825);
+        assert_eq!(rounded_interval(result.confidence_interval), (0.7077, 0.7654));
+        assert_eq!(result.conclude_by_convention(), Conclusion::DoNotReject);
+    }
+
+    #[test]
+    fn exact_and_mid_p_values() {
+        // 9 out of 10 at p = 0.5: P(X >= 9) + P(X <= 1) = 22 / 1024
+        let exact = binomial::test(9, 10, 0.5, Tails::BOTH, Interval::ClopperPearson, 0.95).unwrap();
+        let mid_p = binomial::mid_p_test(9, 10, 0.5, Tails::BOTH, Interval::ClopperPearson, 0.95).unwrap();
+        assert_eq!(round(exact.p, 10), round(22.0 / 1024.0, 10));
+        assert_eq!(round(mid_p.p, 10), round(12.0 / 1024.0, 10));
+
+        let upper = binomial::test(9, 10, 0.5, Tails::UPPER, Interval::ClopperPearson, 0.95).unwrap();
+        let lower = binomial::test(9, 10, 0.5, Tails::LOWER, Interval::ClopperPearson, 0.95).unwrap();
+        assert_eq!(round(upper.p, 10), round(11.0 / 1024.0, 10));
+        assert_eq!(round(lower.p, 10), round(1023.0 / 1024.0, 10));
+        assert_eq!(upper.confidence_interval.1, 1.0);
+        assert_eq!(lower.confidence_interval.0, 0.0);
+    }
+
+    #[test]
+    fn log_p_beyond_underflow() {
+        // P(X = 1000) = 0.01^1000 underflows, but its logarithm does not
+        let upper = binomial::test(1000, 1000, 0.01, Tails::UPPER, Interval::ClopperPearson, 0.95).unwrap();
+        let both = binomial::test(1000, 1000, 0.01, Tails::BOTH, Interval::ClopperPearson, 0.95).unwrap();
+        let mid_p = binomial::mid_p_test(1000, 1000, 0.01, Tails::UPPER, Interval::ClopperPearson, 0.95).unwrap();
+        assert_eq!(upper.p, 0.0);
+        assert_eq!(round(upper.log_p, 6), round(1000.0 * 0.01_f64.ln(), 6));
+        assert_eq!(round(both.log_p, 6), round(upper.log_p, 6));
+        assert_eq!(round(mid_p.log_p - upper.log_p, 10), round(0.5_f64.ln(), 10));
+    }
+
+    #[test]
+    fn newcombe_intervals() {
+        // Newcombe (1998), Table I
+        assert_eq!(rounded_interval(binomial::interval(81, 263, Interval::ClopperPearson, 0.95).unwrap()), (0.2527, 0.3676));
+        assert_eq!(rounded_interval(binomial::interval(81, 263, Interval::Wilson, 0.95).unwrap()), (0.2553, 0.3662));
+        assert_eq!(rounded_interval(binomial::interval(81, 263, Interval::WilsonCorrected, 0.95).unwrap()), (0.2535, 0.3682));
+        assert_eq!(rounded_interval(binomial::interval(1, 29, Interval::WilsonCorrected, 0.95).unwrap()), (0.0018, 0.1963));
+        assert_eq!(rounded_interval(binomial::interval(0, 20, Interval::ClopperPearson, 0.95).unwrap()), (0.0, 0.1684));
+    }
+
+    #[test]
+    fn agresti_coull_and_jeffreys_intervals() {
+        let agresti_coull = binomial::interval(15, 148, Interval::AgrestiCoull, 0.95).unwrap();
+        let jeffreys = binomial::interval(15, 148, Interval::Jeffreys, 0.95).unwrap();
+        let clopper_pearson = binomial::interval(15, 148, Interval::ClopperPearson, 0.95).unwrap();
+        assert!(agresti_coull.0 < 15.0 / 148.0 && 15.0 / 148.0 < agresti_coull.1);
+        // The Jeffreys interval is always contained in the Clopper-Pearson interval
+        assert!(clopper_pearson.0 < jeffreys.0 && jeffreys.1 < clopper_pearson.1);
+        assert_eq!(binomial::interval(0, 20, Interval::Jeffreys, 0.95).unwrap().0, 0.0);
+    }
+
+    #[test]
+    fn binomial_empty_trials() {
+        assert!(binomial::test(0, 0, 0.5, Tails::BOTH, Interval::Wilson, 0.95).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Number of successes cannot exceed the number of trials!")]
+    fn binomial_too_many_successes() {
+        binomial::test(11, 10, 0.5, Tails::BOTH, Interval::Wilson, 0.95);
+    }
+}
+
+#[cfg(test)]
+mod poisson_testcases {
+    use super::*;
+    use poisson::Approximation;
+    use utils::{Tails, Conclusion};
+    use crate::test_helpers::round;
+
+
+    #[test]
+    fn exact_one_sample() {
+        let result = poisson::test(137, 24.19893, 1.0, Tails::BOTH, 0.95).unwrap();
+        assert_eq!(round(result.estimate, 6), 5.661407);
+        assert_eq!((round(result.confidence_interval.0, 6), round(result.confidence_interval.1, 6)), (4.753125, 6.692709));
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
+    }
+
+    #[test]
+    fn exact_zero_count() {
+        // P(X = 0) + P(X >= 4) with mean 2, as 3 is more likely than 0 but 4 is not
+        let result = poisson::test(0, 2.0, 1.0, Tails::BOTH, 0.95).unwrap();
+        assert_eq!(round(result.p, 6), 0.278212);
+        assert_eq!(result.confidence_interval.0, 0.0);
+        assert_eq!(round(result.confidence_interval.1, 4), 1.8444);
+    }
+
+    #[test]
+    fn log_p_beyond_underflow() {
+        // P(X = 0) = e^-1000 underflows with mean 1000, and the other tail adds less than itself
+        let lower = poisson::test(0, 1.0, 1000.0, Tails::LOWER, 0.95).unwrap();
+        let both = poisson::test(0, 1.0, 1000.0, Tails::BOTH, 0.95).unwrap();
+        assert_eq!(lower.p, 0.0);
+        assert_eq!(round(lower.log_p, 10), -1000.0);
+        assert!(both.log_p > lower.log_p && both.log_p < lower.log_p + std::f64::consts::LN_2);
+
+        // P(X >= 2000) / P(X = 2000) = 1 + 1 / 2001 + 1 / (2001 × 2002) + ..., which lies below 2001 / 2000
+        let upper = poisson::test(2000, 1.0, 1.0, Tails::UPPER, 0.95).unwrap();
+        let ratio = upper.log_p - (-1.0 - statrs::function::gamma::ln_gamma(2001.0));
+        assert_eq!(upper.p, 0.0);
+        assert!(ratio > (1.0_f64 + 1.0 / 2001.0).ln() && ratio < (2001.0_f64 / 2000.0).ln());
+    }
+
+    #[test]
+    fn exact_one_sided() {
+        let result = poisson::test(10, 1.0, 6.0, Tails::UPPER, 0.95).unwrap();
+        assert_eq!(round(result.p, 6), 0.083924);
+        assert_eq!(result.confidence_interval.1, f64::INFINITY);
+    }
+
+    #[test]
+    fn exact_rate_ratio() {
+        let result = poisson::compare(11, 800.0, 21, 3011.0, Tails::BOTH, 0.95).unwrap();
+        assert_eq!(round(result.estimate, 6), 1.971488);
+        assert_eq!(round(result.p, 5), 0.07967);
+        assert_eq!((round(result.confidence_interval.0, 6), round(result.confidence_interval.1, 6)), (0.858426, 4.277266));
+        assert!(poisson::compare(0, 800.0, 0, 3011.0, Tails::BOTH, 0.95).is_none());
+    }
+
+    #[test]
+    fn z_tests() {
+        let score = poisson::z_test(30, 10.0, 2.0, Approximation::Score, Tails::UPPER).unwrap();
+        let wald = poisson::z_test(30, 10.0, 2.0, Approximation::Wald, Tails::UPPER).unwrap();
+        assert_eq!(round(score.statistic, 10), round(5.0_f64.sqrt(), 10));
+        assert_eq!(round(wald.statistic, 10), round(10.0 / 30.0_f64.sqrt(), 10));
+
+        let ratio = poisson::compare_z(11, 800.0, 21, 3011.0, Approximation::Wald, Tails::BOTH).unwrap();
+        assert_eq!(round(ratio.statistic, 4), 1.8238);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Exposure must be a positive number!")]
+    fn zero_exposure() {
+        poisson::test(3, 0.0, 1.0, Tails::BOTH, 0.95);
+    }
+}
+
+#[cfg(test)]
+mod equivalence_testcases {
+    use super::*;
+    use equivalence::Hypothesis;
+    use utils::{Tails, Conclusion, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    fn data() -> Vec<f64> {
+        vec![99.8, 100.4, 100.1, 99.6, 100.3, 99.9, 100.2, 100.0, 99.7, 100.1]
+    }
+
+    #[test]
+    fn tost_matches_one_sided_t_tests() {
+        let result = equivalence::t_test(data(), 100.0, Hypothesis::Equivalence(-0.5, 0.5), 0.05, MissingPolicy::Propagate).unwrap();
+        let lower = t::test(data(), 99.5, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        let upper = t::test(data(), 100.5, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+        let result_lower = result.lower.as_ref().unwrap();
+        let result_upper = result.upper.as_ref().unwrap();
+        assert_eq!(round(result_lower.statistic, 10), round(lower.statistic, 10));
+        assert_eq!(round(result_lower.p, 10), round(lower.p, 10));
+        assert_eq!(round(result_upper.statistic, 10), round(upper.statistic, 10));
+        assert_eq!(round(result_upper.p, 10), round(upper.p, 10));
+        assert_eq!(result.p, result_lower.p.max(result_upper.p));
+        assert_eq!(result.conclude_by_convention(), Conclusion::Reject);
+    }
+
+    #[test]
+    fn tost_confidence_interval() {
+        let result = equivalence::t_test(data(), 100.0, Hypothesis::Equivalence(-0.15, 0.15), 0.05, MissingPolicy::Propagate).unwrap();
+        // The 90% interval (-0.1408, 0.1608) is not within the margins, so equivalence is not shown
+        assert_eq!((round(result.confidence_interval.0, 4), round(result.confidence_interval.1, 4)), (-0.1408, 0.1608));
+        assert_eq!(result.conclude(0.05), Conclusion::DoNotReject);
+    }
+
+    #[test]
+    fn non_inferiority_and_superiority() {
+        let non_inferiority = equivalence::t_test(data(), 100.0, Hypothesis::NonInferiority(0.5), 0.05, MissingPolicy::Propagate).unwrap();
+        let superiority = equivalence::t_test(data(), 100.0, Hypothesis::Superiority(0.5), 0.05, MissingPolicy::Propagate).unwrap();
+        assert_eq!(non_inferiority.lower.as_ref().unwrap().bound, -0.5);
+        assert_eq!(non_inferiority.upper, None);
+        assert_eq!(non_inferiority.conclude_by_convention(), Conclusion::Reject);
+        assert_eq!(superiority.lower.as_ref().unwrap().bound, 0.5);
+        assert_eq!(superiority.conclude_by_convention(), Conclusion::DoNotReject);
+    }
+
+    #[test]
+    fn two_samples_welch_and_pooled() {
+        let a = vec![5.1, 4.9, 5.3, 5.0, 5.2, 4.8, 5.1, 5.0];
+        let b = vec![5.0, 5.2, 4.9, 5.1, 5.1, 4.9, 5.0, 5.2];
+        let welch = equivalence::t_test_two_samples(a.clone(), b.clone(), Hypothesis::Equivalence(-0.3, 0.3), false, 0.05, MissingPolicy::Propagate).unwrap();
+        let pooled = equivalence::t_test_two_samples(a, b, Hypothesis::Equivalence(-0.3, 0.3), true, 0.05, MissingPolicy::Propagate).unwrap();
+        assert_eq!(pooled.df, Some(14.0));
+        assert_eq!(round(welch.df.unwrap(), 4), 12.9434);
+        assert_eq!(round(welch.standard_error, 10), round(pooled.standard_error, 10));
+        assert!(pooled.p < welch.p);
+    }
+
+    #[test]
+    fn proportions_non_inferiority() {
+        let result = equivalence::proportions(430, 500, 420, 500, Hypothesis::NonInferiority(0.05), 0.025).unwrap();
+        assert_eq!(round(result.estimate, 10), 0.02);
+        assert_eq!(round(result.lower.unwrap().statistic, 4), 3.1009);
+        assert_eq!(round(result.confidence_interval.0, 4), -0.0242);
+        assert_eq!(result.df, None);
+        assert!(equivalence::proportions(0, 0, 1, 2, Hypothesis::NonInferiority(0.05), 0.025).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The lower equivalence margin must be less than the upper margin!")]
+    fn reversed_margins() {
+        equivalence::t_test(data(), 100.0, Hypothesis::Equivalence(0.5, -0.5), 0.05, MissingPolicy::Propagate);
+    }
+}
+
+#[cfg(test)]
+mod permutation_testcases {
+    use super::*;
+    use utils::{mean, Alternative, Tails, MissingPolicy, Resampling};
+
+    fn difference(samples: &[&[f64]]) -> f64 {
+        mean(samples[1]).unwrap() - mean(samples[0]).unwrap()
+    }
+
+    #[test]
+    fn exact_two_samples() {
+        let samples = [vec![1, 2], vec![3, 4]];
+        let upper = permutation::test(&samples, difference, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        let lower = permutation::test(&samples, difference, Tails::LOWER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        let both = permutation::test(&samples, difference, Tails::BOTH, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        assert_eq!((upper.statistic, upper.permutations, upper.exact, upper.standard_error), (2.0, 6, true, 0.0));
+        assert_eq!(upper.p, 1.0 / 6.0);
+        assert_eq!(lower.p, 1.0);
+        assert_eq!(both.p, 2.0 / 6.0);
+        assert_eq!(upper.hypotheses, "H₀: the samples are exchangeable vs H₁: T is greater than under H₀");
+    }
+
+    #[test]
+    fn three_samples() {
+        let samples = [vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]];
+        let extremes = |samples: &[&[f64]]| mean(samples[2]).unwrap() - mean(samples[0]).unwrap();
+        let result = permutation::test(&samples, extremes, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        // 6! / (2! 2! 2!) relabellings, of which only the observed one gives a difference of 4
+        assert_eq!(result.permutations, 90);
+        assert_eq!(result.p, 1.0 / 90.0);
+    }
+
+    #[test]
+    fn monte_carlo_agrees_with_exact() {
+        let samples = [vec![10.2, 9.7, 11.1, 10.4, 9.9, 10.8, 10.1, 9.5], vec![10.9, 11.3, 10.0, 11.6, 10.7, 11.2, 10.3, 11.0]];
+        let monte_carlo = permutation::test(&samples, difference, Tails::BOTH, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        let exact = permutation::test(&samples, difference, Tails::BOTH, Resampling { exact_limit: 20000, ..Resampling::default() }, MissingPolicy::Propagate).unwrap();
+        assert_eq!(monte_carlo.test_type, "Permutation Test (Monte Carlo)");
+        assert_eq!((exact.test_type, exact.permutations), ("Permutation Test (Exact)", 12870));
+        assert!((monte_carlo.p - exact.p).abs() < 4.0 * monte_carlo.standard_error, "{} vs {}", monte_carlo.p, exact.p);
+    }
+
+    #[test]
+    fn seeded() {
+        let samples = [vec![3.1, 2.8, 3.6, 3.3, 2.9, 3.4, 3.0, 3.7, 3.2], vec![3.5, 3.9, 3.3, 4.1, 3.6, 3.8, 3.4, 4.0]];
+        let run = |seed: u64| permutation::test(&samples, difference, Tails::UPPER, Resampling { resamples: 3000, ..Resampling::seeded(seed) }, MissingPolicy::Propagate).unwrap();
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1).p, run(2).p);
+        assert_eq!(run(1).permutations, 3000);
+        assert_eq!(run(1).p, (run(1).p * 3001.0).round() / 3001.0);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_sequential() {
+        let samples = [vec![3.1, 2.8, 3.6, 3.3, 2.9, 3.4, 3.0, 3.7, 3.2], vec![3.5, 3.9, 3.3, 4.1, 3.6, 3.8, 3.4, 4.0]];
+        let sequential = permutation::test(&samples, difference, Tails::BOTH, Resampling::seeded(5), MissingPolicy::Propagate).unwrap();
+        let parallel = permutation::test(&samples, difference, Tails::BOTH, Resampling { parallel: true, ..Resampling::seeded(5) }, MissingPolicy::Propagate).unwrap();
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn stratified() {
+        let samples = [vec![1.0, 100.0], vec![2.0, 101.0]];
+        let strata = [[0, 1], [0, 1]];
+        let stratified = permutation::test_stratified(&samples, &strata, difference, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        // Swapping within each stratum only ever changes the difference by 1
+        assert_eq!((stratified.permutations, stratified.p), (4, 0.25));
+        let pooled = permutation::test(&samples, difference, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        assert_eq!((pooled.permutations, pooled.p), (6, 2.0 / 6.0));
+    }
+
+    #[test]
+    fn stratified_omits_strata_with_missing_observations() {
+        let samples = [vec![Some(1.0), None, Some(100.0)], vec![Some(2.0), Some(101.0)]];
+        let strata: [&[usize]; 2] = [&[0, 0, 1], &[0, 1]];
+        let result = permutation::test_stratified(&samples, &strata, difference, Tails::UPPER, Resampling::default(), MissingPolicy::Omit).unwrap();
+        assert_eq!((result.permutations, result.p, result.omitted), (4, 0.25, 1));
+    }
+
+    #[test]
+    fn paired_keeps_pairs_in_order() {
+        // Only swapping the first pair changes the difference of the first observations, so half of the 8 relabellings reach 10
+        let first_difference = |first: &[f64], second: &[f64]| first[0] - second[0];
+        let result = permutation::test_paired(vec![10.0, 1.0, 1.0], vec![0.0, 1.0, 1.0], first_difference, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        assert_eq!((result.statistic, result.permutations, result.p), (10.0, 8, 0.5));
+        assert_eq!(result.hypotheses, "H₀: the observations of each pair are exchangeable vs H₁: T is greater than under H₀");
+    }
+
+    #[test]
+    fn sign_flip_about_null_value() {
+        let statistic = |data: &[f64]| mean(data).unwrap();
+        let about_zero = permutation::test_sign_flip(vec![1, 2, 3], statistic, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        assert_eq!((about_zero.permutations, about_zero.p), (8, 1.0 / 8.0));
+        // Reflecting 1, 2, 3 about 2 gives means of 2 + (±1 ± 1) / 3, of which 6 of the 8 are at least 2
+        let about_two = permutation::test_sign_flip(vec![1, 2, 3], statistic, Alternative::greater(2.0), Resampling::default(), MissingPolicy::Propagate).unwrap();
+        assert_eq!(about_two.p, 0.75);
+        assert_eq!(about_two.hypotheses, "H₀: the data is symmetric about 2.0 vs H₁: T is greater than under H₀");
+    }
+
+    #[test]
+    fn nan_statistic() {
+        let result = permutation::test(&[vec![1.0, f64::NAN], vec![2.0, 3.0]], difference, Tails::BOTH, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        assert!(result.statistic.is_nan() && result.p.is_nan());
+        assert!(permutation::test(&[vec![f64::NAN], vec![2.0]], difference, Tails::BOTH, Resampling::default(), MissingPolicy::Omit).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Permutation tests require at least 2 samples!")]
+    fn one_sample() {
+        permutation::test(&[vec![1.0, 2.0]], |samples: &[&[f64]]| samples[0][0], Tails::BOTH, Resampling::default(), MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] A permutation test does not take a null value!")]
+    fn null_value() {
+        permutation::test(&[vec![1.0], vec![2.0]], difference, Alternative::greater(1.0), Resampling::default(), MissingPolicy::Propagate);
+    }
+
+    #[test]
+    fn report() {
+        use report::{Format, Reportable};
+        let exact = permutation::test(&[vec![1, 2], vec![3, 4]], difference, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+        assert_eq!(exact.report().render(Format::Apa), "Test statistic = 2.00, p = .167, 6 permutations");
+        assert!(exact.report().rows.iter().any(|(quantity, value)| quantity == "Test statistic" && value == "2.00"));
+        let monte_carlo = permutation::test(&[vec![1, 2, 3, 4, 5, 6, 7], vec![8, 9, 10, 11, 12, 13, 14]], difference, Tails::UPPER, Resampling { exact_limit: 100, resamples: 999, ..Resampling::default() }, MissingPolicy::Propagate).unwrap();
+        assert!(monte_carlo.report().rows.iter().any(|(quantity, _)| quantity == "Monte Carlo standard error"));
+        assert_eq!(monte_carlo.p, 0.001);
+    }
+}
+
+#[cfg(test)]
+mod bootstrap_testcases {
+    use super::*;
+    use bootstrap::Interval;
+    use utils::{mean, median, Alternative, Tails, MissingPolicy, Resampling};
+
+    const SKEWED: [f64; 15] = [1.2, 0.9, 1.4, 3.8, 1.1, 1.0, 1.6, 1.3, 0.8, 2.9, 1.2, 1.5, 1.1, 4.2, 1.0];
+
+    fn median_of(samples: &[&[f64]]) -> f64 {
+        median(samples[0]).unwrap()
+    }
+
+    fn mean_of(samples: &[&[f64]]) -> f64 {
+        mean(samples[0]).unwrap()
+    }
+
+    #[test]
+    fn seeded() {
+        let run = |seed: u64| bootstrap::interval(&[SKEWED], median_of, Interval::Percentile, 0.95, Resampling { resamples: 2000, ..Resampling::seeded(seed) }, MissingPolicy::Propagate).unwrap();
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1).bias, run(2).bias);
+        assert_eq!((run(1).resamples, run(1).estimate), (2000, 1.2));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_sequential() {
+        let sequential = bootstrap::interval(&[SKEWED], median_of, Interval::BCa, 0.9, Resampling::seeded(5), MissingPolicy::Propagate).unwrap();
+        let parallel = bootstrap::interval(&[SKEWED], median_of, Interval::BCa, 0.9, Resampling { parallel: true, ..Resampling::seeded(5) }, MissingPolicy::Propagate).unwrap();
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn basic_reflects_percentile() {
+        let percentile = bootstrap::interval(&[SKEWED], mean_of, Interval::Percentile, 0.9, Resampling::seeded(3), MissingPolicy::Propagate).unwrap();
+        let basic = bootstrap::interval(&[SKEWED], mean_of, Interval::Basic, 0.9, Resampling::seeded(3), MissingPolicy::Propagate).unwrap();
+        let estimate = percentile.estimate;
+        assert_eq!(basic.confidence_interval, (2.0 * estimate - percentile.confidence_interval.1, 2.0 * estimate - percentile.confidence_interval.0));
+        assert_eq!((basic.bias, basic.standard_error), (percentile.bias, percentile.standard_error));
+        // The mean of a right-skewed sample has a longer upper tail, which the basic interval moves below the estimate
+        assert!(percentile.confidence_interval.1 - estimate > estimate - percentile.confidence_interval.0);
+        assert!(basic.confidence_interval.1 - estimate < estimate - basic.confidence_interval.0);
+    }
+
+    #[test]
+    fn intervals_of_the_mean() {
+        let data = [4.9, 5.1, 5.6, 4.4, 5.0, 5.3, 4.7, 5.2, 4.8, 5.5, 5.0, 4.6, 5.4, 4.9, 5.1, 5.2, 4.8, 5.0, 5.3, 4.7];
+        let t_width = 2.0 * 2.093 * utils::variance(&data).unwrap().sqrt() / 20_f64.sqrt();
+        for method in [Interval::Percentile, Interval::Basic, Interval::Studentized(50), Interval::BCa] {
+            let result = bootstrap::interval(&[data], mean_of, method, 0.95, Resampling { resamples: 999, ..Resampling::seeded(11) }, MissingPolicy::Propagate).unwrap();
+            let (lower, upper) = result.confidence_interval;
+            assert!(lower < result.estimate && result.estimate < upper, "{:?}", method);
+            assert!((upper - lower) / t_width > 0.8 && (upper - lower) / t_width < 1.25, "{:?}", method);
+            assert!(result.bias.abs() < 0.01 && result.standard_error > 0.0);
+            assert_eq!(result.skipped, 0);
+        }
+    }
+
+    #[test]
+    fn studentized_skips_resamples_without_standard_error() {
+        // Most resamples are mostly ones, so resampling them again rarely moves the median
+        let data = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 3.0];
+        let result = bootstrap::interval(&[data], median_of, Interval::Studentized(20), 0.9, Resampling { resamples: 500, ..Resampling::seeded(6) }, MissingPolicy::Propagate).unwrap();
+        assert!(result.skipped > 0 && result.skipped < 500);
+        assert!(result.confidence_interval.0.is_finite() && result.confidence_interval.1.is_finite());
+        let percentile = bootstrap::interval(&[data], median_of, Interval::Percentile, 0.9, Resampling { resamples: 500, ..Resampling::seeded(6) }, MissingPolicy::Propagate).unwrap();
+        assert_eq!(percentile.skipped, 0);
+    }
+
+    #[test]
+    fn ratio_of_means() {
+        let revenue = [12.0, 0.0, 30.0, 5.0, 0.0, 0.0, 18.0, 22.0, 9.0, 0.0, 14.0, 7.0];
+        let sessions = [3.0, 1.0, 4.0, 2.0, 1.0, 2.0, 3.0, 5.0, 2.0, 1.0, 3.0, 2.0];
+        let ratio = |samples: &[&[f64]]| mean(samples[0]).unwrap() / mean(samples[1]).unwrap();
+        let result = bootstrap::interval(&[revenue, sessions], ratio, Interval::BCa, 0.95, Resampling::seeded(2), MissingPolicy::Propagate).unwrap();
+        assert_eq!((result.test_type, result.estimate), ("Bootstrap BCa Interval", 117.0 / 29.0));
+        assert!(result.confidence_interval.0 < result.estimate && result.estimate < result.confidence_interval.1);
+    }
+
+    #[test]
+    fn unit_blocks_and_clusters_resample_like_observations() {
+        let resampling = Resampling { resamples: 500, ..Resampling::seeded(9) };
+        let independent = bootstrap::interval(&[SKEWED], median_of, Interval::Percentile, 0.9, resampling, MissingPolicy::Propagate).unwrap();
+        let blocks = bootstrap::interval_blocks(SKEWED, 1, |series: &[f64]| median(series).unwrap(), Interval::Percentile, 0.9, resampling, MissingPolicy::Propagate).unwrap();
+        let labels: Vec<usize> = (0..SKEWED.len()).collect();
+        let clusters = bootstrap::interval_clusters(&[SKEWED], &[&labels], median_of, Interval::Percentile, 0.9, resampling, MissingPolicy::Propagate).unwrap();
+        assert_eq!(blocks.test_type, "Moving Block Bootstrap Percentile Interval");
+        assert_eq!(clusters.test_type, "Cluster Bootstrap Percentile Interval");
+        for result in [blocks, clusters] {
+            assert_eq!((result.confidence_interval, result.bias), (independent.confidence_interval, independent.bias));
+        }
+    }
+
+    #[test]
+    fn blocks_widen_intervals_of_autocorrelated_series() {
+        // A slowly drifting series, whose observations are strongly correlated with their neighbours
+        let series: Vec<f64> = (0..60).map(|day| (day as f64 / 6.0).sin() * 3.0 + (day % 3) as f64 * 0.2).collect();
+        let resampling = Resampling { resamples: 999, ..Resampling::seeded(4) };
+        let statistic = |series: &[f64]| mean(series).unwrap();
+        let independent = bootstrap::interval_blocks(&series, 1, statistic, Interval::Percentile, 0.95, resampling, MissingPolicy::Propagate).unwrap();
+        let blocks = bootstrap::interval_blocks(&series, 10, statistic, Interval::BCa, 0.95, resampling, MissingPolicy::Propagate).unwrap();
+        assert!(blocks.standard_error > 1.5 * independent.standard_error);
+    }
+
+    #[test]
+    fn clusters_keep_observations_together() {
+        // Each user has two nearly identical observations, so resampling users doubles the weight of each draw
+        let data = [1.0, 1.1, 5.0, 5.1, 2.0, 2.1, 8.0, 8.1, 3.0, 3.1, 6.0, 6.1];
+        let users = [0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5];
+        let resampling = Resampling { resamples: 999, ..Resampling::seeded(6) };
+        let independent = bootstrap::interval(&[data], mean_of, Interval::Percentile, 0.95, resampling, MissingPolicy::Propagate).unwrap();
+        let clustered = bootstrap::interval_clusters(&[data], &[users], mean_of, Interval::BCa, 0.95, resampling, MissingPolicy::Propagate).unwrap();
+        assert!(clustered.standard_error > 1.2 * independent.standard_error);
+    }
+
+    #[test]
+    fn clusters_omit_missing_observations_with_their_labels() {
+        let data = [Some(1.0), None, Some(3.0), Some(4.0)];
+        let result = bootstrap::interval_clusters(&[data], &[[0, 1, 1, 2]], mean_of, Interval::Percentile, 0.95, Resampling::default(), MissingPolicy::Omit).unwrap();
+        assert_eq!((result.estimate, result.omitted), (8.0 / 3.0, 1));
+        assert!(bootstrap::interval_clusters(&[[None::<f64>]], &[[0]], mean_of, Interval::Percentile, 0.95, Resampling::default(), MissingPolicy::Omit).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The block length must be at least 1 and less than the length of the series!")]
+    fn block_as_long_as_series() {
+        bootstrap::interval_blocks([1.0, 2.0, 3.0], 3, |series: &[f64]| series[0], Interval::Percentile, 0.95, Resampling::default(), MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The cluster bootstrap requires at least 2 clusters!")]
+    fn one_cluster() {
+        bootstrap::interval_clusters(&[[1.0, 2.0]], &[[7, 7]], mean_of, Interval::Percentile, 0.95, Resampling::default(), MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Each sample must contain at least 2 observations!")]
+    fn one_observation() {
+        bootstrap::interval(&[vec![1.0, 2.0], vec![3.0]], mean_of, Interval::Percentile, 0.95, Resampling::default(), MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Confidence level must be in the interval [0, 1)!")]
+    fn confidence_level() {
+        bootstrap::interval(&[SKEWED], mean_of, Interval::Percentile, 1.0, Resampling::default(), MissingPolicy::Propagate);
+    }
+
+    #[test]
+    fn t_test_agrees_with_t_test_for_normal_data() {
+        let data = [2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2, 2.4, 2.6, 2.5, 2.7, 2.9, 3.0, 2.8, 2.6];
+        let bootstrap = bootstrap::t_test(data, 2.95, Tails::BOTH, Resampling::seeded(8), MissingPolicy::Propagate).unwrap();
+        let parametric = t::test(data, 2.95, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!((bootstrap.statistic, bootstrap.hypotheses.as_str()), (parametric.statistic, parametric.hypotheses.as_str()));
+        assert!((bootstrap.p - parametric.p).abs() < 0.02, "{} {}", bootstrap.p, parametric.p);
+        assert!(bootstrap.standard_error > 0.0 && bootstrap.standard_error < 0.005);
+    }
+
+    #[test]
+    fn t_test_two_samples_null_difference() {
+        let group1 = [20.0, 22.0, 19.0, 20.0, 21.0, 20.0, 19.0, 21.0, 22.0, 18.0];
+        let group2 = [21.0, 22.0, 20.0, 23.0, 21.0, 22.0, 20.0, 23.0, 22.0, 21.0];
+        let shifted: Vec<f64> = group2.iter().map(|x| x - 2.0).collect();
+        let against_zero = bootstrap::t_test_two_samples(group1, group2, Tails::LOWER, Resampling::seeded(1), MissingPolicy::Propagate).unwrap();
+        let against_two = bootstrap::t_test_two_samples(group1, shifted, Alternative::less(2.0), Resampling::seeded(1), MissingPolicy::Propagate).unwrap();
+        assert!((against_zero.statistic - against_two.statistic).abs() < 1e-12);
+        assert_eq!(against_zero.p, against_two.p);
+        assert_eq!(against_two.hypotheses, "H₀: μ₁ - μ₂ = 2.0 vs H₁: μ₁ - μ₂ < 2.0");
+        assert!(against_zero.p < 0.05);
+    }
+
+    #[test]
+    fn t_test_two_samples_with_constant_resamples() {
+        // Small binary samples often resample to two constant groups, whose statistic is infinite or undefined
+        let group1 = [0.0, 0.0, 0.0, 1.0, 0.0, 1.0];
+        let group2 = [1.0, 1.0, 0.0, 1.0, 1.0, 1.0];
+        let result = bootstrap::t_test_two_samples(group1, group2, Tails::BOTH, Resampling::seeded(1), MissingPolicy::Propagate).unwrap();
+        let welch = t::test_two_samples(group1, group2, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.statistic, welch.statistic);
+        assert!(result.p > 0.0 && result.p < 1.0);
+    }
+
+    #[test]
+    fn t_test_omits_missing_observations() {
+        let result = bootstrap::t_test([Some(1.0), None, Some(2.0), Some(4.0)], 0, Tails::UPPER, Resampling::default(), MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 1);
+        assert!(bootstrap::t_test([None::<f64>], 0, Tails::UPPER, Resampling::default(), MissingPolicy::Omit).is_none());
+    }
+
+    #[test]
+    fn report() {
+        use report::{Format, Reportable};
+        let interval = bootstrap::interval(&[[1.0, 2.0, 3.0, 4.0]], mean_of, Interval::Percentile, 0.0, Resampling { resamples: 1, ..Resampling::default() }, MissingPolicy::Propagate).unwrap();
+        let (lower, upper) = interval.confidence_interval;
+        assert_eq!(lower, upper);
+        assert_eq!(interval.report().render(Format::Apa), format!("estimate = 2.50, 0% CI [{:.2}, {:.2}]", lower, upper));
+        let test = bootstrap::t_test([1.0, 2.0, 3.0, 4.0], 0, Tails::UPPER, Resampling { resamples: 99, ..Resampling::default() }, MissingPolicy::Propagate).unwrap();
+        assert_eq!(test.report().render(Format::Apa), format!("t = {:.2}, {}, 99 resamples", test.statistic, report::apa_p(test.p)));
+    }
+}
+
+#[cfg(test)]
+mod bayes_testcases {
+    use super::*;
+    use bayes::NormalGamma;
+    use statrs::distribution::{ContinuousCDF, StudentsT};
+    use statrs::function::beta::ln_beta;
+    use utils::{mean, variance, Tails, MissingPolicy};
+
+    const SLEEP1: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    const SLEEP2: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+    /// The exact probability that B beats A when B's posterior has a whole-number alpha (Evan Miller, 2015).
+    fn exact_probability_b_better(alpha_a: f64, beta_a: f64, alpha_b: f64, beta_b: f64) -> f64 {
+        (0..alpha_b as usize).map(|i| {
+            let i = i as f64;
+            (ln_beta(alpha_a + i, beta_a + beta_b) - (beta_b + i).ln() - ln_beta(1.0 + i, beta_b) - ln_beta(alpha_a, beta_a)).exp()
+        }).sum()
+    }
+
+    #[test]
+    fn paired_bayes_factor() {
+        // ttestBF(sleep$extra[11:20], sleep$extra[1:10], paired = TRUE) in R's BayesFactor package
+        let results = bayes::t_test_paired(SLEEP2, SLEEP1, bayes::MEDIUM, MissingPolicy::Propagate).unwrap();
+        assert!((results.bf10 - 17.25888).abs() < 1e-4);
+        assert_eq!(results.log_bf10, results.bf10.ln());
+        let paired = t::test_paired(SLEEP2, SLEEP1, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!((results.statistic, results.df), (paired.statistic, paired.df));
+    }
+
+    #[test]
+    fn two_samples_use_the_pooled_statistic() {
+        let results = bayes::t_test_two_samples(SLEEP1, SLEEP2, bayes::MEDIUM, MissingPolicy::Propagate).unwrap();
+        let pooled = (variance(&SLEEP1).unwrap() + variance(&SLEEP2).unwrap()) / 2.0;
+        let statistic = (mean(&SLEEP1).unwrap() - mean(&SLEEP2).unwrap()) / (pooled * 0.2).sqrt();
+        assert!((results.statistic - statistic).abs() < 1e-12);
+        assert_eq!(results, bayes::t_test_two_samples_dataless(results.statistic, 10, 10, bayes::MEDIUM).unwrap());
+        let swapped = bayes::t_test_two_samples(SLEEP2, SLEEP1, bayes::MEDIUM, MissingPolicy::Propagate).unwrap();
+        assert!((swapped.bf10 - results.bf10).abs() < 1e-12);
+    }
+
+    #[test]
+    fn evidence_for_the_null() {
+        let small = bayes::t_test_dataless(0.0, 10, bayes::MEDIUM).unwrap();
+        let large = bayes::t_test_dataless(0.0, 1000, bayes::MEDIUM).unwrap();
+        assert!(large.bf10 < small.bf10 && small.bf10 < 1.0);
+        // A wider prior spreads its belief over larger effects, which the data rules out
+        assert!(bayes::t_test_dataless(0.0, 10, bayes::WIDE).unwrap().bf10 < small.bf10);
+        assert!(bayes::t_test_dataless(0.0, 10, bayes::ULTRAWIDE).unwrap().bf10 < bayes::t_test_dataless(0.0, 10, bayes::WIDE).unwrap().bf10);
+    }
+
+    #[test]
+    fn overwhelming_evidence() {
+        let results = bayes::t_test_dataless(200.0, 1000, bayes::MEDIUM).unwrap();
+        assert!(results.bf10.is_infinite());
+        assert!(results.log_bf10.is_finite() && results.log_bf10 > 1000.0);
+    }
+
+    #[test]
+    fn one_sample_omits_missing_observations() {
+        let results = bayes::t_test([Some(2.5), None, Some(2.9), Some(3.1), Some(2.6)], 3, bayes::WIDE, MissingPolicy::Omit).unwrap();
+        assert_eq!((results.df, results.omitted, results.prior_scale), (3.0, 1, 1.0));
+        assert_eq!(results.hypotheses, "H₀: δ = 0 vs H₁: δ ~ Cauchy(0, 1)");
+        assert!(bayes::t_test([None::<f64>], 3, bayes::WIDE, MissingPolicy::Omit).is_none());
+    }
+
+    #[test]
+    fn beta_binomial_matches_exact_probability() {
+        let results = bayes::beta_binomial(12, 40, 18, 42, (1.0, 1.0), 0.95).unwrap();
+        let exact = exact_probability_b_better(13.0, 29.0, 19.0, 25.0);
+        assert!((results.probability_b_better - exact).abs() < 1e-5, "{} {}", results.probability_b_better, exact);
+        assert_eq!((results.estimate_a, results.estimate_b), (13.0 / 42.0, 19.0 / 44.0));
+        assert!((results.expected_loss_a - results.expected_loss_b - (results.estimate_b - results.estimate_a)).abs() < 1e-12);
+        let swapped = bayes::beta_binomial(18, 42, 12, 40, (1.0, 1.0), 0.95).unwrap();
+        assert!((swapped.probability_b_better - (1.0 - exact)).abs() < 1e-5);
+        assert!((swapped.expected_loss_a - results.expected_loss_b).abs() < 1e-6);
+    }
+
+    #[test]
+    fn beta_binomial_with_large_samples() {
+        let results = bayes::beta_binomial(4800, 100000, 5000, 100000, (1.0, 1.0), 0.95).unwrap();
+        let exact = exact_probability_b_better(4801.0, 95201.0, 5001.0, 95001.0);
+        assert!((results.probability_b_better - exact).abs() < 1e-5, "{} {}", results.probability_b_better, exact);
+        assert!(results.expected_loss_b < 1e-5 && results.expected_loss_a > 0.0019);
+    }
+
+    #[test]
+    fn identical_variants() {
+        let results = bayes::beta_binomial(30, 100, 30, 100, (1.0, 1.0), 0.9).unwrap();
+        assert!((results.probability_b_better - 0.5).abs() < 1e-9);
+        assert!((results.expected_loss_a - results.expected_loss_b).abs() < 1e-9);
+        assert_eq!(results.credible_interval_a, results.credible_interval_b);
+        assert!(bayes::beta_binomial(0, 0, 3, 10, (1.0, 1.0), 0.95).is_none());
+    }
+
+    #[test]
+    fn jeffreys_prior_gives_the_jeffreys_interval() {
+        let results = bayes::beta_binomial(81, 263, 90, 250, (0.5, 0.5), 0.95).unwrap();
+        assert_eq!(results.credible_interval_a, binomial::interval(81, 263, binomial::Interval::Jeffreys, 0.95).unwrap());
+    }
+
+    #[test]
+    fn reference_prior_gives_the_t_interval() {
+        let data = [9.8, 10.4, 10.1, 9.6, 10.3, 10.0, 9.9, 10.5];
+        let results = bayes::normal_gamma(data, NormalGamma::reference(), 0.95, MissingPolicy::Propagate).unwrap();
+        let half_width = StudentsT::new(0.0, 1.0, 7.0).unwrap().inverse_cdf(0.975) * (variance(&data).unwrap() / 8.0).sqrt();
+        let data_mean = mean(&data).unwrap();
+        assert!((results.credible_interval.0 - (data_mean - half_width)).abs() < 1e-9);
+        assert!((results.credible_interval.1 - (data_mean + half_width)).abs() < 1e-9);
+        assert_eq!(results.posterior.alpha, 3.5);
+    }
+
+    #[test]
+    fn prior_shrinks_the_mean() {
+        let data = [12.0, 14.0, 13.0, 15.0];
+        let results = bayes::normal_gamma(data, NormalGamma::new(10.0, 4.0, 2.0, 2.0), 0.95, MissingPolicy::Propagate).unwrap();
+        // Four prior observations at 10 and four observations averaging 13.5
+        assert_eq!(results.estimate, 11.75);
+        assert_eq!(results.posterior, NormalGamma { mean: 11.75, kappa: 8.0, alpha: 4.0, beta: 2.0 + 2.5 + 12.25 });
+    }
+
+    #[test]
+    fn normal_gamma_compare() {
+        let a = [31.5, 28.0, 35.2, 30.1, 27.9, 33.4, 29.8, 32.0, 30.7, 28.6];
+        let b = [31.1, 34.4, 29.9, 33.0, 32.2, 30.8, 35.5, 31.9, 33.6, 32.0];
+        let results = bayes::normal_gamma_compare(a, b, NormalGamma::reference(), 0.95, MissingPolicy::Propagate).unwrap();
+        let swapped = bayes::normal_gamma_compare(b, a, NormalGamma::reference(), 0.95, MissingPolicy::Propagate).unwrap();
+        assert!((results.probability_b_better + swapped.probability_b_better - 1.0).abs() < 1e-6);
+        assert!((results.expected_loss_a - results.expected_loss_b - (results.estimate_b - results.estimate_a)).abs() < 1e-9);
+        assert!((results.expected_loss_a - swapped.expected_loss_b).abs() < 1e-4);
+        // Welch's T-test gives a similar one-sided p-value for the same question
+        let welch = t::test_two_samples(a, b, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+        assert!((1.0 - results.probability_b_better - welch.p).abs() < 0.01);
+    }
+
+    #[test]
+    fn one_degree_of_freedom_has_infinite_loss() {
+        let results = bayes::normal_gamma_compare([1.0, 2.0], [1.5, 3.5], NormalGamma::reference(), 0.95, MissingPolicy::Propagate).unwrap();
+        assert!(results.expected_loss_a.is_infinite() && results.expected_loss_b.is_infinite());
+        assert!(results.probability_b_better > 0.5 && results.probability_b_better < 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The prior scale must be greater than 0!")]
+    fn prior_scale() {
+        bayes::t_test_dataless(1.0, 10, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The parameters of a Beta prior must be greater than 0!")]
+    fn beta_prior() {
+        bayes::beta_binomial(1, 2, 1, 2, (0.0, 1.0), 0.95);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] A Normal-Gamma prior requires kappa and beta of at least 0, and alpha of at least -0.5!")]
+    fn normal_gamma_prior() {
+        NormalGamma::new(0.0, -1.0, 1.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The data must vary when the prior has a beta of 0!")]
+    fn constant_data() {
+        bayes::normal_gamma([2.0, 2.0, 2.0], NormalGamma::reference(), 0.95, MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Credible level must be in the interval [0, 1)!")]
+    fn credible_level() {
+        bayes::beta_binomial(1, 2, 1, 2, (1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn report() {
+        use report::{Format, Reportable};
+        let factor = bayes::t_test_paired(SLEEP2, SLEEP1, bayes::MEDIUM, MissingPolicy::Propagate).unwrap();
+        assert_eq!(factor.report().render(Format::Apa), "t(9) = 4.06, BF₁₀ = 17.259");
+        let comparison = bayes::beta_binomial(30, 100, 30, 100, (1.0, 1.0), 0.95).unwrap();
+        assert_eq!(comparison.report().render(Format::Apa), "P(B > A) = .500");
+        let posterior = bayes::normal_gamma([12.0, 14.0, 13.0, 15.0], NormalGamma::new(10.0, 4.0, 2.0, 2.0), 0.95, MissingPolicy::Propagate).unwrap();
+        assert!(posterior.report().render(Format::Apa).starts_with("M = 11.75, 95% CrI ["));
+    }
+}
+
+#[cfg(test)]
+mod alternative_testcases {
+    use super::*;
+    use utils::{Alternative, Direction, Tails, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    #[test]
+    fn statements() {
+        assert_eq!(Alternative::less(4.0).statement("μ"), "H₀: μ = 4.0 vs H₁: μ < 4.0");
+        assert_eq!(Alternative::two_sided(0.5).statement("π"), "H₀: π = 0.5 vs H₁: π ≠ 0.5");
+        assert_eq!(Alternative::equivalence(100.0, -0.5, 0.5).statement("μ"), "H₀: μ ≤ 99.5 or μ ≥ 100.5 vs H₁: 99.5 < μ < 100.5");
+        let from_tails: Alternative = Tails::UPPER.into();
+        assert_eq!(from_tails, Alternative { direction: Direction::Greater, null_value: None, margins: None });
+    }
+
+    #[test]
+    fn tails_use_the_expected_value() {
+        let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+        let with_tails = t::test(data.clone(), 3.0, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+        let with_alternative = t::test(data, 3.0, Alternative::less(3.0), MissingPolicy::Propagate).unwrap();
+        assert_eq!(with_tails, with_alternative);
+        assert_eq!(with_tails.hypotheses, "H₀: μ = 3.0 vs H₁: μ < 3.0");
+    }
+
+    #[test]
+    fn two_samples_with_non_zero_difference() {
+        let group1 = vec![24.0, 26.0, 25.0, 27.0, 26.0, 25.0, 28.0, 26.0];
+        let group2 = vec![22.0, 24.0, 23.0, 24.0, 25.0, 23.0, 24.0, 23.0];
+        let shifted: Vec<f64> = group1.iter().map(|x| x - 1.0).collect();
+        let result = t::test_two_samples(group1, group2.clone(), Alternative::greater(1.0), MissingPolicy::Propagate).unwrap();
+        let expected = t::test_two_samples(shifted, group2, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 10), round(expected.statistic, 10));
+        assert_eq!(round(result.p, 10), round(expected.p, 10));
+        assert_eq!(result.hypotheses, "H₀: μ₁ - μ₂ = 1.0 vs H₁: μ₁ - μ₂ > 1.0");
+    }
+
+    #[test]
+    fn pearson_non_zero_null() {
+        let x = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let y = vec![2.1, 3.9, 6.2, 7.8, 10.1, 12.2, 13.8, 16.1, 18.0, 19.9];
+        let result = correlation::pearson(x, y, Alternative::greater(0.9), 0.95, MissingPolicy::Propagate).unwrap();
+        let expected = (result.estimate.atanh() - 0.9_f64.atanh()) * 7.0_f64.sqrt();
+        assert_eq!(result.test_type, "Pearson's Product-Moment Correlation (Upper-Tailed, Fisher z)");
+        assert_eq!(round(result.statistic, 10), round(expected, 10));
+        assert!(result.p < 0.001);
+    }
+
+    #[test]
+    fn poisson_rate_ratio_null() {
+        // Testing a ratio of 2 is the same as halving the second exposure and testing a ratio of 1
+        let ratio = poisson::compare(11, 800.0, 21, 3011.0, Alternative::two_sided(2.0), 0.95).unwrap();
+        let scaled = poisson::compare(11, 800.0, 21, 1505.5, Tails::BOTH, 0.95).unwrap();
+        assert_eq!(round(ratio.p, 10), round(scaled.p, 10));
+        assert_eq!(ratio.hypotheses, "H₀: λ₁ / λ₂ = 2.0 vs H₁: λ₁ / λ₂ ≠ 2.0");
+    }
+
+    #[test]
+    fn equivalence_accepts_alternative() {
+        let data = vec![99.8, 100.4, 100.1, 99.6, 100.3, 99.9, 100.2, 100.0, 99.7, 100.1];
+        let with_hypothesis = equivalence::t_test(data.clone(), 100.0, equivalence::Hypothesis::Equivalence(-0.5, 0.5), 0.05, MissingPolicy::Propagate).unwrap();
+        let with_alternative = equivalence::t_test(data.clone(), 100.0, Alternative::equivalence(100.0, -0.5, 0.5), 0.05, MissingPolicy::Propagate).unwrap();
+        assert_eq!(with_hypothesis, with_alternative);
+        // Smaller values being better is expressed with `Direction::Less`
+        let lower_is_better = Alternative { direction: Direction::Less, null_value: None, margins: Some((f64::NEG_INFINITY, 0.5)) };
+        let result = equivalence::t_test(data, 100.0, lower_is_better, 0.05, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.test_type, "(1-Sample) Non-Inferiority T-Test");
+        assert_eq!(result.lower, None);
+        assert_eq!(result.upper.unwrap().bound, 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The null value of the alternative (4) does not match the expected value (3)!")]
+    fn mismatched_null_value() {
+        z::test(vec![1, 2, 3, 4, 5], 3.0, Alternative::less(4.0), MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Equivalence margins are only supported by the `equivalence` module!")]
+    fn margins_outside_equivalence() {
+        z::test(vec![1, 2, 3, 4, 5], 3.0, Alternative::equivalence(3.0, -0.5, 0.5), MissingPolicy::Propagate);
+    }
+}
+
+#[cfg(test)]
+mod running_stats_testcases {
+    use super::*;
+    use crate::utils::{RunningStats, Tails, mean, variance, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    #[test]
+    fn matches_two_pass_summaries() {
+        let data = [2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+        let stats: RunningStats = data.iter().copied().collect();
+        assert_eq!(stats.count(), 8);
+        assert_eq!(round(stats.sum(), 12), 22.8);
+        assert_eq!(round(stats.mean().unwrap(), 12), 2.85);
+        assert_eq!(round(stats.variance().unwrap(), 12), 0.06);
+    }
+
+    #[test]
+    fn merged_chunks_match_single_pass() {
+        let data: Vec<f64> = (1..=1000).map(|i| (i as f64 * 0.37).sin() * 50.0 + 200.0).collect();
+        let single: RunningStats = data.iter().copied().collect();
+        let mut merged = RunningStats::new();
+        for chunk in data.chunks(77) {
+            merged.merge(&chunk.iter().copied().collect());
+        }
+        assert_eq!(merged.count(), single.count());
+        assert_eq!(round(merged.mean().unwrap(), 10), round(single.mean().unwrap(), 10));
+        assert_eq!(round(merged.variance().unwrap(), 8), round(single.variance().unwrap(), 8));
+    }
+
+    #[test]
+    fn merging_empty_accumulators() {
+        let mut stats = RunningStats::new();
+        stats.merge(&RunningStats::new());
+        assert_eq!(stats, RunningStats::new());
+        stats.merge(&vec![1, 2, 3].into_iter().collect());
+        assert_eq!(stats.mean(), Some(2.0));
+        stats.merge(&RunningStats::new());
+        assert_eq!(stats.variance(), Some(1.0));
+    }
+
+    #[test]
+    fn avoids_catastrophic_cancellation() {
+        let data = vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        assert_eq!(variance(&data), Some(30.0));
+        assert_eq!(mean(&data), Some(1e9 + 10.0));
+    }
+
+    #[test]
+    fn too_few_observations() {
+        assert_eq!(RunningStats::new().mean(), None);
+        assert_eq!(variance(&[1.0]), None);
+        assert_eq!(RunningStats::from_iter([1.0]).sd(), None);
+    }
+
+    #[test]
+    fn stats_tests_match_data_tests() {
+        let data = vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18];
+        let other = vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24];
+        let stats: RunningStats = data.iter().copied().collect();
+        let other_stats: RunningStats = other.iter().copied().collect();
+        assert_eq!(z::test_stats(&stats, 21, Tails::BOTH), z::test(data.clone(), 21, Tails::BOTH, MissingPolicy::Propagate));
+        assert_eq!(t::test_stats(&stats, 21, Tails::LOWER), t::test(data.clone(), 21, Tails::LOWER, MissingPolicy::Propagate));
+        assert_eq!(t::test_two_samples_stats(&stats, &other_stats, Tails::BOTH), t::test_two_samples(data, other, Tails::BOTH, MissingPolicy::Propagate));
+        assert_eq!(t::test_stats(&RunningStats::new(), 21, Tails::BOTH), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Each sample must contain at least 2 observations!")]
+    fn two_samples_single_observation() {
+        t::test_two_samples(vec![1.0], vec![2.0, 3.0], Tails::BOTH, MissingPolicy::Propagate);
+    }
+}
+
+#[cfg(test)]
+mod input_testcases {
+    use super::*;
+    use crate::utils::{Alternative, Tails, MissingPolicy};
+
+    #[test]
+    fn borrowed_data_matches_owned_data() {
+        let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+        let owned = t::test(data.clone(), 3.0, Tails::LOWER, MissingPolicy::Propagate);
+        assert_eq!(t::test(&data, 3.0, Tails::LOWER, MissingPolicy::Propagate), owned);
+        assert_eq!(t::test(&data[..], 3.0, Tails::LOWER, MissingPolicy::Propagate), owned);
+        assert_eq!(t::test([2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2], 3.0, Tails::LOWER, MissingPolicy::Propagate), owned);
+        // The data is still usable after every test
+        assert_eq!(data.len(), 8);
+    }
+
+    #[test]
+    fn wide_integer_types() {
+        let counts: Vec<i64> = vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18];
+        let as_i32: Vec<i32> = counts.iter().map(|&x| x as i32).collect();
+        let as_u64: Vec<u64> = counts.iter().map(|&x| x as u64).collect();
+        let as_usize: Vec<usize> = counts.iter().map(|&x| x as usize).collect();
+        let expected = z::test(&as_i32, 21, Tails::BOTH, MissingPolicy::Propagate);
+        assert_eq!(z::test(&counts, 21_i64, Tails::BOTH, MissingPolicy::Propagate), expected);
+        assert_eq!(z::test(&as_u64, 21_u64, Tails::BOTH, MissingPolicy::Propagate), expected);
+        assert_eq!(z::test(&as_usize, 21_usize, Tails::BOTH, MissingPolicy::Propagate), expected);
+        assert_eq!(t::test_two_samples(&counts, &counts[..5], Alternative::two_sided(0.5), MissingPolicy::Propagate), t::test_two_samples(&as_i32, &as_i32[..5], Alternative::two_sided(0.5), MissingPolicy::Propagate));
+    }
+
+    #[test]
+    fn dataless_sample_sizes() {
+        let expected = z::test_dataless(5.0, 4.5, 30_u32, 1.0, Tails::UPPER);
+        assert_eq!(z::test_dataless(5.0, 4.5, 30_i64, 1.0, Tails::UPPER), expected);
+        assert_eq!(z::test_dataless(5.0, 4.5, 30_usize, 1.0, Tails::UPPER), expected);
+        // Sample sizes beyond `u32::MAX` are no longer truncated
+        let large = t::test_dataless(1.0001, 1.0, 10_000_000_000_u64, 1.0, Tails::UPPER).unwrap();
+        assert_eq!(large.df, 9_999_999_999.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Sample size must be greater than 0!")]
+    fn negative_sample_size() {
+        t::test_dataless(2.5, 3.0, -5_i64, 0.5, Tails::UPPER);
+    }
+
+    #[test]
+    fn borrowed_groups_and_columns() {
+        let a = vec![10.1, 9.8, 10.4, 10.0, 9.7];
+        let b = vec![12.3, 8.1, 11.9, 7.6, 10.2];
+        let owned = vec![a.clone(), b.clone()];
+        assert_eq!(homogeneity::levene([&a[..], &b[..]], MissingPolicy::Propagate), homogeneity::levene(owned.clone(), MissingPolicy::Propagate));
+        assert_eq!(homogeneity::bartlett([&a, &b], MissingPolicy::Propagate), homogeneity::bartlett(&owned, MissingPolicy::Propagate));
+
+        let x = vec![1_i64, 2, 3, 4, 5, 6];
+        let y = vec![2_i64, 4, 7, 7, 10, 13];
+        let simple = regression::simple(&x, &y, 0.95, MissingPolicy::Propagate);
+        assert_eq!(regression::linear([&x], &y, 0.95, MissingPolicy::Propagate), simple);
+        assert_eq!(correlation::kendall(&x, &y, Tails::BOTH, MissingPolicy::Propagate), correlation::kendall(x.clone(), y.clone(), Tails::BOTH, MissingPolicy::Propagate));
+    }
+}
+
+#[cfg(test)]
+mod descriptive_testcases {
+    use crate::utils::*;
+    use crate::test_helpers::round;
+
+
+    #[test]
+    fn hyndman_fan_types() {
+        // Reference values from R's `quantile(1:10, 0.25, type = k)`
+        let data: Vec<i32> = (1..=10).collect();
+        let expected = [
+            (QuantileType::Type1, 3.0),
+            (QuantileType::Type2, 3.0),
+            (QuantileType::Type3, 2.0),
+            (QuantileType::Type4, 2.5),
+            (QuantileType::Type5, 3.0),
+            (QuantileType::Type6, 2.75),
+            (QuantileType::Type7, 3.25),
+            (QuantileType::Type8, 2.916667),
+            (QuantileType::Type9, 2.9375),
+        ];
+        for (method, value) in expected {
+            assert_eq!(round(quantile(&data, 0.25, method).unwrap(), 6), value, "{:?}", method);
+        }
+    }
+
+    #[test]
+    fn discontinuous_types_at_steps() {
+        let data: Vec<i32> = (1..=10).collect();
+        assert_eq!(quantile(&data, 0.5, QuantileType::Type1), Some(5.0));
+        assert_eq!(quantile(&data, 0.5, QuantileType::Type2), Some(5.5));
+        assert_eq!(quantile(&data, 0.5, QuantileType::Type3), Some(5.0));
+    }
+
+    #[test]
+    fn extreme_probabilities() {
+        let data = [4.0, 1.0, 3.0, 2.0];
+        for method in [QuantileType::Type1, QuantileType::Type4, QuantileType::Type6, QuantileType::Type7, QuantileType::Type9] {
+            assert_eq!(quantiles(&data, &[0.0, 1.0], method), Some(vec![1.0, 4.0]), "{:?}", method);
+        }
+        assert_eq!(quantile(&[7], 0.3, QuantileType::Type8), Some(7.0));
+        assert_eq!(quantile(&Vec::<f64>::new(), 0.3, QuantileType::Type8), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Probabilities must be between 0 and 1!")]
+    fn invalid_probability() {
+        quantile(&[1, 2, 3], 1.5, QuantileType::Type7);
+    }
+
+    #[test]
+    fn robust_spread() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(median(&data), Some(4.5));
+        assert_eq!(iqr(&data, QuantileType::Type7), Some(1.5));
+        assert_eq!(mad(&data, false), Some(0.5));
+        assert_eq!(round(mad(&data, true).unwrap(), 4), 0.7413);
+    }
+
+    #[test]
+    fn shape() {
+        // Reference values from e1071::skewness and e1071::kurtosis (types 1 and 2)
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(round(skewness(&data, false).unwrap(), 6), 0.65625);
+        assert_eq!(round(skewness(&data, true).unwrap(), 6), 0.818488);
+        assert_eq!(round(kurtosis(&data, false).unwrap(), 6), -0.21875);
+        assert_eq!(round(kurtosis(&data, true).unwrap(), 6), 0.940625);
+        assert_eq!(skewness(&[3, 3, 3], false), None);
+        assert_eq!(skewness(&[1, 2], true), None);
+        assert_eq!(kurtosis(&[1, 2, 3], true), None);
+    }
+
+    #[test]
+    fn robust_means() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 50.0, 100.0];
+        assert_eq!(trimmed_mean(&data, 0.0), mean(&data));
+        // floor(11 × 0.1) = 1 value is trimmed from, or replaced at, each end
+        assert_eq!(round(trimmed_mean(&data, 0.1).unwrap(), 10), round(94.0 / 9.0, 10));
+        assert_eq!(round(winsorised_mean(&data, 0.1).unwrap(), 10), round(146.0 / 11.0, 10));
+        assert_eq!(round(geometric_mean(&[2, 8]).unwrap(), 10), 4.0);
+        assert_eq!(harmonic_mean(&[1, 4, 4]), Some(2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Proportion to trim must be in the interval [0, 0.5)!")]
+    fn invalid_trim() {
+        trimmed_mean(&[1, 2, 3], 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The geometric mean requires every value to be positive!")]
+    fn geometric_mean_of_non_positive() {
+        geometric_mean(&[1, 0, 3]);
+    }
+
+    #[test]
+    fn describe_summaries() {
+        let data = [2, 4, 4, 4, 5, 5, 7, 9];
+        let summary = describe(&data).unwrap();
+        assert_eq!(summary.count, 8);
+        assert_eq!(summary.variance, variance(&data));
+        assert_eq!((summary.q1, summary.median, summary.q3), (4.0, 4.5, 5.5));
+        assert_eq!(summary.skewness, skewness(&data, true));
+        assert_eq!(summary.kurtosis, kurtosis(&data, true));
+        assert_eq!(summary.geometric_mean, geometric_mean(&data));
+
+        let signed = describe(&[-1.0, 2.0]).unwrap();
+        assert_eq!((signed.geometric_mean, signed.harmonic_mean, signed.skewness), (None, None, None));
+        let single = describe(&[3]).unwrap();
+        assert_eq!((single.mean, single.variance, single.mad), (3.0, None, 0.0));
+        assert_eq!(describe(&Vec::<i64>::new()), None);
+    }
+}
+
+#[cfg(test)]
+mod weighted_testcases {
+    use super::*;
+    use crate::utils::{Tails, Weights, effective_sample_size, weighted_mean, weighted_variance, variance, MissingPolicy};
+    use crate::test_helpers::round;
+
+
+    const DATA: [f64; 8] = [2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+    const WEIGHTS: [f64; 8] = [1.2, 0.8, 1.0, 1.5, 0.6, 1.1, 0.9, 1.3];
+
+    #[test]
+    fn weighted_summaries() {
+        assert_eq!(round(weighted_mean(&DATA, &WEIGHTS).unwrap(), 10), 2.8428571429);
+        assert_eq!(round(weighted_variance(&DATA, &WEIGHTS, Weights::Reliability).unwrap(), 10), 0.0687508175);
+        assert_eq!(round(effective_sample_size(&WEIGHTS).unwrap(), 10), 7.5063829787);
+        assert_eq!(weighted_variance(&[1, 2, 3], &[1, 1, 1], Weights::Frequency), variance(&[1, 2, 3]));
+        assert_eq!(weighted_mean(&[1, 2, 3], &[0, 0, 0]), None);
+        assert_eq!(weighted_variance(&[1, 2], &[0.5, 0.5], Weights::Frequency), None);
+    }
+
+    #[test]
+    fn reliability_weighted_t_test() {
+        let result = t::test_weighted(DATA, WEIGHTS, Weights::Reliability, 3, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(result.statistic, 8), -1.64199213);
+        assert_eq!(round(result.df, 8), 6.50638298);
+        assert_eq!(round(result.p, 8), 0.07391915);
+    }
+
+    #[test]
+    fn reliability_weights_are_scale_free() {
+        let scaled: Vec<f64> = WEIGHTS.iter().map(|w| w * 37.0).collect();
+        let original = t::test_weighted(DATA, WEIGHTS, Weights::Reliability, 3, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        let rescaled = t::test_weighted(DATA, &scaled, Weights::Reliability, 3, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(original.p, 12), round(rescaled.p, 12));
+        assert_eq!(round(original.df, 12), round(rescaled.df, 12));
+    }
+
+    #[test]
+    fn frequency_weights_match_repeated_data() {
+        let repeated = vec![20, 20, 22, 19, 19, 19, 21];
+        let result = t::test_weighted(vec![20, 22, 19, 21], vec![2_u64, 1, 3, 1], Weights::Frequency, 21, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        let expected = t::test(&repeated, 21, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(result.df, 6.0);
+        assert_eq!(round(result.p, 12), round(expected.p, 12));
+
+        let two = t::test_two_samples_weighted(vec![20, 22, 19], vec![2, 1, 3], vec![23, 24], vec![2, 2], Weights::Frequency, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        let two_expected = t::test_two_samples(vec![20, 20, 22, 19, 19, 19], vec![23, 23, 24, 24], Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(two.df, 10), round(two_expected.df, 10));
+        assert_eq!(round(two.p, 12), round(two_expected.p, 12));
+    }
+
+    #[test]
+    fn weighted_z_tests() {
+        let ones = [1.0; 8];
+        let unweighted = z::test(DATA, 3, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        let weighted = z::test_weighted(DATA, ones, Weights::Reliability, 3, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+        assert_eq!(round(weighted.p, 12), round(unweighted.p, 12));
+
+        let two = z::test_two_samples_weighted(DATA, WEIGHTS, DATA, ones, Weights::Reliability, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert_eq!(two.hypotheses, "H₀: μ₁ - μ₂ = 0.0 vs H₁: μ₁ - μ₂ ≠ 0.0");
+        assert!(two.p > 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The lengths of data (8) and weights (2) do not match!")]
+    fn mismatched_weights() {
+        t::test_weighted(DATA, [1, 2], Weights::Frequency, 3, Tails::BOTH, MissingPolicy::Propagate);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Weights must be finite and not negative!")]
+    fn negative_weights() {
+        weighted_mean(&[1, 2], &[1, -1]);
+    }
+}
+
+#[cfg(test)]
+mod missing_testcases {
+    use super::*;
+    use crate::utils::{MissingPolicy, Tails, Weights};
+
+    const COMPLETE: [f64; 8] = [2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+
+    #[test]
+    fn omit_matches_complete_data() {
+        let with_nan = [2.5, 2.9, f64::NAN, 3.1, 2.6, 2.7, 2.8, f64::NAN, 3.0, 3.2];
+        let result = t::test(with_nan, 3.0, Tails::LOWER, MissingPolicy::Omit).unwrap();
+        let expected = t::test(COMPLETE, 3.0, Tails::LOWER, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 2);
+        assert_eq!(expected.omitted, 0);
+        assert_eq!((result.statistic, result.p), (expected.statistic, expected.p));
+    }
+
+    #[test]
+    fn option_inputs() {
+        let data = vec![Some(2.5), None, Some(2.9), Some(3.1), Some(2.6), Some(2.7), Some(2.8), Some(3.0), None, Some(3.2)];
+        let result = z::test(&data, 3.0, Tails::BOTH, MissingPolicy::Omit).unwrap();
+        let expected = z::test(COMPLETE, 3.0, Tails::BOTH, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 2);
+        assert_eq!(result.p, expected.p);
+
+        let integers = vec![Some(20), Some(22), None, Some(19), Some(21), Some(20), Some(19), Some(21), Some(22), Some(18)];
+        let normality = normality::shapiro_wilk(&integers, MissingPolicy::Omit).unwrap();
+        assert_eq!(normality.omitted, 1);
+        assert_eq!(normality.p, normality::shapiro_wilk(vec![20, 22, 19, 21, 20, 19, 21, 22, 18], MissingPolicy::Omit).unwrap().p);
+    }
+
+    #[test]
+    fn propagate_gives_nan() {
+        let result = t::test([2.5, f64::NAN, 3.1, 2.6], 3.0, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert!(result.statistic.is_nan() && result.p.is_nan());
+        assert_eq!(result.omitted, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] The data contains 2 missing observations!")]
+    fn error_policy_panics() {
+        t::test([Some(2.5), None, Some(3.1), None, Some(2.6)], 3.0, Tails::BOTH, MissingPolicy::Error);
+    }
+
+    #[test]
+    fn error_policy_accepts_complete_data() {
+        let result = t::test([Some(2.5), Some(2.9), Some(3.1)], 3.0, Tails::BOTH, MissingPolicy::Error).unwrap();
+        assert_eq!(result.omitted, 0);
+    }
+
+    #[test]
+    fn two_samples_count_both_groups() {
+        let result = t::test_two_samples([1.0, f64::NAN, 2.0, 3.0], [4.0, 5.0, f64::NAN, 6.0, f64::NAN], Tails::BOTH, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 3);
+        assert_eq!(result.p, t::test_two_samples([1.0, 2.0, 3.0], [4.0, 5.0, 6.0], Tails::BOTH, MissingPolicy::Omit).unwrap().p);
+    }
+
+    #[test]
+    fn pairwise_omission_in_correlation() {
+        let x = [Some(1.0), Some(2.0), None, Some(4.0), Some(5.0), Some(6.0)];
+        let y = [Some(2.0), Some(1.0), Some(3.0), Some(5.0), None, Some(6.0)];
+        let result = correlation::pearson(x, y, Tails::BOTH, 0.95, MissingPolicy::Omit).unwrap();
+        let expected = correlation::pearson([1.0, 2.0, 4.0, 6.0], [2.0, 1.0, 5.0, 6.0], Tails::BOTH, 0.95, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 2);
+        assert_eq!(result.estimate, expected.estimate);
+        assert_eq!(result.p, expected.p);
+    }
+
+    #[test]
+    fn listwise_omission_in_regression() {
+        let x1 = vec![Some(1.0), Some(2.0), Some(3.0), None, Some(5.0), Some(6.0), Some(7.0)];
+        let x2 = vec![Some(2.0), Some(1.0), Some(4.0), Some(3.0), Some(6.0), None, Some(5.0)];
+        let y = vec![Some(3.0), Some(4.0), Some(8.0), Some(9.0), Some(12.0), Some(13.0), Some(14.0)];
+        let result = regression::linear(vec![x1, x2], y, 0.95, MissingPolicy::Omit).unwrap();
+        let expected = regression::linear(vec![vec![1.0, 2.0, 3.0, 5.0, 7.0], vec![2.0, 1.0, 4.0, 6.0, 5.0]], vec![3.0, 4.0, 8.0, 12.0, 14.0], 0.95, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 2);
+        assert_eq!(result.coefficients, expected.coefficients);
+        assert_eq!(result.df2, 2);
+    }
+
+    #[test]
+    fn weighted_omission_drops_weights() {
+        let result = z::test_weighted([Some(2.5), None, Some(2.9), Some(3.1)], [1.0, 5.0, 2.0, 1.0], Weights::Reliability, 3.0, Tails::BOTH, MissingPolicy::Omit).unwrap();
+        let expected = z::test_weighted([2.5, 2.9, 3.1], [1.0, 2.0, 1.0], Weights::Reliability, 3.0, Tails::BOTH, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 1);
+        assert_eq!(result.p, expected.p);
+    }
+
+    #[test]
+    fn group_omission_in_homogeneity() {
+        let groups = vec![vec![20.0, 22.0, f64::NAN, 19.0, 21.0], vec![15.0, 30.0, 12.0, f64::NAN, 28.0]];
+        let result = homogeneity::levene(&groups, MissingPolicy::Omit).unwrap();
+        assert_eq!(result.omitted, 2);
+        assert_eq!(result.df2, Some(6));
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] All values in the data are identical!")]
+    fn zero_variance_t_test() {
+        t::test([4, 4, 4, 4], 3, Tails::BOTH, MissingPolicy::Omit);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] Both samples have zero variance, so the test statistic is undefined!")]
+    fn zero_variance_two_samples() {
+        t::test_two_samples([1, 1, 1], [2, 2, 2], Tails::BOTH, MissingPolicy::Omit);
+    }
+
+    #[test]
+    #[should_panic(expected = "[HYTE-Panic] All values in the response are identical!")]
+    fn zero_variance_regression() {
+        regression::simple([1, 2, 3, 4], [5, 5, 5, 5], 0.95, MissingPolicy::Omit);
+    }
+}
+
+#[cfg(test)]
+mod report_testcases {
+    use super::*;
+    use crate::report::{Format, Report, Reportable};
+    use crate::utils::{Tails, MissingPolicy};
+
+    fn sample() -> t::TResult {
+        t::test(vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2], 3, Tails::LOWER, MissingPolicy::Propagate).unwrap()
+    }
+
+    #[test]
+    fn apa_t_test() {
+        let results = sample();
+        assert_eq!(results.report().render(Format::Apa), "t(7) = -1.73, p = .063, d = -0.61");
+    }
+
+    #[test]
+    fn cohens_d_one_sample() {
+        let results = t::test_dataless(2.85, 3.0, 8, 0.25, Tails::BOTH).unwrap();
+        assert!((results.cohens_d - -0.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cohens_d_two_samples() {
+        let results = t::test_two_samples(vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0], Tails::BOTH, MissingPolicy::Propagate).unwrap();
+        assert!((results.cohens_d - -2.0 / 2.5_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn text_is_the_default() {
+        let results = sample();
+        let text = results.report().to_string();
+        assert_eq!(text, results.report().render(Format::Text));
+        assert!(text.starts_with(&format!("{}\n{}\n\n", results.test_type, results.hypotheses)));
+        assert!(text.contains("\nT test statistic = -1.73"));
+        assert!(text.contains("\nDegrees of freedom = 7"));
+        assert!(!text.contains("Z test statistic"));
+    }
+
+    #[test]
+    fn markdown_table() {
+        let report = Report::new("Test").row("a | b", 1).with_format(Format::Markdown);
+        assert_eq!(report.to_string(), "**Test**\n\n| Quantity | Value |\n| --- | --- |\n| a \\| b | 1 |");
+    }
+
+    #[test]
+    fn latex_escaping() {
+        let report = Report::new("50% of R_1 & {x}").row("A^2", "$5").with_format(Format::Latex);
+        assert_eq!(report.to_string(), "\\begin{tabular}{lr}\n\\hline\n\\multicolumn{2}{l}{50\\% of R\\_1 \\& \\{x\\}} \\\\\n\\hline\nA\\textasciicircum{}2 & \\$5 \\\\\n\\hline\n\\end{tabular}");
+    }
+
+    #[test]
+    fn apa_p_values() {
+        assert_eq!(report::apa_p(0.0004), "p < .001");
+        assert_eq!(report::apa_p(0.9996), "p > .999");
+        assert_eq!(report::apa_p(0.0271), "p = .027");
+        assert_eq!(report::bounded(-0.456, 2), "-.46");
+        assert_eq!(report::bounded(1.0, 2), "1.00");
+        assert_eq!(report::df(7.0), "7");
+        assert_eq!(report::df(12.345), "12.35");
+    }
+
+    #[test]
+    fn apa_correlation_drops_leading_zero() {
+        let results = correlation::pearson(vec![1, 2, 3, 4, 5, 6], vec![2, 1, 4, 3, 6, 5], Tails::BOTH, 0.95, MissingPolicy::Propagate).unwrap();
+        assert!(results.report().render(Format::Apa).starts_with("r = .83, CI ["));
+    }
+}
+
+#[cfg(test)]
+mod registry_testcases {
+    use crate::registry::{self, Shape, MODULES, TESTS};
+
+    #[test]
+    fn find_and_list() {
+        let test = registry::find("correlation::spearman").unwrap();
+        assert_eq!(test.name(), "correlation::spearman");
+        assert_eq!(test.parameters.iter().map(|parameter| parameter.name).collect::<Vec<_>>(), ["x", "y", "alternative", "missing"]);
+        assert!(registry::find("t::anova").is_none());
+        assert!(registry::find("t").is_none());
+        assert_eq!(registry::tests("homogeneity").count(), 4);
+        assert_eq!(registry::find("chisquare::test").unwrap().parameters[1].shape, Shape::Matrix);
+    }
+
+    #[test]
+    fn every_test_is_complete() {
+        for (index, test) in TESTS.iter().enumerate() {
+            assert!(MODULES.iter().any(|module| module.name == test.module), "{}", test.name());
+            assert!(TESTS[..index].iter().all(|other| other.name() != test.name()), "{} is registered twice", test.name());
+            assert!(!test.summary.is_empty() && !test.summary.ends_with('.'), "{}", test.name());
+            assert!(!test.parameters.is_empty() && !test.assumptions.is_empty() && !test.fields.is_empty(), "{}", test.name());
+            assert_eq!(test.fields[0].name, "test_type", "{}", test.name());
+            assert!(TESTS.iter().filter(|other| other.result == test.result).all(|other| other.fields == test.fields), "{}", test.result);
+        }
+        let grouped: Vec<&str> = MODULES.iter().flat_map(|module| registry::tests(module.name)).map(|test| test.function).collect();
+        assert_eq!(grouped, TESTS.iter().map(|test| test.function).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn manual_describes_every_test() {
+        let manual = registry::manual();
+        for test in TESTS {
+            assert!(manual.contains(&format!("➜  (FN) hyte::{}\n", test.name())));
+        }
+        assert_eq!(manual.matches("returns: Option<ZResult>\n                Fields:").count(), 1);
+        assert!(manual.contains("                1. data1: impl AsRef<[Number]>, where Number: Observation; the first observation of each pair\n"));
+        assert_eq!(registry::find("t::test").unwrap().description(), "1-sample T-test for a mean\n\nAssumptions:\n  - Observations are independent\n  - The data is normally distributed, or the sample is large enough for its mean to be");
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn fields_match_the_results() {
+        use crate::{bayes, binomial, bootstrap, chisquare, correlation, equivalence, homogeneity, ks, multiple, normality, permutation, poisson, regression, t, z};
+        let schemas = [
+            ("ZResult", schemars::schema_for!(z::ZResult)),
+            ("TResult", schemars::schema_for!(t::TResult)),
+            ("ChiSquareResult", schemars::schema_for!(chisquare::ChiSquareResult)),
+            ("NormalityResult", schemars::schema_for!(normality::NormalityResult)),
+            ("KSResult", schemars::schema_for!(ks::KSResult)),
+            ("VarianceResult", schemars::schema_for!(homogeneity::VarianceResult)),
+            ("CorrelationResult", schemars::schema_for!(correlation::CorrelationResult)),
+            ("RegressionResult", schemars::schema_for!(regression::RegressionResult)),
+            ("BinomialResult", schemars::schema_for!(binomial::BinomialResult)),
+            ("PoissonResult", schemars::schema_for!(poisson::PoissonResult)),
+            ("EquivalenceResult", schemars::schema_for!(equivalence::EquivalenceResult)),
+            ("PermutationResult", schemars::schema_for!(permutation::PermutationResult)),
+            ("IntervalResult", schemars::schema_for!(bootstrap::IntervalResult)),
+            ("BootstrapResult", schemars::schema_for!(bootstrap::BootstrapResult)),
+            ("BayesFactorResult", schemars::schema_for!(bayes::BayesFactorResult)),
+            ("ComparisonResult", schemars::schema_for!(bayes::ComparisonResult)),
+            ("PosteriorResult", schemars::schema_for!(bayes::PosteriorResult)),
+            ("MultipleResult", schemars::schema_for!(multiple::MultipleResult)),
+            ("QValueResult", schemars::schema_for!(multiple::QValueResult)),
+        ];
+        for test in TESTS {
+            let (_, schema) = schemas.iter().find(|(name, _)| *name == test.result).unwrap_or_else(|| panic!("{} has no schema", test.result));
+            let mut properties: Vec<&str> = schema.as_value()["properties"].as_object().unwrap().keys().map(String::as_str).collect();
+            let mut fields: Vec<&str> = test.fields.iter().map(|field| field.name).collect();
+            properties.sort_unstable();
+            fields.sort_unstable();
+            assert_eq!(fields, properties, "{}", test.name());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod record_testcases {
+    use super::*;
+    use crate::record::{Record, TestResult};
+    use crate::utils::{Alternative, Conclusion, Tails, MissingPolicy};
+
+    fn sample() -> Record {
+        let data = vec![Some(2.5), Some(2.9), None, Some(3.1), Some(2.6), Some(2.7)];
+        let results = t::test(&data, 3, Tails::LOWER, MissingPolicy::Omit).unwrap();
+        Record::new(results).input("data", &data).parameter("expected_mean", 3).alternative(Tails::LOWER).conclude(0.05)
+    }
+
+    #[test]
+    fn round_trip() {
+        let record = sample();
+        let json = serde_json::to_string(&record).unwrap();
+        let restored: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, record);
+    }
+
+    #[test]
+    fn json_representation() {
+        let json = serde_json::to_value(sample()).unwrap();
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["inputs"]["data"][2], serde_json::Value::Null);
+        assert_eq!(json["parameters"]["expected_mean"], 3.0);
+        assert_eq!(json["alternative"]["direction"], "Less");
+        assert_eq!(json["conclusion"], "Reject");
+        assert_eq!(json["result"]["kind"], "t");
+        assert_eq!(json["result"]["df"], 4.0);
+        assert_eq!(json["result"]["omitted"], 1);
+    }
+
+    #[test]
+    fn one_sided_poisson_round_trip() {
+        let record = Record::new(poisson::test(12, 2.0, 4.0, Tails::UPPER, 0.95).unwrap()).alternative(Tails::UPPER);
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["result"]["confidence_interval"][1], "inf");
+        let restored: Record = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, record);
+    }
+
+    #[test]
+    fn non_inferiority_round_trip() {
+        let hypothesis = equivalence::Hypothesis::NonInferiority(0.5);
+        let results = equivalence::t_test([2.9, 3.1, 3.0, 3.2, 2.8, 3.1, 3.0], 3, hypothesis, 0.05, MissingPolicy::Propagate).unwrap();
+        let record = Record::new(results).alternative(hypothesis);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains(r#""margins":[-0.5,"inf"]"#));
+        let restored: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, record);
+        assert_eq!(restored.alternative.unwrap().margins, Some((-0.5, f64::INFINITY)));
+    }
+
+    #[test]
+    fn nan_statistic_round_trip() {
+        let data = vec![2.5, 2.9, f64::NAN, 3.1];
+        let record = Record::new(t::test(&data, 3, Tails::LOWER, MissingPolicy::Propagate).unwrap()).input("data", &data).conclude(0.05);
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!((&json["result"]["statistic"], &json["result"]["p"]), (&"nan".into(), &"nan".into()));
+        let restored: Record = serde_json::from_value(json.clone()).unwrap();
+        let TestResult::T(result) = &restored.result else { panic!("expected a T-test result") };
+        assert!(result.statistic.is_nan() && result.p.is_nan());
+        assert_eq!(serde_json::to_value(&restored).unwrap(), json);
+    }
+
+    #[test]
+    fn negative_infinite_log_p_round_trip() {
+        // The p-value of the exact binomial test is 0 with probability 0
+        let results = binomial::test(3, 3, 0.0, Tails::UPPER, binomial::Interval::ClopperPearson, 0.95).unwrap();
+        let record = Record::new(results).parameter("expected_probability", 0.0).conclude(0.05);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains(r#""log_p":"-inf""#));
+        let restored: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, record);
+    }
+
+    #[test]
+    fn results_deserialise_without_borrowing() {
+        let json = serde_json::to_vec(&z::test([1, 2, 3, 4, 5], 3.5, Tails::LOWER, MissingPolicy::Propagate).unwrap()).unwrap();
+        let first: z::ZResult = serde_json::from_reader(json.as_slice()).unwrap();
+        let second: z::ZResult = serde_json::from_reader(json.as_slice()).unwrap();
+        assert_eq!(first.test_type, "One-Sided Z-Test for Mean (Lower-Tailed)");
+        assert!(std::ptr::eq(first.test_type, second.test_type));
+    }
+
+    #[test]
+    fn unknown_test_types_are_rejected() {
+        let mut json = serde_json::to_value(z::test([1, 2, 3, 4, 5], 3.5, Tails::LOWER, MissingPolicy::Propagate).unwrap()).unwrap();
+        json["test_type"] = "Made-Up Test".into();
+        let error = serde_json::from_value::<z::ZResult>(json).unwrap_err();
+        assert_eq!(error.to_string(), "unknown test type `Made-Up Test`");
+    }
+
+    #[test]
+    fn other_versions_are_rejected() {
+        let mut json = serde_json::to_value(sample()).unwrap();
+        json["version"] = serde_json::Value::from(2);
+        let error = serde_json::from_value::<Record>(json).unwrap_err();
+        assert!(error.to_string().contains("unsupported record version 2, expected 1"));
+    }
+
+    #[test]
+    fn conclusion_needs_a_single_p_value() {
+        let adjusted = multiple::adjust(&[0.01, 0.04, 0.2], multiple::Correction::Holm, 0.05).unwrap();
+        let record = Record::new(adjusted).conclude(0.05);
+        assert!(matches!(record.result, TestResult::Multiple(_)));
+        assert_eq!(record.conclusion, None);
+        assert_eq!(Record::new(z::test_dataless(3.0, 3.5, 30, 1.0, Alternative::less(3.5)).unwrap()).conclude(0.05).conclusion, Some(Conclusion::Reject));
+    }
+
+    #[test]
+    #[cfg(feature = "schema")]
+    fn published_schema_is_current() {
+        let published: serde_json::Value = serde_json::from_str(include_str!("../schema/record.v1.json")).unwrap();
+        assert_eq!(serde_json::to_value(record::schema()).unwrap(), published, "regenerate with `cargo run --example schema --features schema > schema/record.v1.json`");
+    }
+}
+
+#[cfg(all(test, feature = "cli"))]
+mod cli_testcases {
+    use clap::Parser;
+    use crate::cli::{self, CommandLines, EXIT_DO_NOT_REJECT, EXIT_REJECT};
+    use crate::record::{Record, TestResult};
+    use crate::utils::Conclusion;
+
+    const DATA: &str = "score,before,after\n2.5,72,70\n2.9,75,73\n3.1,68,69\n2.6,80,76\n2.7,77,74\n2.8,74,70\n3.0,,\n3.2,NA,\n";
+
+    fn execute(args: &[&str], stdin: &str) -> Result<cli::Outcome, String> {
+        let command = CommandLines::try_parse_from(std::iter::once("hyte").chain(args.iter().copied())).unwrap();
+        cli::execute(&command.subcommand, stdin.as_bytes())
+    }
+
+    #[test]
+    fn column_by_name_or_index() {
+        let by_name = execute(&["t", "score", "--mean", "3", "--tail", "lower"], DATA).unwrap();
+        let by_index = execute(&["t", "1", "--mean", "3", "--tail", "lower"], DATA).unwrap();
+        assert_eq!(by_name, by_index);
+        assert_eq!(by_name.conclusion, Conclusion::DoNotReject);
+        assert_eq!(by_name.exit_code(), EXIT_DO_NOT_REJECT);
+        assert!(by_name.output.contains("\nT test statistic = -1.73\n"));
+        assert!(by_name.output.ends_with("\nConclusion = DoNotReject at significance level 0.05"));
+    }
+
+    #[test]
+    fn alpha_changes_the_exit_code() {
+        let outcome = execute(&["t", "score", "-m", "3", "-t", "lower", "--alpha", "0.1"], DATA).unwrap();
+        assert_eq!(outcome.exit_code(), EXIT_REJECT);
+    }
+
+    #[test]
+    fn paired_omits_incomplete_rows() {
+        let outcome = execute(&["paired", "before", "after", "-o", "apa"], DATA).unwrap();
+        assert_eq!(outcome.output, "t(5) = 3.07, p = .028, d = 1.25");
+        assert_eq!(outcome.conclusion, Conclusion::Reject);
+    }
+
+    #[test]
+    fn tsv_without_header() {
+        let outcome = execute(&["t2", "1", "2", "-d", "\t", "--no-header", "-o", "apa"], "1\t4\n2\t6\n3\t5\n4\t8\n").unwrap();
+        assert_eq!(outcome.output, "t(5.58) = -3.04, p = .025, d = -2.15");
+    }
+
+    #[test]
+    fn tsv_files_are_tab_delimited() {
+        let path = std::env::temp_dir().join("hyte_cli_testcases.tsv");
+        std::fs::write(&path, DATA.replace(',', "\t")).unwrap();
+        let from_file = execute(&["t", "score", "-m", "3", "-f", path.to_str().unwrap()], "").unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(from_file, execute(&["t", "score", "-m", "3"], DATA).unwrap());
+    }
+
+    #[test]
+    fn json_output_is_a_record() {
+        let outcome = execute(&["z", "score", "-m", "3", "-o", "json"], DATA).unwrap();
+        let record: Record = serde_json::from_str(&outcome.output).unwrap();
+        assert_eq!(record.inputs["score"].len(), 8);
+        assert_eq!(record.parameters["expected_mean"], 3.0);
+        assert_eq!(record.conclusion, Some(outcome.conclusion));
+        assert!(matches!(record.result, TestResult::Z(_)));
+    }
+
+    #[test]
+    fn chisquare_commands() {
+        let table = execute(&["chisq-toi", "-o", "apa"], "a,b,c\n762,327,468\n484,239,477\n").unwrap();
+        assert_eq!(table.output, "χ²(2) = 30.07, p < .001");
+        let uniform = execute(&["chisq-gof", "count", "-o", "apa"], "count\n30\n40\n30\n").unwrap();
+        let given = execute(&["chisq-gof", "count", "-p", "0.25,0.5,0.25", "-o", "apa"], "count\n30\n40\n30\n").unwrap();
+        assert_eq!(uniform.output, "χ²(2) = 2.00, p = .368");
+        assert_eq!(given.output, "χ²(2) = 4.00, p = .135");
+    }
+
+    #[test]
+    fn normality_ks_and_correlation_commands() {
+        let shapiro_wilk = execute(&["normality", "score", "-o", "json"], DATA).unwrap();
+        assert!(matches!(shapiro_wilk.record.result, TestResult::Normality(ref result) if result.test_type == "Shapiro-Wilk Test for Normality"));
+        let lilliefors = execute(&["normality", "score", "--method", "lilliefors"], DATA).unwrap();
+        assert!(matches!(lilliefors.record.result, TestResult::KS(_)));
+
+        let ks = execute(&["ks", "score", "normal", "2.85", "0.25"], DATA).unwrap();
+        assert_eq!((ks.record.parameters["mean"], ks.record.parameters["sd"]), (2.85, 0.25));
+        assert_eq!(ks.conclusion, Conclusion::DoNotReject);
+        assert_eq!(execute(&["ks", "score", "exponential", "1", "2"], DATA).unwrap_err(), "the normal and uniform distributions take 2 parameters, and the exponential distribution 1");
+        assert_eq!(execute(&["ks2", "before", "after", "-o", "json"], DATA).unwrap().record.inputs.len(), 2);
+
+        let pearson = execute(&["correlation", "before", "after", "-t", "upper"], DATA).unwrap();
+        let kendall = execute(&["correlation", "before", "after", "--method", "kendall"], DATA).unwrap();
+        assert_eq!(pearson.record.parameters["confidence_level"], 0.95);
+        assert!(matches!(kendall.record.result, TestResult::Correlation(ref result) if result.test_type.starts_with("Kendall's")));
+    }
+
+    #[test]
+    fn binomial_and_poisson_commands() {
+        let outcomes = "hit,events,hours\n1,3,2\n0,1,1\n1,4,2\n1,0,1\n1,2,1\n1,5,2\n1,,1\n";
+        let binomial = execute(&["binomial", "hit", "-p", "0.5", "-t", "upper"], outcomes).unwrap();
+        assert!(matches!(binomial.record.result, TestResult::Binomial(ref result) if result.estimate == 6.0 / 7.0));
+        assert_eq!(execute(&["binomial", "events", "-p", "0.5"], outcomes).unwrap_err(), "`3` is not 0 or 1");
+
+        // The missing count is omitted together with its exposure
+        let poisson = execute(&["poisson", "events", "-r", "1", "--exposure", "hours"], outcomes).unwrap();
+        assert!(matches!(poisson.record.result, TestResult::Poisson(ref result) if result.estimate == 15.0 / 9.0));
+        assert_eq!(poisson.record.parameters["exposure"], 9.0);
+        assert_eq!(execute(&["poisson", "events", "-r", "1"], outcomes).unwrap().record.parameters["exposure"], 6.0);
+        assert_eq!(execute(&["poisson", "events", "-r", "1", "--missing", "propagate"], outcomes).unwrap_err(), "missing observations cannot be counted; use `--missing omit`");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(execute(&["t", "weight", "-m", "3"], DATA).unwrap_err(), "there is no column named or numbered `weight`");
+        assert_eq!(execute(&["t", "score", "-m", "3"], "score\n2.5\nabc\n").unwrap_err(), "row 3, column `score`: `abc` is not a number");
+        assert_eq!(execute(&["t", "score", "-m", "3"], "score\n1\n1\n1\n").unwrap_err(), "All values in the data are identical!");
+        assert_eq!(execute(&["t", "before", "-m", "70", "--missing", "error"], DATA).unwrap_err(), "The data contains 2 missing observations!");
+        assert_eq!(execute(&["t", "before", "-m", "70", "--missing", "propagate"], DATA).unwrap_err(), "the test has no p-value, as when missing observations are kept under `--missing propagate`");
+    }
+
+    #[test]
+    fn alpha_must_be_a_probability() {
+        for alpha in ["7", "0", "1", "-0.05", "nan"] {
+            let error = CommandLines::try_parse_from(["hyte", "t", "score", "-m", "3", &format!("--alpha={}", alpha)]).unwrap_err();
+            assert!(error.to_string().contains(&format!("`{}` is not a number between 0 and 1", alpha)));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serve"))]
+mod serve_testcases {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use std::thread;
+    use crate::record::{Record, TestResult};
+    use crate::registry::{self, Shape};
+    use crate::bootstrap;
+    use crate::serve::{self, ENDPOINTS, Server};
+    use crate::utils::{Conclusion, MissingPolicy, Resampling, Tails};
+
+    const ONE_SAMPLE: &str = r#"{"data": [2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2], "expected_mean": 3, "alternative": {"direction": "Less"}}"#;
+
+    fn error_code(response: &serve::Response) -> String {
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["error"]["status"], response.status);
+        body["error"]["code"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn record_and_conclusion() {
+        let response = serve::handle("POST", "/v1/t/test", ONE_SAMPLE.as_bytes());
+        assert_eq!(response.status, 200);
+        let record: Record = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(record.conclusion, None);
+        assert!(matches!(record.result, TestResult::T(ref result) if (result.p - 0.06343518346183559).abs() < 1e-12));
+
+        let concluded: Record = serde_json::from_str(&serve::handle("POST", "/v1/t/test?alpha=0.05", ONE_SAMPLE.as_bytes()).body).unwrap();
+        assert_eq!(concluded.significance_level, Some(0.05));
+        assert_eq!(concluded.conclusion, Some(Conclusion::DoNotReject));
+    }
+
+    #[test]
+    fn defaults_and_missing_observations() {
+        let response = serve::handle("POST", "/v1/t/test_paired", br#"{"data1": [72, 75, 68, 80, 77, 74, null], "data2": [70, 73, 69, 76, 74, 70, 71]}"#);
+        let record: Record = serde_json::from_str(&response.body).unwrap();
+        let TestResult::T(result) = record.result else { panic!() };
+        assert_eq!(result.df, 5.0);
+        assert_eq!(result.omitted, 1);
+        assert!((result.p - 0.027793195443138623).abs() < 1e-8);
+    }
+
+    #[test]
+    fn bootstrap_resampling_defaults() {
+        let response = serve::handle("POST", "/v1/bootstrap/t_test", br#"{"data": [2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2], "expected_mean": 3, "resampling": {"resamples": 999}}"#);
+        let record: Record = serde_json::from_str(&response.body).unwrap();
+        let TestResult::Bootstrap(result) = record.result else { panic!() };
+        let direct = bootstrap::t_test([2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2], 3, Tails::BOTH, Resampling { resamples: 999, ..Resampling::default() }, MissingPolicy::Omit).unwrap();
+        assert_eq!(result, direct);
+    }
+
+    #[test]
+    fn health_and_routing() {
+        let health = serve::handle("GET", "/healthz", b"");
+        assert_eq!(health.status, 200);
+        assert!(health.body.contains(r#""status": "ok""#));
+        assert_eq!(error_code(&serve::handle("POST", "/v1/t/nothing", b"{}")), "not_found");
+        assert_eq!(error_code(&serve::handle("GET", "/v1/t/test", b"")), "method_not_allowed");
+        assert_eq!(error_code(&serve::handle("POST", "/v1/t/test?alpha=2", ONE_SAMPLE.as_bytes())), "invalid_query");
+    }
+
+    #[test]
+    fn validation_errors() {
+        assert_eq!(error_code(&serve::handle("POST", "/v1/t/test", b"{\"data\": [1, 2")), "invalid_json");
+        assert_eq!(error_code(&serve::handle("POST", "/v1/t/test", br#"{"data": [1, 2], "mean": 3}"#)), "invalid_json");
+        assert_eq!(error_code(&serve::handle("POST", "/v1/t/test", br#"{"data": [], "expected_mean": 3}"#)), "insufficient_data");
+
+        let identical = serve::handle("POST", "/v1/t/test", br#"{"data": [1, 1, 1], "expected_mean": 3}"#);
+        assert_eq!(identical.status, 422);
+        assert_eq!(error_code(&identical), "invalid_input");
+        assert!(identical.body.contains("All values in the data are identical!"));
+    }
+
+    #[test]
+    fn openapi_describes_every_endpoint() {
+        let document = serve::openapi();
+        assert_eq!(document["openapi"], "3.1.0");
+        assert_eq!(document["paths"].as_object().unwrap().len(), ENDPOINTS.len() + 3);
+        for (path, _) in ENDPOINTS {
+            let schema = &document["paths"][path]["post"]["requestBody"]["content"]["application/json"]["schema"]["$ref"];
+            let name = schema.as_str().unwrap().strip_prefix("#/components/schemas/").unwrap();
+            assert!(document["components"]["schemas"][name].is_object());
+        }
+    }
+
+    #[test]
+    fn requests_match_the_registry() {
+        let document = serve::openapi();
+        for (path, test) in ENDPOINTS {
+            let test = registry::find(test).unwrap();
+            let operation = &document["paths"][path]["post"];
+            assert_eq!(operation["summary"], test.summary);
+            if test.module == "chisquare" { continue };
+            let name = operation["requestBody"]["content"]["application/json"]["schema"]["$ref"].as_str().unwrap().rsplit('/').next().unwrap();
+            let mut properties: Vec<&str> = document["components"]["schemas"][name]["properties"].as_object().unwrap().keys().map(String::as_str).collect();
+            let mut parameters: Vec<&str> = test.parameters.iter().map(|parameter| parameter.name).collect();
+            properties.sort_unstable();
+            parameters.sort_unstable();
+            assert_eq!(properties, parameters, "{}", path);
+        }
+    }
+
+    #[test]
+    fn tests_are_discoverable() {
+        let response = serve::handle("GET", "/v1/tests", b"");
+        assert_eq!(response.status, 200);
+        let tests: Vec<serde_json::Value> = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(tests.len(), registry::TESTS.len());
+        for (test, registered) in tests.iter().zip(registry::TESTS) {
+            let takes_data = registered.parameters.iter().all(|parameter| !matches!(parameter.shape, Shape::Summary | Shape::Results | Shape::Statistic));
+            assert_eq!(test["endpoints"].as_array().unwrap().is_empty(), !takes_data, "{}", registered.name());
+        }
+        let paired = tests.iter().find(|test| test["module"] == "t" && test["function"] == "test_paired").unwrap();
+        assert_eq!(paired["endpoints"], serde_json::json!(["/v1/t/test_paired"]));
+        assert_eq!(paired["parameters"][0]["shape"], "sample");
+        assert_eq!(tests.iter().find(|test| test["module"] == "chisquare").unwrap()["endpoints"].as_array().unwrap().len(), 2);
+    }
+
+    fn send(address: std::net::SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn loopback() {
+        let server = Server::bind("127.0.0.1:0", 256).unwrap();
+        let address = server.local_addr();
+        thread::scope(|scope| {
+            scope.spawn(|| server.run(2));
+
+            let ok = send(address, &format!("POST /v1/t/test HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", ONE_SAMPLE.len(), ONE_SAMPLE));
+            assert!(ok.starts_with("HTTP/1.1 200"));
+            assert!(ok.to_lowercase().contains("content-type: application/json"));
+            assert!(ok.contains(r#""kind": "t""#));
+
+            let body = format!("{{\"data\": [{}], \"expected_mean\": 0}}", vec!["1.5"; 100].join(", "));
+            let too_large = send(address, &format!("POST /v1/t/test HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body));
+            assert!(too_large.starts_with("HTTP/1.1 413"));
+            assert!(too_large.contains(r#""code": "payload_too_large""#));
+
+            server.stop();
+        });
+    }
+
+    #[test]
+    fn loopback_limits() {
+        let limits = serve::Limits { max_trials: 1000, max_resamples: 500, ..serve::Limits::default() };
+        let server = Server::bind("127.0.0.1:0", 4096).unwrap().limits(limits);
+        let address = server.local_addr();
+        let post = |path: &str, body: &str| send(address, &format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", path, body.len(), body));
+        thread::scope(|scope| {
+            scope.spawn(|| server.run(2));
+
+            let trials = post("/v1/binomial/test", r#"{"successes": 4000000000, "trials": 9000000000, "expected_probability": 0.5}"#);
+            assert!(trials.starts_with("HTTP/1.1 422"));
+            assert!(trials.contains(r#""code": "invalid_input""#) && trials.contains("the number of trials must not exceed 1000"));
+            assert!(post("/v1/binomial/test", r#"{"successes": 400, "trials": 1000, "expected_probability": 0.5}"#).starts_with("HTTP/1.1 200"));
+
+            let events = post("/v1/poisson/compare", r#"{"count1": 900, "exposure1": 1, "count2": 900, "exposure2": 1}"#);
+            assert!(events.starts_with("HTTP/1.1 422"));
+            let resamples = post("/v1/bootstrap/t_test", r#"{"data": [2.5, 2.9, 3.1, 2.6], "expected_mean": 3, "resampling": {"resamples": 100000000}}"#);
+            assert!(resamples.starts_with("HTTP/1.1 422") && resamples.contains("resamples must not exceed 500"));
+
+            server.stop();
+        });
+    }
+
+    #[test]
+    fn default_limits() {
+        let limits = serve::Limits::default();
+        let expected_count = serve::handle_within("POST", "/v1/poisson/test", br#"{"count": 12, "exposure": 1e9, "rate": 1e6}"#, &limits);
+        assert_eq!(error_code(&expected_count), "invalid_input");
+        let trials = serve::handle("POST", "/v1/binomial/mid_p_test", br#"{"successes": 1, "trials": 18446744073709551615, "expected_probability": 0.5}"#);
+        assert_eq!(error_code(&trials), "invalid_input");
+    }
+}
+
+#[cfg(all(test, feature = "repl"))]
+mod repl_testcases {
+    use crate::repl::Session;
+
+    const DATA: &str = "score,before,after,group name\n2.5,72,70,1\n2.9,75,73,2\n3.1,68,69,1\n2.6,80,76,2\n2.7,77,74,1\n2.8,74,70,2\n3.0,,,1\n3.2,NA,,2\n";
+
+    fn loaded(name: &str) -> (Session, std::path::PathBuf) {
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, DATA).unwrap();
+        let mut session = Session::new();
+        session.execute(&format!("load '{}'", path.display())).unwrap();
+        (session, path)
+    }
+
+    #[test]
+    fn columns_and_versus_shorthand() {
+        let (mut session, path) = loaded("hyte_repl_columns.csv");
+        let columns = session.execute("columns").unwrap().unwrap();
+        assert!(columns.contains("before      8 values, 2 missing"));
+        assert!(columns.contains("group name  8 values, 0 missing"));
+
+        let versus = session.execute("t before vs after -o apa").unwrap().unwrap();
+        assert_eq!(versus, session.execute("t2 before after -o apa").unwrap().unwrap());
+        assert_eq!(session.execute("paired before vs after -o apa").unwrap().unwrap(), "t(5) = 3.07, p = .028, d = 1.25");
+        assert_eq!(session.execute("t 'group name' --mean 1.5 -o apa").unwrap().unwrap(), "t(7) = 0.00, p > .999, d = 0.00");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn adjusting_previous_results() {
+        let (mut session, path) = loaded("hyte_repl_adjust.csv");
+        assert_eq!(session.execute("adjust holm").unwrap_err(), "no test has been run since the session started or since the last `clear`");
+        assert_eq!(session.execute("show").unwrap_err(), "no test has been run yet");
+
+        session.execute("t score --mean 3 --tail lower").unwrap();
+        assert!(session.execute("show -o apa").unwrap().unwrap().starts_with("t(7) = -1.73"));
+        session.execute("paired before vs after").unwrap();
+        let adjusted = session.execute("adjust bonferroni").unwrap().unwrap();
+        assert!(adjusted.contains("Adjusted p-value of [1] t score --mean 3 --tail lower = 1.269e-1 (p = 6.344e-2), do not reject"));
+        assert!(adjusted.contains("Adjusted p-value of [2] paired before vs after = 5.559e-2 (p = 2.779e-2), do not reject"));
+        assert_eq!(session.execute("show -o apa").unwrap().unwrap(), "0 of 2 hypotheses rejected, α = .05");
+        assert!(session.execute("adjust benjamini-hochberg --level 0.1 -o apa").unwrap().unwrap().starts_with("2 of 2"));
+
+        assert_eq!(session.execute("clear").unwrap().unwrap(), "Cleared 2 tests");
+        assert!(session.execute("qvalues").is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn errors_leave_the_session_unchanged() {
+        let mut session = Session::new();
+        assert_eq!(session.execute("t score -m 3").unwrap_err(), "no file is loaded; use `load <path>` first");
+        assert!(session.execute("anova a b").unwrap_err().starts_with("unrecognized subcommand 'anova'"));
+        assert_eq!(session.execute("load 'scores.csv").unwrap_err(), "the line has an unclosed quote");
+
+        let (mut session, path) = loaded("hyte_repl_errors.csv");
+        assert_eq!(session.execute("t before -m 70 --missing error").unwrap_err(), "The data contains 2 missing observations!");
+        assert_eq!(session.execute("t score -m 3 -f other.csv").unwrap_err(), "tests run on the loaded file; use `load <path>` to read another one");
+        assert!(session.execute("adjust holm").is_err());
+        assert_eq!(session.execute("# a comment").unwrap(), Some(String::new()));
+        assert_eq!(session.execute("quit").unwrap(), None);
+        assert_eq!(session.history().len(), 5);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn exported_script_reproduces_the_session() {
+        let (mut session, path) = loaded("hyte_repl_script.csv");
+        let first = session.execute("t score -m 3 -o apa").unwrap().unwrap();
+        session.execute("columns").unwrap();
+        session.execute("t nothing -m 3").unwrap_err();
+        let second = session.execute("paired before vs after -o apa").unwrap().unwrap();
+        let adjusted = session.execute("adjust holm").unwrap().unwrap();
+
+        let script = session.script();
+        assert_eq!(script.lines().count(), 5);
+        assert!(script.starts_with("# hyte repl session"));
+        assert!(!script.contains("columns") && !script.contains("nothing"));
+
+        let replayed = Session::new().run_script(&script).unwrap();
+        assert!(replayed.contains(&format!("> t score -m 3 -o apa\n{}", first)));
+        assert!(replayed.contains(&format!("> paired before vs after -o apa\n{}", second)));
+        assert!(replayed.ends_with(&format!("> adjust holm\n{}", adjusted)));
+        assert_eq!(Session::new().run_script("t score -m 3\n").unwrap_err(), "line 1: no file is loaded; use `load <path>` first");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn tab_completion() {
+        let (session, path) = loaded("hyte_repl_complete.csv");
+        assert_eq!(session.complete("ch", 2), (0, vec!["chisq-toi".to_string(), "chisq-gof".to_string()]));
+        assert!(!session.complete("", 0).1.contains(&"repl".to_string()));
+        assert_eq!(session.complete("t b", 3), (2, vec!["before".to_string()]));
+        assert_eq!(session.complete("t before v", 10), (9, vec!["vs".to_string()]));
+        assert_eq!(session.complete("t before vs a", 13), (12, vec!["after".to_string()]));
+        assert_eq!(session.complete("t before vs after --tail u", 26), (25, vec!["upper".to_string()]));
+        assert_eq!(session.complete("t score --t", 11), (8, vec!["--tail".to_string()]));
+        assert_eq!(session.complete("adjust b", 8), (7, vec!["bonferroni".to_string(), "benjamini-hochberg".to_string(), "benjamini-yekutieli".to_string()]));
+        assert!(session.complete("t score -m ", 11).1.is_empty());
+        std::fs::remove_file(path).unwrap();
+    }
+    #[test]
+    fn history_path() {
+        use crate::repl::history_path;
+        use std::path::PathBuf;
+        assert_eq!(history_path(Some("/state".into()), Some("/home/ada".into())), Some(PathBuf::from("/state/hyte/history")));
+        assert_eq!(history_path(Some("".into()), Some("/home/ada".into())), Some(PathBuf::from("/home/ada/.local/state/hyte/history")));
+        assert_eq!(history_path(None, None), None);
+    }
+}
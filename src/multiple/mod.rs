@@ -0,0 +1,284 @@
+//! Module responsible for all items needed to correct p-values for multiple testing.
+
+use crate::report::{self, Report, Reportable};
+use crate::utils::{PValue, TestType};
+
+/// An enum for specifying which multiple-testing correction to apply.
+///
+/// `Bonferroni`, `Holm`, and `Hochberg` control the family-wise error rate (FWER), whereas
+/// `BenjaminiHochberg` and `BenjaminiYekutieli` control the false discovery rate (FDR).
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Correction {
+    Bonferroni,
+    Holm,
+    Hochberg,
+    BenjaminiHochberg,
+    BenjaminiYekutieli,
+}
+
+/// A struct for storing the adjusted p-values and reject flags from a multiple-testing correction.
+///
+/// `adjusted[i]` and `reject[i]` correspond to the `i`-th p-value passed in, in the original order.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MultipleResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::utils::non_finite::Float>"))]
+    pub adjusted: Vec<f64>,
+    pub reject: Vec<bool>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub level: f64,
+}
+
+/// A struct for storing Storey's q-values along with the estimated proportion of true null hypotheses `pi0`.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QValueResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub pi0: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::utils::non_finite::Float>"))]
+    pub q: Vec<f64>,
+    pub reject: Vec<bool>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub level: f64,
+}
+
+/// Test type names of each correction.
+const BONFERRONI: TestType = "Bonferroni Correction (FWER)";
+const HOLM: TestType = "Holm Correction (FWER)";
+const HOCHBERG: TestType = "Hochberg Correction (FWER)";
+const BENJAMINI_HOCHBERG: TestType = "Benjamini-Hochberg Correction (FDR)";
+const BENJAMINI_YEKUTIELI: TestType = "Benjamini-Yekutieli Correction (FDR)";
+const STOREY: TestType = "Storey's q-values (FDR)";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[BONFERRONI, HOLM, HOCHBERG, BENJAMINI_HOCHBERG, BENJAMINI_YEKUTIELI, STOREY]];
+
+fn validate(p_values: &[f64], level: f64) {
+    if p_values.iter().any(|p| !(0.0..=1.0).contains(p)) {
+        panic!("\n[HYTE-Panic] P-values must be between 0 and 1!\n");
+    }
+    if !(0.0..=1.0).contains(&level) {
+        panic!("\n[HYTE-Panic] Significance level must be between 0 and 1!\n");
+    }
+}
+
+/// Returns the indices of `p_values` sorted so that the p-values are in ascending order.
+fn ascending_order(p_values: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..p_values.len()).collect();
+    order.sort_by(|&a, &b| p_values[a].total_cmp(&p_values[b]));
+    order
+}
+
+/// Adjusts a slice of p-values for multiple testing using the specified `Correction`,
+/// and flags each hypothesis to be rejected if its adjusted p-value is less than `level`.
+/// It returns `None` if `p_values` is empty.
+///
+/// # Examples
+///
+/// The following is an example of how you can control the false discovery rate at 0.05
+/// using the Benjamini-Hochberg procedure.
+///
+/// ```
+/// use hyte::multiple::{self, Correction};
+///
+/// fn main() {
+///     let p_values = vec![0.01, 0.04, 0.03, 0.005];
+///     let unwrapped_results = multiple::adjust(&p_values, Correction::BenjaminiHochberg, 0.05);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Benjamini-Hochberg Correction (FDR)");
+///     assert_eq!(results.adjusted, vec![0.02, 0.04, 0.04, 0.02]);
+///     assert_eq!(results.reject, vec![true, true, true, true]);
+/// }
+/// ```
+pub fn adjust(p_values: &[f64], method: Correction, level: f64) -> Option<MultipleResult> {
+    if p_values.is_empty() { return None };
+    validate(p_values, level);
+
+    let m = p_values.len();
+    let m_f64 = m as f64;
+    let order = ascending_order(p_values);
+    let mut adjusted = vec![0.0; m];
+
+    let test_type: &'static str = match method {
+        Correction::Bonferroni => {
+            for (i, &p) in p_values.iter().enumerate() {
+                adjusted[i] = (m_f64 * p).min(1.0);
+            }
+            BONFERRONI
+        },
+        Correction::Holm => {
+            // Step-down: enforce monotonicity from the smallest p-value upwards
+            let mut running_max: f64 = 0.0;
+            for (rank, &i) in order.iter().enumerate() {
+                let value = ((m - rank) as f64 * p_values[i]).min(1.0);
+                running_max = running_max.max(value);
+                adjusted[i] = running_max;
+            }
+            HOLM
+        },
+        Correction::Hochberg => {
+            // Step-up: enforce monotonicity from the largest p-value downwards
+            let mut running_min: f64 = 1.0;
+            for (rank, &i) in order.iter().enumerate().rev() {
+                let value = ((m - rank) as f64 * p_values[i]).min(1.0);
+                running_min = running_min.min(value);
+                adjusted[i] = running_min;
+            }
+            HOCHBERG
+        },
+        Correction::BenjaminiHochberg | Correction::BenjaminiYekutieli => {
+            let harmonic: f64 = match method {
+                Correction::BenjaminiYekutieli => (1..=m).map(|k| 1.0 / k as f64).sum(),
+                _ => 1.0,
+            };
+            let mut running_min: f64 = 1.0;
+            for (rank, &i) in order.iter().enumerate().rev() {
+                let value = (harmonic * p_values[i] * m_f64 / (rank + 1) as f64).min(1.0);
+                running_min = running_min.min(value);
+                adjusted[i] = running_min;
+            }
+            match method {
+                Correction::BenjaminiYekutieli => BENJAMINI_YEKUTIELI,
+                _ => BENJAMINI_HOCHBERG,
+            }
+        },
+    };
+
+    let reject: Vec<bool> = adjusted.iter().map(|&p| p < level).collect();
+
+    let results = MultipleResult {
+        test_type,
+        adjusted,
+        reject,
+        level,
+    };
+
+    Some(results)
+}
+
+/// Extracts the p-values from a slice of test results (e.g. `ZResult`, `TResult`, or `ChiSquareResult`),
+/// then calls `multiple::adjust`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::{multiple, z};
+/// use hyte::multiple::Correction;
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let results = vec![
+///         z::test_dataless(5.0, 4.5, 30, 1.0, Tails::UPPER).unwrap(),
+///         z::test_dataless(4.6, 4.5, 30, 1.0, Tails::UPPER).unwrap(),
+///     ];
+///     let corrected = multiple::adjust_results(&results, Correction::Holm, 0.05).unwrap();
+///
+///     assert_eq!(corrected.reject, vec![true, false]);
+/// }
+/// ```
+pub fn adjust_results<R: PValue>(results: &[R], method: Correction, level: f64) -> Option<MultipleResult> {
+    let p_values: Vec<f64> = results.iter().map(|result| result.p_value()).collect();
+    adjust(&p_values, method, level)
+}
+
+/// Computes Storey's q-values for a slice of p-values. The proportion of true null hypotheses `pi0`
+/// is estimated as the fraction of p-values greater than the tuning parameter `lambda`, rescaled by
+/// `1 - lambda` and capped at 1. A `lambda` of 0.5 is a common choice.
+/// Each hypothesis is flagged to be rejected if its q-value is less than `level`.
+/// It returns `None` if `p_values` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::multiple;
+///
+/// fn main() {
+///     let p_values = vec![0.001, 0.008, 0.039, 0.041, 0.6, 0.9, 0.75, 0.2];
+///     let results = multiple::qvalues(&p_values, 0.5, 0.05).unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Storey's q-values (FDR)");
+///     assert_eq!(results.pi0, 0.75);
+///     assert_eq!(results.reject, vec![true, true, false, false, false, false, false, false]);
+/// }
+/// ```
+pub fn qvalues(p_values: &[f64], lambda: f64, level: f64) -> Option<QValueResult> {
+    if p_values.is_empty() { return None };
+    validate(p_values, level);
+    if !(0.0..1.0).contains(&lambda) {
+        panic!("\n[HYTE-Panic] Lambda must be in the interval [0, 1)!\n");
+    }
+
+    let m = p_values.len();
+    let m_f64 = m as f64;
+    let above_lambda = p_values.iter().filter(|&&p| p > lambda).count() as f64;
+    let pi0 = (above_lambda / (m_f64 * (1.0 - lambda))).min(1.0);
+
+    let order = ascending_order(p_values);
+    let mut q = vec![0.0; m];
+    let mut running_min: f64 = 1.0;
+    for (rank, &i) in order.iter().enumerate().rev() {
+        let value = (pi0 * p_values[i] * m_f64 / (rank + 1) as f64).min(1.0);
+        running_min = running_min.min(value);
+        q[i] = running_min;
+    }
+
+    let reject: Vec<bool> = q.iter().map(|&value| value < level).collect();
+
+    let results = QValueResult {
+        test_type: STOREY,
+        pi0,
+        q,
+        reject,
+        level,
+    };
+
+    Some(results)
+}
+
+impl Reportable for MultipleResult {
+    fn report(&self) -> Report {
+        let rejected = self.reject.iter().filter(|&&r| r).count();
+        Report::new(self.test_type)
+            .row("Number of hypotheses", self.adjusted.len())
+            .row("significance level", report::p_value(self.level))
+            .row("Rejected hypotheses", rejected)
+            .apa(format!("{} of {} hypotheses rejected, α = {}", rejected, self.adjusted.len(), report::bounded(self.level, 2)))
+    }
+}
+
+impl Reportable for QValueResult {
+    fn report(&self) -> Report {
+        let rejected = self.reject.iter().filter(|&&r| r).count();
+        Report::new(self.test_type)
+            .row("Number of hypotheses", self.q.len())
+            .row("Estimated pi0", format!("{:.3}", self.pi0))
+            .row("significance level", report::p_value(self.level))
+            .row("Rejected hypotheses", rejected)
+            .apa(format!("{} of {} hypotheses rejected, q < {}, π₀ = {}", rejected, self.q.len(), report::bounded(self.level, 2), report::bounded(self.pi0, 2)))
+    }
+}
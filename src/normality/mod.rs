@@ -0,0 +1,356 @@
+//! Module responsible for all items needed to test whether data is normally distributed.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Conclusion, PValue, MissingPolicy, Observation, central_moments, clean, conclude, mean, normal_tail, chi_squared_tail, TestType};
+
+/// A struct for storing the resulting test statistic and p-value from normality tests.
+///
+/// `log_p` is the natural logarithm of the p-value, which remains finite even when `p` underflows to 0.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NormalityResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    pub omitted: usize,
+}
+
+/// Test type names of each test for normality.
+const SHAPIRO_WILK: TestType = "Shapiro-Wilk Test for Normality";
+const ANDERSON_DARLING: TestType = "Anderson-Darling Test for Normality";
+const DAGOSTINO_PEARSON: TestType = "D'Agostino-Pearson K^2 Test for Normality";
+const JARQUE_BERA: TestType = "Jarque-Bera Test for Normality";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[SHAPIRO_WILK, ANDERSON_DARLING, DAGOSTINO_PEARSON, JARQUE_BERA]];
+
+/// Evaluates the polynomial `coefficients[0] + coefficients[1] * x + ...` using Horner's method.
+fn poly(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// Calculates the Shapiro-Wilk W statistic and p-value using Royston's (1995) algorithm AS R94,
+/// which is valid for sample sizes between 3 and 5000.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::normality;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+///     let unwrapped_results = normality::shapiro_wilk(data, MissingPolicy::Propagate);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Shapiro-Wilk Test for Normality");
+///     assert_eq!((results.statistic * 1e5).round() / 1e5, 0.78881);
+///     assert_eq!((results.p * 1e6).round() / 1e6, 0.006704);
+/// }
+/// ```
+pub fn shapiro_wilk<Number: Observation>(data: impl AsRef<[Number]>, missing: MissingPolicy) -> Option<NormalityResult> {
+    let (mut x, omitted) = clean(data.as_ref(), missing);
+    if x.is_empty() { return None };
+    let n = x.len();
+    if !(3..=5000).contains(&n) { panic!("\n[HYTE-Panic] Shapiro-Wilk test requires a sample size between 3 and 5000!\n") };
+
+    x.sort_by(|a, b| a.total_cmp(b));
+    if x[n - 1] - x[0] <= 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+
+    let an = n as f64;
+    let half = n / 2;
+    let standard_normal = Normal::new(0.0, 1.0).unwrap();
+
+    // Coefficients `a[i]` for the upper half of the ordered sample
+    let mut a = vec![0.0; half];
+    if n == 3 {
+        a[0] = 0.5_f64.sqrt();
+    } else {
+        const C1: [f64; 6] = [0.0, 0.221157, -0.147981, -2.071190, 4.434685, -2.706056];
+        const C2: [f64; 6] = [0.0, 0.042981, -0.293762, -1.752461, 5.682633, -3.582633];
+
+        let m: Vec<f64> = (1..=half)
+            .map(|i| standard_normal.inverse_cdf((i as f64 - 0.375) / (an + 0.25)))
+            .collect();
+        let summ2 = 2.0 * m.iter().map(|v| v * v).sum::<f64>();
+        let ssumm2 = summ2.sqrt();
+        let rsn = 1.0 / an.sqrt();
+        let a1 = poly(&C1, rsn) - m[0] / ssumm2;
+
+        let (first_scaled, fac) = if n > 5 {
+            let a2 = -m[1] / ssumm2 + poly(&C2, rsn);
+            let fac = ((summ2 - 2.0 * m[0] * m[0] - 2.0 * m[1] * m[1]) / (1.0 - 2.0 * a1 * a1 - 2.0 * a2 * a2)).sqrt();
+            a[1] = a2;
+            (2, fac)
+        } else {
+            let fac = ((summ2 - 2.0 * m[0] * m[0]) / (1.0 - 2.0 * a1 * a1)).sqrt();
+            (1, fac)
+        };
+        a[0] = a1;
+        for i in first_scaled..half {
+            a[i] = -m[i] / fac;
+        }
+    }
+
+    let x_mean = mean(&x).unwrap();
+    let ss: f64 = x.iter().map(|&v| (v - x_mean).powi(2)).sum();
+    let numerator: f64 = (0..half).map(|i| a[i] * (x[n - 1 - i] - x[i])).sum();
+    let statistic = (numerator * numerator / ss).min(1.0);
+
+    let (p, log_p) = if n == 3 {
+        let pi6 = 6.0 / std::f64::consts::PI;
+        let stqr = std::f64::consts::PI / 3.0;
+        let p = (pi6 * (statistic.sqrt().asin() - stqr)).max(0.0);
+        (p, p.ln())
+    } else {
+        let w1 = (1.0 - statistic).ln();
+        if n <= 11 {
+            const G: [f64; 2] = [-2.273, 0.459];
+            const C3: [f64; 4] = [0.544, -0.39978, 0.025054, -6.714e-4];
+            const C4: [f64; 4] = [1.3822, -0.77857, 0.062767, -0.0020322];
+            let gamma = poly(&G, an);
+            if w1 >= gamma {
+                (1e-99, 1e-99_f64.ln())
+            } else {
+                let y = -(gamma - w1).ln();
+                let m = poly(&C3, an);
+                let s = poly(&C4, an).exp();
+                normal_tail((y - m) / s)
+            }
+        } else {
+            const C5: [f64; 4] = [-1.5861, -0.31082, -0.083751, 0.0038915];
+            const C6: [f64; 3] = [-0.4803, -0.082676, 0.0030302];
+            let ln_n = an.ln();
+            let m = poly(&C5, ln_n);
+            let s = poly(&C6, ln_n).exp();
+            normal_tail((w1 - m) / s)
+        }
+    };
+
+    let results = NormalityResult {
+        test_type: SHAPIRO_WILK,
+        statistic,
+        p,
+        log_p,
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Calculates the Anderson-Darling A² statistic against the normal distribution with mean and
+/// standard deviation estimated from the data. The p-value is computed from the small-sample
+/// adjusted statistic `A² (1 + 0.75/n + 2.25/n²)` using the approximation of D'Agostino and Stephens (1986).
+/// The sample size must be at least 8.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::normality;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+///     let results = normality::anderson_darling(data, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Anderson-Darling Test for Normality");
+///     assert!(results.p < 0.05);
+/// }
+/// ```
+pub fn anderson_darling<Number: Observation>(data: impl AsRef<[Number]>, missing: MissingPolicy) -> Option<NormalityResult> {
+    let (mut x, omitted) = clean(data.as_ref(), missing);
+    if x.is_empty() { return None };
+    let n = x.len();
+    if n < 8 { panic!("\n[HYTE-Panic] Anderson-Darling test requires a sample size of at least 8!\n") };
+
+    x.sort_by(|a, b| a.total_cmp(b));
+    let x_mean = mean(&x).unwrap();
+    let sd = (x.iter().map(|&v| (v - x_mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt();
+    if sd == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+
+    let standard_normal = Normal::new(0.0, 1.0).unwrap();
+    let an = n as f64;
+    let mut sum = 0.0;
+    for i in 0..n {
+        let lower = standard_normal.cdf((x[i] - x_mean) / sd).ln();
+        let upper = standard_normal.sf((x[n - 1 - i] - x_mean) / sd).ln();
+        sum += (2 * i + 1) as f64 * (lower + upper);
+    }
+    let statistic = -an - sum / an;
+
+    let adjusted = statistic * (1.0 + 0.75 / an + 2.25 / (an * an));
+    // The approximation is exponential in the upper tail, so its logarithm is taken directly there
+    let log_p = if adjusted < 0.2 {
+        (-(-13.436 + 101.14 * adjusted - 223.73 * adjusted.powi(2)).exp()).ln_1p()
+    } else if adjusted < 0.34 {
+        (-(-8.318 + 42.796 * adjusted - 59.938 * adjusted.powi(2)).exp()).ln_1p()
+    } else if adjusted < 0.6 {
+        0.9177 - 4.279 * adjusted - 1.38 * adjusted.powi(2)
+    } else {
+        1.2937 - 5.709 * adjusted + 0.0186 * adjusted.powi(2)
+    }.min(0.0);
+
+    let results = NormalityResult {
+        test_type: ANDERSON_DARLING,
+        statistic,
+        p: log_p.exp(),
+        log_p,
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Calculates D'Agostino's K² omnibus statistic, which combines the standardised skewness
+/// (D'Agostino, 1970) and kurtosis (Anscombe and Glynn, 1983) into a Chi-squared statistic
+/// with 2 degrees of freedom. The sample size must be at least 8.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::normality;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+///     let results = normality::dagostino_pearson(data, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "D'Agostino-Pearson K^2 Test for Normality");
+///     assert!(results.p < 0.05);
+/// }
+/// ```
+pub fn dagostino_pearson<Number: Observation>(data: impl AsRef<[Number]>, missing: MissingPolicy) -> Option<NormalityResult> {
+    let (x, omitted) = clean(data.as_ref(), missing);
+    if x.is_empty() { return None };
+    let n = x.len();
+    if n < 8 { panic!("\n[HYTE-Panic] D'Agostino-Pearson test requires a sample size of at least 8!\n") };
+
+    let (m2, m3, m4) = central_moments(&x);
+    if m2 == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+    let an = n as f64;
+
+    // Skewness test
+    let b1 = m3 / m2.powf(1.5);
+    let mut y = b1 * ((an + 1.0) * (an + 3.0) / (6.0 * (an - 2.0))).sqrt();
+    let beta2 = 3.0 * (an * an + 27.0 * an - 70.0) * (an + 1.0) * (an + 3.0)
+        / ((an - 2.0) * (an + 5.0) * (an + 7.0) * (an + 9.0));
+    let w2 = -1.0 + (2.0 * (beta2 - 1.0)).sqrt();
+    let delta = 1.0 / (0.5 * w2.ln()).sqrt();
+    let alpha = (2.0 / (w2 - 1.0)).sqrt();
+    if y == 0.0 { y = 1.0 };
+    let z_skew = delta * (y / alpha + ((y / alpha).powi(2) + 1.0).sqrt()).ln();
+
+    // Kurtosis test
+    let b2 = m4 / (m2 * m2);
+    let expected = 3.0 * (an - 1.0) / (an + 1.0);
+    let var_b2 = 24.0 * an * (an - 2.0) * (an - 3.0) / ((an + 1.0).powi(2) * (an + 3.0) * (an + 5.0));
+    let standardised = (b2 - expected) / var_b2.sqrt();
+    let sqrt_beta1 = 6.0 * (an * an - 5.0 * an + 2.0) / ((an + 7.0) * (an + 9.0))
+        * (6.0 * (an + 3.0) * (an + 5.0) / (an * (an - 2.0) * (an - 3.0))).sqrt();
+    let big_a = 6.0 + 8.0 / sqrt_beta1 * (2.0 / sqrt_beta1 + (1.0 + 4.0 / (sqrt_beta1 * sqrt_beta1)).sqrt());
+    let term1 = 1.0 - 2.0 / (9.0 * big_a);
+    let denominator = 1.0 + standardised * (2.0 / (big_a - 4.0)).sqrt();
+    let term2 = denominator.signum() * ((1.0 - 2.0 / big_a) / denominator.abs()).cbrt();
+    let z_kurt = (term1 - term2) / (2.0 / (9.0 * big_a)).sqrt();
+
+    let statistic = z_skew * z_skew + z_kurt * z_kurt;
+    let (p, log_p) = chi_squared_tail(statistic, 2.0);
+
+    let results = NormalityResult {
+        test_type: DAGOSTINO_PEARSON,
+        statistic,
+        p,
+        log_p,
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Calculates the Jarque-Bera statistic `n/6 (S² + (K - 3)²/4)`, where `S` and `K` are the sample
+/// skewness and kurtosis, and its asymptotic p-value from a Chi-squared distribution with 2 degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::normality;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let data = vec![148, 154, 158, 160, 161, 162, 166, 170, 182, 195, 236];
+///     let results = normality::jarque_bera(data, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Jarque-Bera Test for Normality");
+///     assert!(results.p < 0.05);
+/// }
+/// ```
+pub fn jarque_bera<Number: Observation>(data: impl AsRef<[Number]>, missing: MissingPolicy) -> Option<NormalityResult> {
+    let (x, omitted) = clean(data.as_ref(), missing);
+    if x.is_empty() { return None };
+    let (m2, m3, m4) = central_moments(&x);
+    if m2 == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+
+    let skewness = m3 / m2.powf(1.5);
+    let kurtosis = m4 / (m2 * m2);
+    let statistic = x.len() as f64 / 6.0 * (skewness.powi(2) + (kurtosis - 3.0).powi(2) / 4.0);
+    let (p, log_p) = chi_squared_tail(statistic, 2.0);
+
+    let results = NormalityResult {
+        test_type: JARQUE_BERA,
+        statistic,
+        p,
+        log_p,
+        omitted,
+    };
+
+    Some(results)
+}
+
+impl NormalityResult {
+    /// Concludes if a `NormalityResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `NormalityResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for NormalityResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for NormalityResult {
+    fn report(&self) -> Report {
+        let (label, apa_statistic) = match self.test_type {
+            SHAPIRO_WILK => ("W", format!("W = {}", report::bounded(self.statistic, 2))),
+            ANDERSON_DARLING => ("A^2", format!("A² = {:.2}", self.statistic)),
+            DAGOSTINO_PEARSON => ("K^2", format!("K² = {:.2}", self.statistic)),
+            _ => ("JB", format!("JB = {:.2}", self.statistic)),
+        };
+        Report::new(self.test_type)
+            .row(&format!("{} test statistic", label), format!("{:.4}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .apa(format!("{}, {}", apa_statistic, report::apa_p(self.p)))
+    }
+}
@@ -0,0 +1,437 @@
+//! Module responsible for all items needed to perform permutation tests of user-defined statistics.
+//!
+//! Each test takes a closure computing the statistic from the samples, and compares its value on the observed data
+//! with its values when the data is relabelled in every way the null hypothesis treats as equally likely: the
+//! observations are exchanged between samples (`test`), between samples within strata (`test_stratified`), within
+//! pairs (`test_paired`), or reflected about a null value (`test_sign_flip`). When there are at most
+//! `Resampling::exact_limit` relabellings the p-value is exact, and otherwise it is estimated from
+//! `Resampling::resamples` random relabellings.
+
+use std::collections::BTreeMap;
+use rand::seq::SliceRandom;
+use statrs::function::factorial::binomial;
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Direction, Tails, Conclusion, PValue, conclude, MissingPolicy, Observation, Resampling, clean, clean_rows, resample, TestType};
+
+/// A struct for storing the resulting statistic and p-value from permutation tests.
+///
+/// `permutations` is the number of relabellings the statistic was evaluated on. If `exact` is `true`, these are
+/// every relabelling and `standard_error` is 0. Otherwise they are random, `p` is the Monte Carlo estimate
+/// `(1 + extreme) / (1 + permutations)`, which is never 0, and `standard_error` is its standard error.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PermutationResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    pub permutations: usize,
+    pub exact: bool,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub standard_error: f64,
+    pub hypotheses: String,
+    pub omitted: usize,
+}
+
+/// Exact and Monte Carlo test type names of each kind of permutation test.
+type TestTypes = [&'static str; 2];
+
+const GROUPS: TestTypes = ["Permutation Test (Exact)", "Permutation Test (Monte Carlo)"];
+const STRATIFIED: TestTypes = ["Stratified Permutation Test (Exact)", "Stratified Permutation Test (Monte Carlo)"];
+const PAIRED: TestTypes = ["Paired Permutation Test (Exact)", "Paired Permutation Test (Monte Carlo)"];
+const SIGN_FLIP: TestTypes = ["Sign-Flip Permutation Test (Exact)", "Sign-Flip Permutation Test (Monte Carlo)"];
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![GROUPS, STRATIFIED, PAIRED, SIGN_FLIP];
+
+/// Observations pooled from every sample, with the sample each one belongs to and the positions of each stratum,
+/// within which those labels are exchangeable. `order` visits the positions by their index within their sample,
+/// so the `i`-th observations of the samples, such as the two of a pair, stay aligned under any relabelling.
+struct Design {
+    values: Vec<f64>,
+    labels: Vec<usize>,
+    strata: Vec<Vec<usize>>,
+    order: Vec<usize>,
+    samples: usize,
+}
+
+/// Counts of relabellings whose statistic is at least, and at most, the observed statistic.
+#[derive(Default)]
+struct Tally {
+    greater: usize,
+    less: usize,
+    total: usize,
+}
+
+impl Tally {
+    fn add(&mut self, statistic: f64, observed: f64) {
+        // Relabellings which only reorder a sum can change the statistic in its last bits, and must still tie
+        let tolerance = 1e-12 * observed.abs().max(1.0);
+        if statistic >= observed - tolerance { self.greater += 1 };
+        if statistic <= observed + tolerance { self.less += 1 };
+        self.total += 1;
+    }
+
+    fn merge(mut self, other: Tally) -> Tally {
+        self.greater += other.greater;
+        self.less += other.less;
+        self.total += other.total;
+        self
+    }
+}
+
+/// Rearranges `labels` into the next permutation in lexicographic order, returning `false` and sorting them once
+/// every permutation has been visited. Equal labels are never swapped, so each distinct permutation is visited once.
+fn next_permutation(labels: &mut [usize]) -> bool {
+    let Some(i) = labels.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        labels.reverse();
+        return false;
+    };
+    let j = labels.iter().rposition(|&label| label > labels[i]).unwrap();
+    labels.swap(i, j);
+    labels[i + 1..].reverse();
+    true
+}
+
+impl Design {
+    /// Pools `samples`, labelling each observation with its sample and stratum.
+    fn new(samples: Vec<Vec<f64>>, strata: Vec<Vec<usize>>) -> Design {
+        let count = samples.len();
+        let mut positions: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let (mut values, mut labels, mut indices) = (Vec::new(), Vec::new(), Vec::new());
+        for (label, (sample, strata)) in samples.into_iter().zip(strata).enumerate() {
+            for (index, (value, stratum)) in sample.into_iter().zip(strata).enumerate() {
+                positions.entry(stratum).or_default().push(values.len());
+                values.push(value);
+                labels.push(label);
+                indices.push(index);
+            }
+        }
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by_key(|&position| indices[position]);
+        Design { values, labels, strata: positions.into_values().collect(), order, samples: count }
+    }
+
+    /// Returns the number of distinct relabellings, the product over strata of the multinomial coefficients of
+    /// the sample sizes within each stratum.
+    fn relabellings(&self) -> f64 {
+        self.strata.iter().map(|positions| {
+            let mut sizes = vec![0; self.samples];
+            positions.iter().for_each(|&position| sizes[self.labels[position]] += 1);
+            let mut pooled = 0;
+            sizes.iter().map(|&size| {
+                pooled += size;
+                binomial(pooled as u64, size as u64)
+            }).product::<f64>()
+        }).product()
+    }
+
+    /// The labels within each stratum, sorted, from which every relabelling is generated.
+    fn stratum_labels(&self) -> Vec<Vec<usize>> {
+        self.strata.iter().map(|positions| {
+            let mut labels: Vec<usize> = positions.iter().map(|&position| self.labels[position]).collect();
+            labels.sort_unstable();
+            labels
+        }).collect()
+    }
+
+    /// Writes the labels of each stratum back to their positions.
+    fn relabel(&self, labels: &mut [usize], stratum_labels: &[Vec<usize>]) {
+        for (positions, stratum) in self.strata.iter().zip(stratum_labels) {
+            positions.iter().zip(stratum).for_each(|(&position, &label)| labels[position] = label);
+        }
+    }
+
+    /// Evaluates `statistic` with the observations split into samples by `labels`, keeping their order.
+    fn evaluate(&self, labels: &[usize], buffers: &mut [Vec<f64>], statistic: &impl Fn(&[&[f64]]) -> f64) -> f64 {
+        buffers.iter_mut().for_each(Vec::clear);
+        self.order.iter().for_each(|&position| buffers[labels[position]].push(self.values[position]));
+        let samples: Vec<&[f64]> = buffers.iter().map(Vec::as_slice).collect();
+        statistic(&samples)
+    }
+
+    /// Evaluates `statistic` on every relabelling.
+    fn enumerate(&self, observed: f64, statistic: &impl Fn(&[&[f64]]) -> f64) -> Tally {
+        let mut tally = Tally::default();
+        let mut buffers = vec![Vec::new(); self.samples];
+        let mut labels = self.labels.clone();
+        let mut stratum_labels = self.stratum_labels();
+        loop {
+            self.relabel(&mut labels, &stratum_labels);
+            tally.add(self.evaluate(&labels, &mut buffers, statistic), observed);
+            // Advance the strata like the digits of an odometer
+            let mut stratum = 0;
+            while !next_permutation(&mut stratum_labels[stratum]) {
+                stratum += 1;
+                if stratum == stratum_labels.len() { return tally };
+            }
+        }
+    }
+
+    /// Evaluates `statistic` on `resampling.resamples` random relabellings.
+    fn sample(&self, observed: f64, statistic: &(impl Fn(&[&[f64]]) -> f64 + Sync), resampling: &Resampling) -> Tally {
+        let tallies = resample(resampling, |rng, count| {
+            let mut tally = Tally::default();
+            let mut buffers = vec![Vec::new(); self.samples];
+            let mut labels = self.labels.clone();
+            let mut stratum_labels = self.stratum_labels();
+            for _ in 0..count {
+                stratum_labels.iter_mut().for_each(|stratum| stratum.shuffle(rng));
+                self.relabel(&mut labels, &stratum_labels);
+                tally.add(self.evaluate(&labels, &mut buffers, statistic), observed);
+            }
+            tally
+        });
+        tallies.into_iter().fold(Tally::default(), Tally::merge)
+    }
+
+    /// Performs the test, describing the null hypothesis as `null`.
+    fn test(&self, statistic: impl Fn(&[&[f64]]) -> f64 + Sync, alternative: Alternative, resampling: Resampling, test_types: TestTypes, null: &str, omitted: usize) -> PermutationResult {
+        let mut buffers = vec![Vec::new(); self.samples];
+        let observed = self.evaluate(&self.labels, &mut buffers, &statistic);
+        let exact = self.relabellings() <= resampling.exact_limit as f64;
+        let tally = if observed.is_nan() {
+            Tally::default()
+        } else if exact {
+            self.enumerate(observed, &statistic)
+        } else {
+            self.sample(observed, &statistic, &resampling)
+        };
+
+        let permutations = if exact { tally.total } else { resampling.resamples };
+        let (p_greater, p_less) = if exact {
+            (tally.greater as f64 / tally.total as f64, tally.less as f64 / tally.total as f64)
+        } else {
+            let estimate = |extreme: usize| (extreme + 1) as f64 / (resampling.resamples + 1) as f64;
+            (estimate(tally.greater), estimate(tally.less))
+        };
+        let p = match alternative.tails() {
+            _ if observed.is_nan() => f64::NAN,
+            Tails::UPPER => p_greater,
+            Tails::LOWER => p_less,
+            Tails::BOTH => (2.0 * p_greater.min(p_less)).min(1.0),
+        };
+        let standard_error = if exact { 0.0 } else { (p * (1.0 - p) / resampling.resamples as f64).sqrt() };
+        let difference = match alternative.direction {
+            Direction::Less => "less",
+            Direction::Greater => "greater",
+            Direction::TwoSided => "greater or less",
+        };
+
+        PermutationResult {
+            test_type: test_types[if exact { 0 } else { 1 }],
+            statistic: observed,
+            p,
+            permutations,
+            exact,
+            standard_error,
+            hypotheses: format!("H₀: {} vs H₁: T is {} than under H₀", null, difference),
+            omitted,
+        }
+    }
+}
+
+/// Applies the missing-data policy to each of at least 2 samples, returning the cleaned samples and the total
+/// number of omitted observations.
+fn clean_samples<Number: Observation, Sample: AsRef<[Number]>>(samples: &[Sample], missing: MissingPolicy) -> (Vec<Vec<f64>>, usize) {
+    if samples.len() < 2 { panic!("\n[HYTE-Panic] Permutation tests require at least 2 samples!\n") };
+    let cleaned: Vec<(Vec<f64>, usize)> = samples.iter().map(|sample| clean(sample.as_ref(), missing)).collect();
+    let omitted = cleaned.iter().map(|(_, omitted)| omitted).sum();
+    (cleaned.into_iter().map(|(sample, _)| sample).collect(), omitted)
+}
+
+/// Performs a permutation test of `statistic`, which is computed from the samples in the order they are given,
+/// under the null hypothesis that the observations are exchangeable between the samples.
+///
+/// `Tails::UPPER` tests whether the observed statistic is greater than under relabelling, and `Tails::LOWER` whether
+/// it is less. `Tails::BOTH` doubles the smaller of the two one-sided p-values, so it suits statistics whose
+/// permutation distribution is not centred on 0, such as ratios. Any statistic can be used, such as a difference of
+/// medians, a ratio of means, or an F statistic of several samples; a NaN statistic gives a NaN p-value.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::permutation;
+/// use hyte::utils::{mean, Tails, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let control = vec![12.1, 9.8, 11.4, 10.2, 10.9, 9.5, 10.7];
+///     let treatment = vec![13.2, 12.7, 11.9, 14.1, 12.2, 13.5];
+///     let ratio = |samples: &[&[f64]]| mean(samples[1]).unwrap() / mean(samples[0]).unwrap();
+///     let results = permutation::test(&[control, treatment], ratio, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Permutation Test (Exact)");
+///     assert_eq!(results.permutations, 1716);
+///     assert_eq!(results.p, 2.0 / 1716.0);
+/// }
+/// ```
+pub fn test<Number: Observation, Sample: AsRef<[Number]>>(samples: &[Sample], statistic: impl Fn(&[&[f64]]) -> f64 + Sync, alternative: impl Into<Alternative>, resampling: Resampling, missing: MissingPolicy) -> Option<PermutationResult> {
+    let (samples, omitted) = clean_samples(samples, missing);
+    if samples.iter().any(Vec::is_empty) { return None };
+    let alternative = alternative.into().without_null("A permutation test");
+    let strata = samples.iter().map(|sample| vec![0; sample.len()]).collect();
+    let design = Design::new(samples, strata);
+    Some(design.test(statistic, alternative, resampling, GROUPS, "the samples are exchangeable", omitted))
+}
+
+/// Performs a permutation test of `statistic` under the null hypothesis that the observations are exchangeable
+/// between the samples within each stratum, such as a site, a day, or a batch.
+///
+/// `strata` holds the stratum of every observation, in the same shape as `samples`. Observations are only ever
+/// exchanged with others of the same stratum, so differences between strata cannot affect the test.
+/// Missing observations are omitted together with their stratum. Otherwise, the test is the same as `permutation::test`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::permutation;
+/// use hyte::utils::{mean, Tails, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let control = vec![4.1, 3.8, 4.4, 9.9, 10.3, 9.6];
+///     let treatment = vec![4.9, 5.2, 4.7, 10.8, 11.1, 11.4];
+///     let site = vec![0, 0, 0, 1, 1, 1];
+///     let difference = |samples: &[&[f64]]| mean(samples[1]).unwrap() - mean(samples[0]).unwrap();
+///     let results = permutation::test_stratified(&[control, treatment], &[&site, &site], difference, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Stratified Permutation Test (Exact)");
+///     assert_eq!(results.permutations, 400);
+///     assert_eq!(results.p, 1.0 / 400.0);
+/// }
+/// ```
+pub fn test_stratified<Number: Observation, Sample: AsRef<[Number]>, Strata: AsRef<[usize]>>(samples: &[Sample], strata: &[Strata], statistic: impl Fn(&[&[f64]]) -> f64 + Sync, alternative: impl Into<Alternative>, resampling: Resampling, missing: MissingPolicy) -> Option<PermutationResult> {
+    if samples.len() != strata.len() {
+        panic!("\n[HYTE-Panic] The number of samples ({}) and strata ({}) do not match!\n", samples.len(), strata.len());
+    }
+    if samples.len() < 2 { panic!("\n[HYTE-Panic] Permutation tests require at least 2 samples!\n") };
+    let mut omitted = 0;
+    let (samples, strata): (Vec<Vec<f64>>, Vec<Vec<usize>>) = samples.iter().zip(strata).map(|(sample, strata)| {
+        let (sample, strata) = (sample.as_ref(), strata.as_ref());
+        if sample.len() != strata.len() {
+            panic!("\n[HYTE-Panic] The lengths of a sample ({}) and its strata ({}) do not match!\n", sample.len(), strata.len());
+        }
+        let (cleaned, missing_observations) = clean(sample, missing);
+        omitted += missing_observations;
+        let kept = strata.iter().zip(sample).filter(|(_, &x)| missing == MissingPolicy::Propagate || x.value().is_some());
+        (cleaned, kept.map(|(&stratum, _)| stratum).collect())
+    }).unzip();
+    if samples.iter().any(Vec::is_empty) { return None };
+    let alternative = alternative.into().without_null("A permutation test");
+    let design = Design::new(samples, strata);
+    Some(design.test(statistic, alternative, resampling, STRATIFIED, "the samples are exchangeable within strata", omitted))
+}
+
+/// Performs a paired permutation test of `statistic`, which is computed from the first and second observations of
+/// each pair, under the null hypothesis that the two observations of every pair are exchangeable.
+///
+/// Each relabelling swaps the observations of some of the pairs, keeping the pairs in order, so there are
+/// `2^n` relabellings of `n` pairs. Pairs with a missing observation are omitted together. Otherwise, the test is the
+/// same as `permutation::test`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::permutation;
+/// use hyte::utils::{median, Tails, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let before = vec![142, 150, 138, 161, 155, 149, 147, 158];
+///     let after = vec![135, 144, 139, 150, 149, 141, 146, 151];
+///     let median_drop = |before: &[f64], after: &[f64]| {
+///         let drops: Vec<f64> = before.iter().zip(after).map(|(b, a)| b - a).collect();
+///         median(&drops).unwrap()
+///     };
+///     let results = permutation::test_paired(before, after, median_drop, Tails::UPPER, Resampling::default(), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Paired Permutation Test (Exact)");
+///     assert_eq!(results.permutations, 256);
+///     assert!(results.p < 0.05);
+/// }
+/// ```
+pub fn test_paired<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, statistic: impl Fn(&[f64], &[f64]) -> f64 + Sync, alternative: impl Into<Alternative>, resampling: Resampling, missing: MissingPolicy) -> Option<PermutationResult> {
+    let (data1, data2) = (data1.as_ref(), data2.as_ref());
+    if data1.len() != data2.len() {
+        panic!("\n[HYTE-Panic] The lengths of data1 ({}) and data2 ({}) do not match!\n", data1.len(), data2.len());
+    }
+    let (columns, omitted) = clean_rows(&[data1, data2], missing);
+    if columns[0].is_empty() { return None };
+    let alternative = alternative.into().without_null("A permutation test");
+    // Each pair is a stratum of its own, within which the two observations can be swapped
+    let strata: Vec<usize> = (0..columns[0].len()).collect();
+    let design = Design::new(columns, vec![strata.clone(), strata]);
+    Some(design.test(|samples| statistic(samples[0], samples[1]), alternative, resampling, PAIRED, "the observations of each pair are exchangeable", omitted))
+}
+
+/// Performs a sign-flip permutation test of `statistic`, which is computed from the data, under the null hypothesis
+/// that the data is symmetric about the null value of `alternative`, which defaults to 0.
+///
+/// Each relabelling reflects some of the observations about the null value, so there are `2^n` relabellings of
+/// `n` observations. Passing the differences of paired observations tests whether their distribution is
+/// symmetric about the null value. Otherwise, the test is the same as `permutation::test`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::permutation;
+/// use hyte::utils::{trimmed_mean, Alternative, MissingPolicy, Resampling};
+///
+/// fn main() {
+///     let differences = vec![0.8, 1.4, -0.3, 2.1, 0.9, 1.7, 0.2, 1.1, -0.6, 1.9, 0.7, 1.3, 2.4, 0.5];
+///     let statistic = |data: &[f64]| trimmed_mean(data, 0.1).unwrap();
+///     let results = permutation::test_sign_flip(differences, statistic, Alternative::greater(0.0), Resampling::seeded(42), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "Sign-Flip Permutation Test (Monte Carlo)");
+///     assert_eq!(results.permutations, 9999);
+///     assert!(results.p < 0.01);
+/// }
+/// ```
+pub fn test_sign_flip<Number: Observation>(data: impl AsRef<[Number]>, statistic: impl Fn(&[f64]) -> f64 + Sync, alternative: impl Into<Alternative>, resampling: Resampling, missing: MissingPolicy) -> Option<PermutationResult> {
+    let (data, omitted) = clean(data.as_ref(), missing);
+    if data.is_empty() { return None };
+    let alternative = alternative.into().or_null(0.0);
+    let center = alternative.null_value.unwrap();
+    // Swapping each observation with its reflection is a paired permutation of the data and the reflected data
+    let reflected = data.iter().map(|&x| 2.0 * center - x).collect();
+    let strata: Vec<usize> = (0..data.len()).collect();
+    let design = Design::new(vec![data, reflected], vec![strata.clone(), strata]);
+    let null = format!("the data is symmetric about {:?}", center);
+    Some(design.test(|samples| statistic(samples[0]), alternative, resampling, SIGN_FLIP, &null, omitted))
+}
+
+impl PermutationResult {
+    /// Concludes if a `PermutationResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `PermutationResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for PermutationResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for PermutationResult {
+    fn report(&self) -> Report {
+        let report = Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("Test statistic", format!("{:.2}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .row("Permutations", self.permutations);
+        let report = if self.exact { report } else { report.row("Monte Carlo standard error", format!("{:.4}", self.standard_error)) };
+        report.apa(format!("Test statistic = {:.2}, {}, {} permutations", self.statistic, report::apa_p(self.p), self.permutations))
+    }
+}
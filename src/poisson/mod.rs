@@ -0,0 +1,390 @@
+//! Module responsible for all items needed to test rates of count data observed over an exposure.
+
+use statrs::distribution::{Discrete, DiscreteCDF, Poisson};
+use statrs::function::gamma::gamma_lr;
+use crate::binomial::{self, Interval};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Tails, Conclusion, PValue, conclude, ln_sum_exp, LOG_SPACE_THRESHOLD, tailed, TestType};
+use crate::z::{self, ZResult};
+
+/// Relative tolerance used to decide whether two outcomes are equally likely, as in R's `poisson.test`.
+const RELATIVE_ERROR: f64 = 1e-7;
+
+/// Methods for approximating a Poisson rate test with the standard normal distribution.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Approximation {
+    /// Standard error estimated from the observed counts.
+    Wald,
+    /// Standard error computed under the null hypothesis.
+    Score,
+}
+
+/// A struct for storing the resulting p-value and confidence interval from exact Poisson rate tests.
+///
+/// `statistic` is the observed count (the count of the first sample when comparing two rates), and
+/// `estimate` is the observed rate, or the ratio of the two rates. `log_p` is the natural logarithm of the p-value,
+/// which remains finite even when `p` underflows to 0.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PoissonResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub confidence_interval: (f64, f64),
+    pub hypotheses: String,
+}
+
+/// Test type names of the exact tests, whose names do not depend on the alternative.
+const EXACT: TestType = "Exact Poisson Test";
+const EXACT_RATIO: TestType = "Exact Conditional Test for the Ratio of Poisson Rates";
+
+/// Lower-tailed, upper-tailed, and two-sided test type names of each approximate Poisson test.
+const WALD: [TestType; 3] = [
+    "One-Sided Poisson Wald Z-Test (Lower-Tailed)",
+    "One-Sided Poisson Wald Z-Test (Upper-Tailed)",
+    "Two-Sided Poisson Wald Z-Test",
+];
+const SCORE: [TestType; 3] = [
+    "One-Sided Poisson Score Z-Test (Lower-Tailed)",
+    "One-Sided Poisson Score Z-Test (Upper-Tailed)",
+    "Two-Sided Poisson Score Z-Test",
+];
+const WALD_RATIO: [TestType; 3] = [
+    "One-Sided Wald Z-Test for the Ratio of Poisson Rates (Lower-Tailed)",
+    "One-Sided Wald Z-Test for the Ratio of Poisson Rates (Upper-Tailed)",
+    "Two-Sided Wald Z-Test for the Ratio of Poisson Rates",
+];
+const SCORE_RATIO: [TestType; 3] = [
+    "One-Sided Score Z-Test for the Ratio of Poisson Rates (Lower-Tailed)",
+    "One-Sided Score Z-Test for the Ratio of Poisson Rates (Upper-Tailed)",
+    "Two-Sided Score Z-Test for the Ratio of Poisson Rates",
+];
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[EXACT, EXACT_RATIO], WALD, SCORE, WALD_RATIO, SCORE_RATIO];
+
+fn validate_exposure(exposure: f64) {
+    if exposure.is_nan() || exposure <= 0.0 { panic!("\n[HYTE-Panic] Exposure must be a positive number!\n") };
+}
+
+fn validate_rate(rate: f64) {
+    if rate.is_nan() || rate <= 0.0 { panic!("\n[HYTE-Panic] Hypothesised rate must be a positive number!\n") };
+}
+
+fn validate_ratio(ratio: f64) {
+    if ratio.is_nan() || ratio <= 0.0 { panic!("\n[HYTE-Panic] Hypothesised rate ratio must be a positive number!\n") };
+}
+
+/// Calculates the `probability` quantile of a Gamma distribution with unit scale by bisection.
+fn gamma_quantile(shape: f64, probability: f64) -> f64 {
+    let mut low = 0.0;
+    let mut high = shape.max(1.0);
+    while gamma_lr(shape, high) < probability { high *= 2.0 };
+    for _ in 0..200 {
+        let middle = (low + high) / 2.0;
+        if gamma_lr(shape, middle) < probability { low = middle } else { high = middle };
+        if high - low <= 1e-15 * high { break };
+    }
+    (low + high) / 2.0
+}
+
+/// Natural logarithm of the exact p-value, summed over the logarithms of the probabilities of the outcomes
+/// for p-values too small to be represented as an `f64`.
+fn ln_exact_p(distribution: &Poisson, count: u64, tail: &Tails) -> f64 {
+    let expected = distribution.lambda();
+    let ln_lower_tail = |x: u64| ln_sum_exp((0..=x).map(|y| distribution.ln_pmf(y)));
+    // Only called beyond the mean, where the terms fall faster than geometrically, so those below e⁻⁴⁰ of the first are negligible
+    let ln_upper_tail = |x: u64| {
+        let first = distribution.ln_pmf(x);
+        ln_sum_exp((x..).map(|y| distribution.ln_pmf(y)).take_while(|&ln_probability| ln_probability > first - 40.0))
+    };
+    match tail {
+        Tails::LOWER => ln_lower_tail(count),
+        Tails::UPPER => ln_upper_tail(count),
+        Tails::BOTH => {
+            let threshold = distribution.ln_pmf(count) + RELATIVE_ERROR.ln_1p();
+            if (count as f64) < expected {
+                let mut other = expected.ceil() as u64;
+                while distribution.ln_pmf(other) > threshold { other += 1 };
+                ln_sum_exp([ln_lower_tail(count), ln_upper_tail(other)])
+            } else {
+                let other = (0..=expected.floor() as u64).take_while(|&y| distribution.ln_pmf(y) <= threshold).count() as u64;
+                let ln_lower = if other == 0 { f64::NEG_INFINITY } else { ln_lower_tail(other - 1) };
+                ln_sum_exp([ln_lower, ln_upper_tail(count)])
+            }
+        }
+    }
+}
+
+/// Performs an exact test that `count` events observed over `exposure` (e.g. person-years) arise from a
+/// Poisson process with the hypothesised `rate` per unit of exposure.
+///
+/// The two-sided p-value uses the minimum-likelihood method, and the confidence interval for the rate at
+/// `confidence_level` is the exact Garwood interval. As with `correlation::pearson`, the interval is
+/// one-sided when `tail` is `Tails::LOWER` or `Tails::UPPER`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::poisson;
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let unwrapped_results = poisson::test(137, 24.19893, 1.0, Tails::BOTH, 0.95);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Exact Poisson Test");
+///     assert_eq!((results.estimate * 1e4).round() / 1e4, 5.6614);
+///     assert!(results.p < 1e-16);
+/// }
+/// ```
+pub fn test(count: u64, exposure: f64, rate: f64, alternative: impl Into<Alternative>, confidence_level: f64) -> Option<PoissonResult> {
+    validate_exposure(exposure);
+    validate_rate(rate);
+    let alternative = alternative.into().with_null(rate);
+    let tail = alternative.tails();
+    if !(0.0..1.0).contains(&confidence_level) { panic!("\n[HYTE-Panic] Confidence level must be in the interval [0, 1)!\n") };
+
+    let expected = rate * exposure;
+    let distribution = Poisson::new(expected).unwrap();
+    let upper_tail = |x: u64| if x == 0 { 1.0 } else { distribution.sf(x - 1) };
+    let p = match tail {
+        Tails::LOWER => distribution.cdf(count),
+        Tails::UPPER => upper_tail(count),
+        Tails::BOTH => {
+            // Minimum-likelihood method: the other tail starts at the first outcome no more likely than the observed one
+            let observed = distribution.pmf(count);
+            let threshold = observed * (1.0 + RELATIVE_ERROR);
+            let x = count as f64;
+            if x == expected {
+                1.0
+            } else if x < expected {
+                let mut other = expected.ceil() as u64;
+                while distribution.pmf(other) > threshold { other += 1 };
+                (distribution.cdf(count) + upper_tail(other)).min(1.0)
+            } else {
+                let other = (0..=expected.floor() as u64).take_while(|&y| distribution.pmf(y) <= threshold).count() as u64;
+                let lower_tail = if other == 0 { 0.0 } else { distribution.cdf(other - 1) };
+                (lower_tail + upper_tail(count)).min(1.0)
+            }
+        }
+    };
+    let log_p = if p > LOG_SPACE_THRESHOLD { p.ln() } else { ln_exact_p(&distribution, count, &tail) };
+
+    let x = count as f64;
+    let alpha = match tail {
+        Tails::BOTH => (1.0 - confidence_level) / 2.0,
+        _ => 1.0 - confidence_level,
+    };
+    let lower = if count == 0 { 0.0 } else { gamma_quantile(x, alpha) / exposure };
+    let upper = gamma_quantile(x + 1.0, 1.0 - alpha) / exposure;
+    let confidence_interval = match alternative.tails() {
+        Tails::LOWER => (0.0, upper),
+        Tails::UPPER => (lower, f64::INFINITY),
+        Tails::BOTH => (lower, upper),
+    };
+
+    let results = PoissonResult {
+        test_type: EXACT,
+        statistic: x,
+        estimate: x / exposure,
+        p,
+        log_p,
+        confidence_interval,
+        hypotheses: alternative.statement("λ"),
+    };
+
+    Some(results)
+}
+
+/// Performs an exact conditional test that the ratio of two Poisson rates is 1, or the null value of an
+/// `Alternative` such as `Alternative::greater(2.0)`. Given the total count, the count of the first sample
+/// follows a binomial distribution with probability `r * exposure1 / (r * exposure1 + exposure2)` under the
+/// null hypothesis that the ratio is `r`, so `binomial::test` is used.
+///
+/// The estimate is the rate ratio `(count1 / exposure1) / (count2 / exposure2)`, and its confidence interval
+/// at `confidence_level` is transformed from the Clopper-Pearson interval. Returns `None` if both counts are zero.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::poisson;
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let results = poisson::compare(11, 800.0, 21, 3011.0, Tails::BOTH, 0.95).unwrap();
+///
+///     assert_eq!(results.test_type, "Exact Conditional Test for the Ratio of Poisson Rates");
+///     assert_eq!((results.estimate * 1e4).round() / 1e4, 1.9715);
+///     assert!(results.p > 0.05);
+/// }
+/// ```
+pub fn compare(count1: u64, exposure1: f64, count2: u64, exposure2: f64, alternative: impl Into<Alternative>, confidence_level: f64) -> Option<PoissonResult> {
+    validate_exposure(exposure1);
+    validate_exposure(exposure2);
+    let alternative = alternative.into().or_null(1.0);
+    let ratio = alternative.null_value.unwrap();
+    validate_ratio(ratio);
+
+    let null_probability = ratio * exposure1 / (ratio * exposure1 + exposure2);
+    let binomial_results = binomial::test(count1, count1 + count2, null_probability, alternative.tails(), Interval::ClopperPearson, confidence_level)?;
+    let to_ratio = |probability: f64| probability / (1.0 - probability) * exposure2 / exposure1;
+
+    let results = PoissonResult {
+        test_type: EXACT_RATIO,
+        statistic: count1 as f64,
+        estimate: (count1 as f64 / exposure1) / (count2 as f64 / exposure2),
+        p: binomial_results.p,
+        log_p: binomial_results.log_p,
+        confidence_interval: (to_ratio(binomial_results.confidence_interval.0), to_ratio(binomial_results.confidence_interval.1)),
+        hypotheses: alternative.statement("λ₁ / λ₂"),
+    };
+
+    Some(results)
+}
+
+/// Performs a Z-test that `count` events observed over `exposure` arise from a Poisson process with the
+/// hypothesised `rate`. The `Approximation::Score` standard error is `sqrt(rate * exposure)`, while the
+/// `Approximation::Wald` standard error is `sqrt(count)`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::poisson::{self, Approximation};
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let results = poisson::z_test(30, 10.0, 2.0, Approximation::Score, Tails::UPPER).unwrap();
+///
+///     assert_eq!(results.test_type, "One-Sided Poisson Score Z-Test (Upper-Tailed)");
+///     assert_eq!((results.statistic * 1e4).round() / 1e4, 2.2361);
+/// }
+/// ```
+pub fn z_test(count: u64, exposure: f64, rate: f64, method: Approximation, alternative: impl Into<Alternative>) -> Option<ZResult> {
+    validate_exposure(exposure);
+    validate_rate(rate);
+    let alternative = alternative.into().with_null(rate);
+    let tail = alternative.tails();
+    let expected = rate * exposure;
+    let se = match method {
+        Approximation::Wald => {
+            if count == 0 { panic!("\n[HYTE-Panic] Wald test requires non-zero counts!\n") };
+            (count as f64).sqrt()
+        }
+        Approximation::Score => expected.sqrt(),
+    };
+    let test_type = match method {
+        Approximation::Wald => tailed(WALD, &tail),
+        Approximation::Score => tailed(SCORE, &tail),
+    };
+
+    // A sample size of 1 makes `z::test_dataless` use `se` directly as the standard error
+    let mut results = z::test_dataless(count as f64, expected, 1, se, tail)?;
+    results.test_type = test_type;
+    results.hypotheses = alternative.statement("λ");
+
+    Some(results)
+}
+
+/// Performs a Z-test that the ratio of two Poisson rates is 1, or the null value of an `Alternative`.
+/// The `Approximation::Score` test compares `count1` with its expectation given the total count, while the
+/// `Approximation::Wald` test compares the log rate ratio with its standard error `sqrt(1 / count1 + 1 / count2)`.
+/// Returns `None` if both counts are zero.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::poisson::{self, Approximation};
+/// use hyte::utils::Tails;
+///
+/// fn main() {
+///     let results = poisson::compare_z(11, 800.0, 21, 3011.0, Approximation::Wald, Tails::BOTH).unwrap();
+///
+///     assert_eq!(results.test_type, "Two-Sided Wald Z-Test for the Ratio of Poisson Rates");
+///     assert!(results.statistic > 0.0);
+/// }
+/// ```
+pub fn compare_z(count1: u64, exposure1: f64, count2: u64, exposure2: f64, method: Approximation, alternative: impl Into<Alternative>) -> Option<ZResult> {
+    validate_exposure(exposure1);
+    validate_exposure(exposure2);
+    let alternative = alternative.into().or_null(1.0);
+    let ratio = alternative.null_value.unwrap();
+    validate_ratio(ratio);
+    let tail = alternative.tails();
+    if count1 + count2 == 0 { return None };
+
+    let (observed, expected, se) = match method {
+        Approximation::Wald => {
+            if count1 == 0 || count2 == 0 { panic!("\n[HYTE-Panic] Wald test requires non-zero counts!\n") };
+            let log_ratio = ((count1 as f64 / exposure1) / (count2 as f64 / exposure2)).ln();
+            (log_ratio, ratio.ln(), (1.0 / count1 as f64 + 1.0 / count2 as f64).sqrt())
+        }
+        Approximation::Score => {
+            let total = (count1 + count2) as f64;
+            let null_probability = ratio * exposure1 / (ratio * exposure1 + exposure2);
+            (count1 as f64, total * null_probability, (total * null_probability * (1.0 - null_probability)).sqrt())
+        }
+    };
+    let test_type = match method {
+        Approximation::Wald => tailed(WALD_RATIO, &tail),
+        Approximation::Score => tailed(SCORE_RATIO, &tail),
+    };
+
+    let mut results = z::test_dataless(observed, expected, 1, se, tail)?;
+    results.test_type = test_type;
+    results.hypotheses = alternative.statement("λ₁ / λ₂");
+
+    Some(results)
+}
+
+impl PoissonResult {
+    /// Concludes if a `PoissonResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if a `PoissonResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for PoissonResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for PoissonResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("Count", self.statistic)
+            .row("Estimate", format!("{:.4}", self.estimate))
+            .row("p-value", report::p_value(self.p))
+            .row("Confidence interval", format!("({:.4}, {:.4})", self.confidence_interval.0, self.confidence_interval.1))
+            .apa(format!("estimate = {:.2}, CI [{:.2}, {:.2}], {}", self.estimate, self.confidence_interval.0, self.confidence_interval.1, report::apa_p(self.p)))
+    }
+}
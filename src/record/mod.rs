@@ -0,0 +1,224 @@
+//! Module responsible for all items needed to store test results in a stable, versioned serde representation.
+//!
+//! This module is only available with the `serde` feature. With the `schema` feature, `record::schema` also
+//! returns the JSON Schema of a `Record`, which is published in the repository as `schema/record.v1.json`.
+//!
+//! # Examples
+//!
+//! ```
+//! use hyte::t;
+//! use hyte::record::{Record, TestResult};
+//! use hyte::utils::{Tails, Conclusion, MissingPolicy};
+//!
+//! fn main() {
+//!     let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+//!     let results = t::test(&data, 3, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+//!
+//!     let record = Record::new(results)
+//!         .input("data", &data)
+//!         .parameter("expected_mean", 3)
+//!         .alternative(Tails::LOWER)
+//!         .conclude(0.05);
+//!
+//!     assert_eq!(record.version, 1);
+//!     assert_eq!(record.conclusion, Some(Conclusion::DoNotReject));
+//!     assert!(matches!(record.result, TestResult::T(_)));
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::{bayes, binomial, bootstrap, chisquare, correlation, equivalence, homogeneity, ks, multiple, normality, permutation, poisson, regression, t, z};
+use crate::report::{Report, Reportable};
+use crate::utils::{Alternative, Conclusion, Observation, ToPrimitive, conclude};
+
+/// The version of the `Record` representation. It is increased whenever a field is renamed or removed,
+/// and records of any other version are rejected when deserialised.
+pub const VERSION: u32 = 1;
+
+/// Any test result, tagged with a `kind` naming the module which produced it.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TestResult {
+    Z(z::ZResult),
+    T(t::TResult),
+    ChiSquare(chisquare::ChiSquareResult),
+    Normality(normality::NormalityResult),
+    #[serde(rename = "ks")]
+    KS(ks::KSResult),
+    Variance(homogeneity::VarianceResult),
+    Correlation(correlation::CorrelationResult),
+    Regression(regression::RegressionResult),
+    Binomial(binomial::BinomialResult),
+    Poisson(poisson::PoissonResult),
+    Equivalence(equivalence::EquivalenceResult),
+    Permutation(permutation::PermutationResult),
+    Bootstrap(bootstrap::BootstrapResult),
+    BootstrapInterval(bootstrap::IntervalResult),
+    BayesFactor(bayes::BayesFactorResult),
+    BayesComparison(bayes::ComparisonResult),
+    BayesPosterior(bayes::PosteriorResult),
+    Multiple(multiple::MultipleResult),
+    #[serde(rename = "qvalue")]
+    QValue(multiple::QValueResult),
+}
+
+impl TestResult {
+    /// Returns the p-value of the result, or `None` for a bootstrap interval, a Bayesian result, or a batch of adjusted
+    /// p-values or q-values.
+    pub fn p_value(&self) -> Option<f64> {
+        match self {
+            TestResult::Z(result) => Some(result.p),
+            TestResult::T(result) => Some(result.p),
+            TestResult::ChiSquare(result) => Some(result.p),
+            TestResult::Normality(result) => Some(result.p),
+            TestResult::KS(result) => Some(result.p),
+            TestResult::Variance(result) => Some(result.p),
+            TestResult::Correlation(result) => Some(result.p),
+            TestResult::Regression(result) => Some(result.p),
+            TestResult::Binomial(result) => Some(result.p),
+            TestResult::Poisson(result) => Some(result.p),
+            TestResult::Equivalence(result) => Some(result.p),
+            TestResult::Permutation(result) => Some(result.p),
+            TestResult::Bootstrap(result) => Some(result.p),
+            TestResult::BootstrapInterval(_) | TestResult::BayesFactor(_) | TestResult::BayesComparison(_) | TestResult::BayesPosterior(_) => None,
+            TestResult::Multiple(_) | TestResult::QValue(_) => None,
+        }
+    }
+}
+
+impl Reportable for TestResult {
+    fn report(&self) -> Report {
+        match self {
+            TestResult::Z(result) => result.report(),
+            TestResult::T(result) => result.report(),
+            TestResult::ChiSquare(result) => result.report(),
+            TestResult::Normality(result) => result.report(),
+            TestResult::KS(result) => result.report(),
+            TestResult::Variance(result) => result.report(),
+            TestResult::Correlation(result) => result.report(),
+            TestResult::Regression(result) => result.report(),
+            TestResult::Binomial(result) => result.report(),
+            TestResult::Poisson(result) => result.report(),
+            TestResult::Equivalence(result) => result.report(),
+            TestResult::Permutation(result) => result.report(),
+            TestResult::Bootstrap(result) => result.report(),
+            TestResult::BootstrapInterval(result) => result.report(),
+            TestResult::BayesFactor(result) => result.report(),
+            TestResult::BayesComparison(result) => result.report(),
+            TestResult::BayesPosterior(result) => result.report(),
+            TestResult::Multiple(result) => result.report(),
+            TestResult::QValue(result) => result.report(),
+        }
+    }
+}
+
+macro_rules! impl_from_result {
+    ($($variant:ident($result:ty)),*) => {
+        $(
+            impl From<$result> for TestResult {
+                fn from(result: $result) -> TestResult {
+                    TestResult::$variant(result)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_result!(
+    Z(z::ZResult), T(t::TResult), ChiSquare(chisquare::ChiSquareResult), Normality(normality::NormalityResult),
+    KS(ks::KSResult), Variance(homogeneity::VarianceResult), Correlation(correlation::CorrelationResult),
+    Regression(regression::RegressionResult), Binomial(binomial::BinomialResult), Poisson(poisson::PoissonResult),
+    Equivalence(equivalence::EquivalenceResult), Permutation(permutation::PermutationResult), Bootstrap(bootstrap::BootstrapResult),
+    BootstrapInterval(bootstrap::IntervalResult), BayesFactor(bayes::BayesFactorResult), BayesComparison(bayes::ComparisonResult),
+    BayesPosterior(bayes::PosteriorResult), Multiple(multiple::MultipleResult), QValue(multiple::QValueResult)
+);
+
+/// A test result together with the inputs and parameters which produced it, and optionally its conclusion.
+///
+/// The statistic, degrees of freedom, and p-value are fields of `result`. Inputs are stored by name with missing
+/// observations as `None` (`null` in JSON), and parameters such as an expected mean or a confidence level are
+/// stored by name as `f64`s. Maps are ordered by name, so the same record always serialises to the same text.
+///
+/// JSON has no infinite or NaN numbers, so every such `f64` of a record, such as the upper bound of a one-sided
+/// confidence interval or the NaN statistic of a test run on missing data, is written as the string `"inf"`,
+/// `"-inf"`, or `"nan"` and read back as it was. Enable `serde_json`'s `float_roundtrip` feature for finite values
+/// to be read back exactly.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Record {
+    #[serde(deserialize_with = "version")]
+    pub version: u32,
+    #[serde(with = "crate::utils::non_finite")]
+    #[cfg_attr(feature = "schema", schemars(with = "BTreeMap<String, Vec<Option<crate::utils::non_finite::Float>>>"))]
+    pub inputs: BTreeMap<String, Vec<Option<f64>>>,
+    #[serde(with = "crate::utils::non_finite")]
+    #[cfg_attr(feature = "schema", schemars(with = "BTreeMap<String, crate::utils::non_finite::Float>"))]
+    pub parameters: BTreeMap<String, f64>,
+    pub alternative: Option<Alternative>,
+    #[serde(default, with = "crate::utils::non_finite")]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub significance_level: Option<f64>,
+    pub conclusion: Option<Conclusion>,
+    pub result: TestResult,
+}
+
+fn version<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let version = u32::deserialize(deserializer)?;
+    if version != VERSION {
+        return Err(serde::de::Error::custom(format!("unsupported record version {}, expected {}", version, VERSION)));
+    }
+    Ok(version)
+}
+
+impl Record {
+    /// Creates a record of the current `VERSION` holding `result`, with no inputs, parameters, or conclusion.
+    pub fn new(result: impl Into<TestResult>) -> Record {
+        Record {
+            version: VERSION,
+            inputs: BTreeMap::new(),
+            parameters: BTreeMap::new(),
+            alternative: None,
+            significance_level: None,
+            conclusion: None,
+            result: result.into(),
+        }
+    }
+
+    /// Adds a named input column, keeping missing observations as `None`.
+    pub fn input<Number: Observation>(mut self, name: &str, data: impl AsRef<[Number]>) -> Record {
+        self.inputs.insert(name.to_string(), data.as_ref().iter().map(|&x| x.value()).collect());
+        self
+    }
+
+    /// Adds a named numeric parameter, such as `expected_mean` or `confidence_level`.
+    pub fn parameter(mut self, name: &str, value: impl ToPrimitive) -> Record {
+        self.parameters.insert(name.to_string(), value.to_f64().unwrap());
+        self
+    }
+
+    /// Records the alternative hypothesis the test was run with.
+    pub fn alternative(mut self, alternative: impl Into<Alternative>) -> Record {
+        self.alternative = Some(alternative.into());
+        self
+    }
+
+    /// Records `significance_level` and the conclusion it leads to. Results without a single p-value,
+    /// such as adjusted p-values, keep their per-hypothesis decisions and get no conclusion.
+    pub fn conclude(mut self, significance_level: f64) -> Record {
+        self.significance_level = Some(significance_level);
+        self.conclusion = self.result.p_value().map(|p| conclude(p, significance_level));
+        self
+    }
+}
+
+/// Returns the JSON Schema of a `Record`.
+#[cfg(feature = "schema")]
+pub fn schema() -> schemars::Schema {
+    schemars::schema_for!(Record)
+}
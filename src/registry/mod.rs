@@ -0,0 +1,1088 @@
+//! Module responsible for all items needed to describe every test in `hyte` at runtime.
+//!
+//! Each `Test` in `TESTS` declares its module and function, a one-line summary, its parameters and the shape of the
+//! data each one takes, its assumptions, and the fields of its result. `help`, the `--help` of the `hyte` subcommands,
+//! and the `/v1/tests` endpoint of `hyte serve` are all generated from it. With the `serde` feature, every item can
+//! be serialised.
+//!
+//! # Examples
+//!
+//! ```
+//! use hyte::registry::{self, Shape};
+//!
+//! fn main() {
+//!     let test = registry::find("t::test_paired").unwrap();
+//!
+//!     assert_eq!(test.result, "TResult");
+//!     assert_eq!(test.parameters[0].name, "data1");
+//!     assert_eq!(test.parameters[0].shape, Shape::Sample);
+//!     assert!(registry::tests("normality").count() >= 4);
+//! }
+//! ```
+
+/// The shape of the data a parameter takes.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Shape {
+    /// A single number.
+    Scalar,
+    /// A non-negative whole number, such as a count or a sample size.
+    Count,
+    /// A sample of observations, any of which may be missing.
+    Sample,
+    /// Several samples of observations, such as groups or the columns of predictors.
+    Samples,
+    /// A sequence of numbers which may not be missing, such as weights, probabilities, or p-values.
+    Sequence,
+    /// A one-dimensional or two-dimensional `utils::Matrix` of counts.
+    Matrix,
+    /// Summaries of a sample accumulated in a `utils::RunningStats`.
+    Summary,
+    /// A sequence of test results.
+    Results,
+    /// A fully specified continuous distribution.
+    Distribution,
+    /// One of a fixed set of options, such as an `Alternative` or a `MissingPolicy`.
+    Choice,
+    /// A flag which is either `true` or `false`.
+    Flag,
+    /// A closure computing a statistic from the data.
+    Statistic,
+    /// Settings for drawing resamples, in a `utils::Resampling`.
+    Resampling,
+}
+
+/// A parameter of a test, in the order of the function's arguments.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Parameter {
+    pub name: &'static str,
+    pub rust_type: &'static str,
+    pub shape: Shape,
+    pub description: &'static str,
+}
+
+/// A field of the result of a test.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Field {
+    pub name: &'static str,
+    pub rust_type: &'static str,
+    pub description: &'static str,
+}
+
+/// A test, performed by calling `hyte::<module>::<function>`, which returns an `Option` of its `result`.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Test {
+    pub module: &'static str,
+    pub function: &'static str,
+    pub summary: &'static str,
+    pub parameters: &'static [Parameter],
+    pub assumptions: &'static [&'static str],
+    pub result: &'static str,
+    pub fields: &'static [Field],
+}
+
+impl Test {
+    /// Returns the path of the test within `hyte`, such as `t::test_paired`.
+    pub fn name(&self) -> String {
+        format!("{}::{}", self.module, self.function)
+    }
+
+    /// Returns the summary followed by the assumptions of the test, as a paragraph for a command's long help.
+    pub fn description(&self) -> String {
+        let assumptions: String = self.assumptions.iter().map(|assumption| format!("\n  - {}", assumption)).collect();
+        format!("{}\n\nAssumptions:{}", self.summary, assumptions)
+    }
+}
+
+/// A module of tests, with the title of its section in `help`.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Module {
+    pub name: &'static str,
+    pub title: &'static str,
+}
+
+const fn parameter(name: &'static str, rust_type: &'static str, shape: Shape, description: &'static str) -> Parameter {
+    Parameter { name, rust_type, shape, description }
+}
+
+const fn field(name: &'static str, rust_type: &'static str, description: &'static str) -> Field {
+    Field { name, rust_type, description }
+}
+
+const SAMPLE_TYPE: &str = "impl AsRef<[Number]>, where Number: Observation";
+
+const DATA: Parameter = parameter("data", SAMPLE_TYPE, Shape::Sample, "e.g. Vec<f64>, &[i32] or Vec<Option<f64>>");
+const DATA1: Parameter = parameter("data1", SAMPLE_TYPE, Shape::Sample, "the first sample");
+const DATA2: Parameter = parameter("data2", SAMPLE_TYPE, Shape::Sample, "the second sample");
+const X: Parameter = parameter("x", SAMPLE_TYPE, Shape::Sample, "the first variable of each pair");
+const Y: Parameter = parameter("y", SAMPLE_TYPE, Shape::Sample, "the second variable of each pair");
+const EXPECTED_MEAN: Parameter = parameter("expected_mean", "Number2: ToPrimitive", Shape::Scalar, "the mean under the null hypothesis");
+const ALTERNATIVE: Parameter = parameter("alternative", "impl Into<Alternative>", Shape::Choice, "e.g. Tails::BOTH or Alternative::less(4.0)");
+const HYPOTHESIS: Parameter = parameter("hypothesis", "impl Into<Alternative>", Shape::Choice, "e.g. Alternative::equivalence(0.0, -0.5, 0.5) or Alternative::greater(-0.5) for non-inferiority");
+const MISSING: Parameter = parameter("missing", "MissingPolicy", Shape::Choice, "MissingPolicy::Propagate, MissingPolicy::Omit or MissingPolicy::Error");
+const WEIGHTS: Parameter = parameter("weights", "impl AsRef<[Weight]>, where Weight: ToPrimitive", Shape::Sequence, "one non-negative weight per observation");
+const WEIGHTS1: Parameter = parameter("weights1", "impl AsRef<[Weight]>, where Weight: ToPrimitive", Shape::Sequence, "one non-negative weight per observation of data1");
+const WEIGHTS2: Parameter = parameter("weights2", "impl AsRef<[Weight]>, where Weight: ToPrimitive", Shape::Sequence, "one non-negative weight per observation of data2");
+const KIND: Parameter = parameter("kind", "Weights", Shape::Choice, "Weights::Frequency or Weights::Reliability");
+const STATS: Parameter = parameter("stats", "&RunningStats", Shape::Summary, "a streamed or merged accumulator");
+const STATS1: Parameter = parameter("stats1", "&RunningStats", Shape::Summary, "the accumulator of the first sample");
+const STATS2: Parameter = parameter("stats2", "&RunningStats", Shape::Summary, "the accumulator of the second sample");
+const OBSERVED_MEAN: Parameter = parameter("observed_mean", "Number: ToPrimitive", Shape::Scalar, "the mean of the sample");
+const SUMMARY_EXPECTED_MEAN: Parameter = parameter("expected_mean", "Number: ToPrimitive", Shape::Scalar, "the mean under the null hypothesis");
+const SAMPLE_SIZE: Parameter = parameter("sample_size", "impl ToPrimitive", Shape::Count, "e.g. u32, i64 or usize");
+const POP_SD: Parameter = parameter("pop_sd", "Number: ToPrimitive", Shape::Scalar, "the standard deviation");
+const CONFIDENCE_LEVEL: Parameter = parameter("confidence_level", "f64", Shape::Scalar, "e.g. 0.95");
+const SIGNIFICANCE_LEVEL: Parameter = parameter("significance_level", "f64", Shape::Scalar, "the level of each one-sided test; the interval is at 1 - 2 * significance_level");
+const GROUPS: Parameter = parameter("groups", "impl AsRef<[Group]>, where Group: AsRef<[Number]>", Shape::Samples, "e.g. Vec<Vec<f64>> or &[&[f64]]");
+const SUCCESSES: Parameter = parameter("successes", "u64", Shape::Count, "the number of successes");
+const TRIALS: Parameter = parameter("trials", "u64", Shape::Count, "the number of trials");
+const COUNT: Parameter = parameter("count", "u64", Shape::Count, "the number of events");
+const EXPOSURE: Parameter = parameter("exposure", "f64", Shape::Scalar, "the time, area, or population over which the events were counted");
+const RATE: Parameter = parameter("rate", "f64", Shape::Scalar, "the rate per unit of exposure under the null hypothesis");
+const COUNT1: Parameter = parameter("count1", "u64", Shape::Count, "the number of events in the first sample");
+const EXPOSURE1: Parameter = parameter("exposure1", "f64", Shape::Scalar, "the exposure of the first sample");
+const COUNT2: Parameter = parameter("count2", "u64", Shape::Count, "the number of events in the second sample");
+const EXPOSURE2: Parameter = parameter("exposure2", "f64", Shape::Scalar, "the exposure of the second sample");
+const APPROXIMATION: Parameter = parameter("method", "Approximation", Shape::Choice, "Approximation::Wald or Approximation::Score");
+const RESAMPLING: Parameter = parameter("resampling", "Resampling", Shape::Resampling, "the seed, resamples, and exact limit, e.g. Resampling::seeded(42)");
+const BOOTSTRAP_RESAMPLING: Parameter = parameter("resampling", "Resampling", Shape::Resampling, "the seed and resamples, e.g. Resampling::seeded(42)");
+const BOOTSTRAP_STATISTIC: Parameter = parameter("statistic", "impl Fn(&[&[f64]]) -> f64 + Sync", Shape::Statistic, "computes the statistic from the samples, in order");
+const BOOTSTRAP_INTERVAL: Parameter = parameter("method", "Interval", Shape::Choice, "Interval::Percentile, Basic, Studentized(inner resamples) or BCa");
+const PRIOR_SCALE: Parameter = parameter("prior_scale", "f64", Shape::Scalar, "the scale of the Cauchy prior on δ, e.g. bayes::MEDIUM, WIDE or ULTRAWIDE");
+const CREDIBLE_LEVEL: Parameter = parameter("credible_level", "f64", Shape::Scalar, "e.g. 0.95");
+const NORMAL_GAMMA: Parameter = parameter("prior", "NormalGamma", Shape::Distribution, "e.g. NormalGamma::new(mean, kappa, alpha, beta) or NormalGamma::reference()");
+const BINOMIAL: &[Parameter] = &[
+    SUCCESSES,
+    TRIALS,
+    parameter("expected_probability", "f64", Shape::Scalar, "the probability of success under the null hypothesis"),
+    ALTERNATIVE,
+    parameter("interval", "Interval", Shape::Choice, "Interval::ClopperPearson, Wilson, WilsonCorrected, AgrestiCoull or Jeffreys"),
+    CONFIDENCE_LEVEL,
+];
+
+const TEST_TYPE: Field = field("test_type", "&'static str", "the name of the test that was performed");
+const STATISTIC: Field = field("statistic", "f64", "the test statistic");
+const P: Field = field("p", "f64", "the p-value");
+const LOG_P: Field = field("log_p", "f64", "the natural logarithm of the p-value, accurate when p underflows");
+const HYPOTHESES: Field = field("hypotheses", "String", "the null and alternative hypotheses, e.g. H₀: μ = 4.0 vs H₁: μ < 4.0");
+const OMITTED: Field = field("omitted", "usize", "observations removed as missing");
+const ESTIMATE: Field = field("estimate", "f64", "the estimate of the tested parameter");
+
+const Z_FIELDS: &[Field] = &[TEST_TYPE, STATISTIC, P, LOG_P, HYPOTHESES, OMITTED];
+const T_FIELDS: &[Field] = &[
+    TEST_TYPE, STATISTIC,
+    field("df", "f64", "the degrees of freedom, fractional for Welch's test"),
+    P, LOG_P,
+    field("cohens_d", "f64", "the standardised mean difference"),
+    HYPOTHESES, OMITTED,
+];
+const CHI_SQUARE_FIELDS: &[Field] = &[
+    TEST_TYPE, STATISTIC, field("df", "usize", "the degrees of freedom"), P, LOG_P,
+    field("omitted", "usize", "rows of a contingency table, or counts of a goodness of fit test, removed as missing"),
+];
+const NORMALITY_FIELDS: &[Field] = &[TEST_TYPE, STATISTIC, P, LOG_P, OMITTED];
+const KS_FIELDS: &[Field] = &[TEST_TYPE, field("statistic", "f64", "the largest distance between the distribution functions"), P, LOG_P, HYPOTHESES, OMITTED];
+const VARIANCE_FIELDS: &[Field] = &[
+    TEST_TYPE, STATISTIC,
+    field("df1", "usize", "the degrees of freedom between groups"),
+    field("df2", "Option<usize>", "the degrees of freedom within groups, or None for a Chi-squared statistic"),
+    P, LOG_P, OMITTED,
+];
+const CORRELATION_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("estimate", "f64", "the correlation coefficient"),
+    STATISTIC, P, LOG_P,
+    field("confidence_interval", "Option<(f64, f64)>", "the Fisher z interval of Pearson's r, or None"),
+    HYPOTHESES, OMITTED,
+];
+const REGRESSION_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("coefficients", "Vec<Coefficient>", "the estimate, standard error, statistic, p-value, and interval of each coefficient, intercept first"),
+    field("r_squared", "f64", "the coefficient of determination"),
+    field("adjusted_r_squared", "f64", "R² adjusted for the number of predictors"),
+    field("statistic", "f64", "the overall F statistic"),
+    field("df1", "usize", "the degrees of freedom of the model"),
+    field("df2", "usize", "the residual degrees of freedom"),
+    P, LOG_P,
+    field("residuals", "Vec<f64>", "the residual of each complete observation"),
+    field("durbin_watson", "f64", "the Durbin-Watson statistic for autocorrelated residuals"),
+    field("breusch_pagan", "BreuschPagan", "the Breusch-Pagan test for heteroscedastic residuals"),
+    OMITTED,
+];
+const BINOMIAL_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("statistic", "f64", "the number of successes"),
+    field("trials", "u64", "the number of trials"),
+    field("estimate", "f64", "the observed proportion of successes"),
+    P, LOG_P,
+    field("confidence_interval", "(f64, f64)", "the interval of the proportion"),
+    HYPOTHESES,
+];
+const POISSON_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("statistic", "f64", "the observed count"),
+    field("estimate", "f64", "the rate, or the ratio of the two rates"),
+    P, LOG_P,
+    field("confidence_interval", "(f64, f64)", "the interval of the rate or the rate ratio"),
+    HYPOTHESES,
+];
+const EQUIVALENCE_FIELDS: &[Field] = &[
+    TEST_TYPE, ESTIMATE,
+    field("standard_error", "f64", "the standard error of the estimate"),
+    field("df", "Option<f64>", "the degrees of freedom, or None for a Z-test"),
+    field("lower", "Option<OneSidedTest>", "the bound, statistic, and p-value of the test against the lower margin"),
+    field("upper", "Option<OneSidedTest>", "the bound, statistic, and p-value of the test against the upper margin"),
+    field("p", "f64", "the larger p-value of the one-sided tests"),
+    LOG_P,
+    field("confidence_interval", "(f64, f64)", "the interval at 1 - 2 * significance_level"),
+    HYPOTHESES, OMITTED,
+];
+const PERMUTATION_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("statistic", "f64", "the statistic of the observed data"),
+    P,
+    field("permutations", "usize", "the number of relabellings the statistic was evaluated on"),
+    field("exact", "bool", "whether every relabelling was evaluated, rather than random ones"),
+    field("standard_error", "f64", "the Monte Carlo standard error of the p-value, or 0 if exact"),
+    HYPOTHESES, OMITTED,
+];
+const INTERVAL_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("estimate", "f64", "the statistic of the observed data"),
+    field("bias", "f64", "the mean of the resampled statistics minus the estimate"),
+    field("standard_error", "f64", "the standard deviation of the resampled statistics"),
+    field("confidence_level", "f64", "the level of the interval"),
+    field("confidence_interval", "(f64, f64)", "the interval of the statistic"),
+    field("resamples", "usize", "the number of resamples drawn"),
+    field("skipped", "usize", "resamples left out of a studentized interval for lacking a standard error"),
+    OMITTED,
+];
+const BOOTSTRAP_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("statistic", "f64", "the T statistic of the observed data"),
+    P,
+    field("resamples", "usize", "the number of resamples drawn under the null hypothesis"),
+    field("standard_error", "f64", "the Monte Carlo standard error of the p-value"),
+    HYPOTHESES, OMITTED,
+];
+const BAYES_FACTOR_FIELDS: &[Field] = &[
+    TEST_TYPE, STATISTIC,
+    field("df", "f64", "the degrees of freedom"),
+    field("bf10", "f64", "the Bayes factor in favour of the alternative hypothesis"),
+    field("log_bf10", "f64", "the natural logarithm of the Bayes factor, accurate when it overflows"),
+    field("prior_scale", "f64", "the scale of the Cauchy prior on δ"),
+    field("hypotheses", "String", "the null hypothesis and the prior under the alternative, e.g. H₀: δ = 0 vs H₁: δ ~ Cauchy(0, 0.707)"),
+    OMITTED,
+];
+const COMPARISON_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("estimate_a", "f64", "the posterior mean of A"),
+    field("estimate_b", "f64", "the posterior mean of B"),
+    field("credible_interval_a", "(f64, f64)", "the equal-tailed credible interval of A"),
+    field("credible_interval_b", "(f64, f64)", "the equal-tailed credible interval of B"),
+    field("credible_level", "f64", "the level of the credible intervals"),
+    field("probability_b_better", "f64", "the posterior probability that B exceeds A"),
+    field("expected_loss_a", "f64", "the expected amount by which B exceeds A, lost by choosing A"),
+    field("expected_loss_b", "f64", "the expected amount by which A exceeds B, lost by choosing B"),
+    OMITTED,
+];
+const POSTERIOR_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("estimate", "f64", "the posterior mean of μ"),
+    field("credible_interval", "(f64, f64)", "the equal-tailed credible interval of μ"),
+    field("credible_level", "f64", "the level of the credible interval"),
+    field("posterior", "NormalGamma", "the parameters of the posterior"),
+    OMITTED,
+];
+const MULTIPLE_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("adjusted", "Vec<f64>", "the adjusted p-values, in the original order"),
+    field("reject", "Vec<bool>", "whether each hypothesis is rejected"),
+    field("level", "f64", "the level the adjusted p-values are compared with"),
+];
+const QVALUE_FIELDS: &[Field] = &[
+    TEST_TYPE,
+    field("pi0", "f64", "the estimated proportion of true null hypotheses"),
+    field("q", "Vec<f64>", "the q-values, in the original order"),
+    field("reject", "Vec<bool>", "whether each hypothesis is rejected"),
+    field("level", "f64", "the level the q-values are compared with"),
+];
+
+const INDEPENDENT: &str = "Observations are independent";
+const INDEPENDENT_SAMPLES: &str = "The two samples are independent of each other";
+const LARGE_SAMPLE: &str = "The sample is large enough for its standard deviation to stand in for the population's";
+const NORMAL_MEAN: &str = "The data is normally distributed, or the sample is large enough for its mean to be";
+const NORMAL_DIFFERENCES: &str = "The differences are normally distributed, or there are enough pairs for their mean to be";
+const INDEPENDENT_PAIRS: &str = "Pairs are independent of each other";
+const CONTINUOUS: &str = "The data comes from a continuous distribution, so that ties are rare";
+const INDEPENDENT_GROUPS: &str = "Observations are independent within and between groups";
+const INDEPENDENT_TRIALS: &str = "Trials are independent and share the same probability of success";
+const POISSON_PROCESS: &str = "Events occur independently and at a constant rate over the exposure";
+const INDEPENDENT_COUNTS: &str = "The two counts are independent of each other";
+const LARGE_COUNTS: &str = "Counts are large enough for a normal approximation";
+const EXCHANGEABLE: &str = "Under the null hypothesis, the observations are exchangeable between the samples";
+const REPRESENTATIVE: &str = "The sample is large enough to represent the distribution it was drawn from";
+const NORMAL_DATA: &str = "The data is normally distributed";
+const WEIGHTS_NON_NEGATIVE: &str = "Weights are non-negative, and frequency weights count how often each observation occurred";
+
+/// Every module of tests, in the order of `help`.
+pub const MODULES: &[Module] = &[
+    Module { name: "z", title: "Z-Test for Mean" },
+    Module { name: "t", title: "T-Test for Mean" },
+    Module { name: "chisquare", title: "Pearson's Chi-squared Test" },
+    Module { name: "normality", title: "Normality Tests" },
+    Module { name: "ks", title: "Kolmogorov-Smirnov Tests" },
+    Module { name: "homogeneity", title: "Homogeneity of Variance Tests" },
+    Module { name: "correlation", title: "Correlation Tests" },
+    Module { name: "regression", title: "Linear Regression" },
+    Module { name: "binomial", title: "Exact Binomial Test" },
+    Module { name: "poisson", title: "Poisson Rate Tests" },
+    Module { name: "equivalence", title: "Equivalence, Non-Inferiority, and Superiority Tests" },
+    Module { name: "permutation", title: "Permutation Tests" },
+    Module { name: "bootstrap", title: "Bootstrap Intervals and Tests" },
+    Module { name: "bayes", title: "Bayesian Tests" },
+    Module { name: "multiple", title: "Multiple Testing Correction" },
+];
+
+/// Every test in `hyte`, grouped by module in the order of `MODULES`.
+pub const TESTS: &[Test] = &[
+    Test {
+        module: "z",
+        function: "test",
+        summary: "1-sample Z-test for a mean",
+        parameters: &[DATA, EXPECTED_MEAN, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT, LARGE_SAMPLE],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "z",
+        function: "test_stats",
+        summary: "1-sample Z-test for a mean, given a streamed or merged accumulator",
+        parameters: &[STATS, SUMMARY_EXPECTED_MEAN, ALTERNATIVE],
+        assumptions: &[INDEPENDENT, LARGE_SAMPLE],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "z",
+        function: "test_weighted",
+        summary: "1-sample Z-test for a weighted mean",
+        parameters: &[DATA, WEIGHTS, KIND, EXPECTED_MEAN, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT, WEIGHTS_NON_NEGATIVE, "The effective sample size is large"],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "z",
+        function: "test_two_samples_weighted",
+        summary: "2-sample Z-test for a difference between weighted means",
+        parameters: &[DATA1, WEIGHTS1, DATA2, WEIGHTS2, KIND, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT_SAMPLES, WEIGHTS_NON_NEGATIVE, "The effective sample sizes are large"],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "z",
+        function: "test_dataless",
+        summary: "1-sample Z-test for a mean, given numerical summaries",
+        parameters: &[OBSERVED_MEAN, SUMMARY_EXPECTED_MEAN, SAMPLE_SIZE, POP_SD, ALTERNATIVE],
+        assumptions: &[INDEPENDENT, "The population standard deviation is known", NORMAL_MEAN],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test",
+        summary: "1-sample T-test for a mean",
+        parameters: &[DATA, EXPECTED_MEAN, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT, NORMAL_MEAN],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test_stats",
+        summary: "1-sample T-test for a mean, given a streamed or merged accumulator",
+        parameters: &[STATS, SUMMARY_EXPECTED_MEAN, ALTERNATIVE],
+        assumptions: &[INDEPENDENT, NORMAL_MEAN],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test_weighted",
+        summary: "1-sample T-test for a weighted mean",
+        parameters: &[DATA, WEIGHTS, KIND, EXPECTED_MEAN, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT, WEIGHTS_NON_NEGATIVE, NORMAL_MEAN],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test_dataless",
+        summary: "1-sample T-test for a mean, given numerical summaries",
+        parameters: &[OBSERVED_MEAN, SUMMARY_EXPECTED_MEAN, SAMPLE_SIZE, parameter("pop_sd", "Number: ToPrimitive", Shape::Scalar, "the standard deviation of the sample"), ALTERNATIVE],
+        assumptions: &[INDEPENDENT, NORMAL_MEAN],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test_two_samples",
+        summary: "Welch's 2-sample T-test for a difference between means",
+        parameters: &[DATA1, DATA2, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, "Each sample is normally distributed, or large enough for its mean to be", "Variances need not be equal"],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test_two_samples_stats",
+        summary: "Welch's 2-sample T-test for a difference between means, given streamed or merged accumulators",
+        parameters: &[STATS1, STATS2, ALTERNATIVE],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, "Each sample is normally distributed, or large enough for its mean to be", "Variances need not be equal"],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test_paired",
+        summary: "Paired T-test for the mean of the differences data1 - data2",
+        parameters: &[
+            parameter("data1", SAMPLE_TYPE, Shape::Sample, "the first observation of each pair"),
+            parameter("data2", SAMPLE_TYPE, Shape::Sample, "the second observation of each pair, at the same index"),
+            ALTERNATIVE,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT_PAIRS, NORMAL_DIFFERENCES],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "t",
+        function: "test_two_samples_weighted",
+        summary: "Welch's 2-sample T-test for a difference between weighted means",
+        parameters: &[DATA1, WEIGHTS1, DATA2, WEIGHTS2, KIND, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT_SAMPLES, WEIGHTS_NON_NEGATIVE, "Each sample is normally distributed, or large enough for its mean to be"],
+        result: "TResult",
+        fields: T_FIELDS,
+    },
+    Test {
+        module: "chisquare",
+        function: "test",
+        summary: "Pearson's Chi-squared test of independence or goodness of fit",
+        parameters: &[
+            parameter("test_type", "&str", Shape::Choice, "\"toi\" (test of independence) or \"gof\" (goodness of fit)"),
+            parameter("observed_matrix", "Matrix<Row>, where Row: AsRef<[Number]> and Number: Observation", Shape::Matrix, "Matrix::TwoDimensional for \"toi\", or Matrix::OneDimensional for \"gof\""),
+            parameter("gof_probabilities", "Option<&[f64]>", Shape::Sequence, "the expected probability of each count; None iff test_type is \"toi\""),
+            MISSING,
+        ],
+        assumptions: &["Observations are independent and each is counted once", "Expected counts are at least 5 in most cells"],
+        result: "ChiSquareResult",
+        fields: CHI_SQUARE_FIELDS,
+    },
+    Test {
+        module: "normality",
+        function: "shapiro_wilk",
+        summary: "Shapiro-Wilk test for normality",
+        parameters: &[DATA, MISSING],
+        assumptions: &[INDEPENDENT, CONTINUOUS],
+        result: "NormalityResult",
+        fields: NORMALITY_FIELDS,
+    },
+    Test {
+        module: "normality",
+        function: "anderson_darling",
+        summary: "Anderson-Darling test for normality",
+        parameters: &[DATA, MISSING],
+        assumptions: &[INDEPENDENT, CONTINUOUS],
+        result: "NormalityResult",
+        fields: NORMALITY_FIELDS,
+    },
+    Test {
+        module: "normality",
+        function: "dagostino_pearson",
+        summary: "D'Agostino-Pearson test for normality",
+        parameters: &[DATA, MISSING],
+        assumptions: &[INDEPENDENT, "The sample is large enough for the skewness and kurtosis statistics to be approximately normal"],
+        result: "NormalityResult",
+        fields: NORMALITY_FIELDS,
+    },
+    Test {
+        module: "normality",
+        function: "jarque_bera",
+        summary: "Jarque-Bera test for normality",
+        parameters: &[DATA, MISSING],
+        assumptions: &[INDEPENDENT, "The sample is large, as the statistic is only asymptotically Chi-squared"],
+        result: "NormalityResult",
+        fields: NORMALITY_FIELDS,
+    },
+    Test {
+        module: "ks",
+        function: "test",
+        summary: "1-sample Kolmogorov-Smirnov test against a fully specified distribution",
+        parameters: &[
+            DATA,
+            parameter("distribution", "&dyn ContinuousCDF<f64, f64>", Shape::Distribution, "any statrs continuous distribution"),
+            ALTERNATIVE,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, CONTINUOUS, "The distribution is specified without estimating its parameters from the data"],
+        result: "KSResult",
+        fields: KS_FIELDS,
+    },
+    Test {
+        module: "ks",
+        function: "test_two_samples",
+        summary: "2-sample Kolmogorov-Smirnov test",
+        parameters: &[DATA1, DATA2, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, CONTINUOUS],
+        result: "KSResult",
+        fields: KS_FIELDS,
+    },
+    Test {
+        module: "ks",
+        function: "lilliefors",
+        summary: "Lilliefors test for normality",
+        parameters: &[DATA, MISSING],
+        assumptions: &[INDEPENDENT, CONTINUOUS],
+        result: "KSResult",
+        fields: KS_FIELDS,
+    },
+    Test {
+        module: "homogeneity",
+        function: "levene",
+        summary: "Levene's test for homogeneity of variance",
+        parameters: &[GROUPS, MISSING],
+        assumptions: &[INDEPENDENT_GROUPS, "Groups are roughly symmetric, as deviations are taken from the mean"],
+        result: "VarianceResult",
+        fields: VARIANCE_FIELDS,
+    },
+    Test {
+        module: "homogeneity",
+        function: "brown_forsythe",
+        summary: "Brown-Forsythe test for homogeneity of variance",
+        parameters: &[GROUPS, MISSING],
+        assumptions: &[INDEPENDENT_GROUPS],
+        result: "VarianceResult",
+        fields: VARIANCE_FIELDS,
+    },
+    Test {
+        module: "homogeneity",
+        function: "bartlett",
+        summary: "Bartlett's test for homogeneity of variance",
+        parameters: &[GROUPS, MISSING],
+        assumptions: &[INDEPENDENT_GROUPS, "Each group is normally distributed"],
+        result: "VarianceResult",
+        fields: VARIANCE_FIELDS,
+    },
+    Test {
+        module: "homogeneity",
+        function: "fligner_killeen",
+        summary: "Fligner-Killeen test for homogeneity of variance",
+        parameters: &[GROUPS, MISSING],
+        assumptions: &[INDEPENDENT_GROUPS],
+        result: "VarianceResult",
+        fields: VARIANCE_FIELDS,
+    },
+    Test {
+        module: "correlation",
+        function: "pearson",
+        summary: "Pearson's product-moment correlation test",
+        parameters: &[X, Y, ALTERNATIVE, CONFIDENCE_LEVEL, MISSING],
+        assumptions: &[INDEPENDENT_PAIRS, "x and y are bivariate normal", "The relationship is linear"],
+        result: "CorrelationResult",
+        fields: CORRELATION_FIELDS,
+    },
+    Test {
+        module: "correlation",
+        function: "spearman",
+        summary: "Spearman's rank correlation test",
+        parameters: &[X, Y, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT_PAIRS, "The relationship is monotonic"],
+        result: "CorrelationResult",
+        fields: CORRELATION_FIELDS,
+    },
+    Test {
+        module: "correlation",
+        function: "kendall",
+        summary: "Kendall's tau-b rank correlation test",
+        parameters: &[X, Y, ALTERNATIVE, MISSING],
+        assumptions: &[INDEPENDENT_PAIRS, "The relationship is monotonic"],
+        result: "CorrelationResult",
+        fields: CORRELATION_FIELDS,
+    },
+    Test {
+        module: "correlation",
+        function: "compare_independent",
+        summary: "Z-test for the difference between two independent correlations",
+        parameters: &[
+            parameter("r1", "f64", Shape::Scalar, "Pearson's r of the first sample"),
+            parameter("n1", "u32", Shape::Count, "the size of the first sample"),
+            parameter("r2", "f64", Shape::Scalar, "Pearson's r of the second sample"),
+            parameter("n2", "u32", Shape::Count, "the size of the second sample"),
+            ALTERNATIVE,
+        ],
+        assumptions: &[INDEPENDENT_SAMPLES, "Each pair of variables is bivariate normal"],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "regression",
+        function: "linear",
+        summary: "Ordinary least squares regression",
+        parameters: &[
+            parameter("predictors", "impl AsRef<[Column]>, where Column: AsRef<[Number]>", Shape::Samples, "one column per predictor; an intercept is always added"),
+            parameter("response", SAMPLE_TYPE, Shape::Sample, "the response of each observation"),
+            CONFIDENCE_LEVEL,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, "The response is linear in the coefficients", "Errors have a constant variance (see breusch_pagan)", "Errors are normally distributed"],
+        result: "RegressionResult",
+        fields: REGRESSION_FIELDS,
+    },
+    Test {
+        module: "regression",
+        function: "simple",
+        summary: "Ordinary least squares regression on a single predictor",
+        parameters: &[
+            parameter("x", SAMPLE_TYPE, Shape::Sample, "the predictor"),
+            parameter("y", SAMPLE_TYPE, Shape::Sample, "the response"),
+            CONFIDENCE_LEVEL,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, "The response is linear in the predictor", "Errors have a constant variance (see breusch_pagan)", "Errors are normally distributed"],
+        result: "RegressionResult",
+        fields: REGRESSION_FIELDS,
+    },
+    Test {
+        module: "binomial",
+        function: "test",
+        summary: "Exact binomial test",
+        parameters: BINOMIAL,
+        assumptions: &[INDEPENDENT_TRIALS],
+        result: "BinomialResult",
+        fields: BINOMIAL_FIELDS,
+    },
+    Test {
+        module: "binomial",
+        function: "mid_p_test",
+        summary: "Exact binomial test with a mid-p-value",
+        parameters: BINOMIAL,
+        assumptions: &[INDEPENDENT_TRIALS],
+        result: "BinomialResult",
+        fields: BINOMIAL_FIELDS,
+    },
+    Test {
+        module: "poisson",
+        function: "test",
+        summary: "Exact test of a Poisson rate",
+        parameters: &[COUNT, EXPOSURE, RATE, ALTERNATIVE, CONFIDENCE_LEVEL],
+        assumptions: &[POISSON_PROCESS],
+        result: "PoissonResult",
+        fields: POISSON_FIELDS,
+    },
+    Test {
+        module: "poisson",
+        function: "compare",
+        summary: "Exact conditional test for the ratio of two Poisson rates",
+        parameters: &[COUNT1, EXPOSURE1, COUNT2, EXPOSURE2, ALTERNATIVE, CONFIDENCE_LEVEL],
+        assumptions: &[POISSON_PROCESS, INDEPENDENT_COUNTS],
+        result: "PoissonResult",
+        fields: POISSON_FIELDS,
+    },
+    Test {
+        module: "poisson",
+        function: "z_test",
+        summary: "Wald or score Z-test of a Poisson rate",
+        parameters: &[COUNT, EXPOSURE, RATE, APPROXIMATION, ALTERNATIVE],
+        assumptions: &[POISSON_PROCESS, LARGE_COUNTS],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "poisson",
+        function: "compare_z",
+        summary: "Wald or score Z-test for the ratio of two Poisson rates",
+        parameters: &[COUNT1, EXPOSURE1, COUNT2, EXPOSURE2, APPROXIMATION, ALTERNATIVE],
+        assumptions: &[POISSON_PROCESS, INDEPENDENT_COUNTS, LARGE_COUNTS],
+        result: "ZResult",
+        fields: Z_FIELDS,
+    },
+    Test {
+        module: "equivalence",
+        function: "t_test",
+        summary: "Equivalence, non-inferiority, or superiority T-test of one sample",
+        parameters: &[DATA, EXPECTED_MEAN, HYPOTHESIS, SIGNIFICANCE_LEVEL, MISSING],
+        assumptions: &[INDEPENDENT, NORMAL_MEAN],
+        result: "EquivalenceResult",
+        fields: EQUIVALENCE_FIELDS,
+    },
+    Test {
+        module: "equivalence",
+        function: "paired_t_test",
+        summary: "Equivalence, non-inferiority, or superiority T-test of paired differences",
+        parameters: &[DATA1, DATA2, HYPOTHESIS, SIGNIFICANCE_LEVEL, MISSING],
+        assumptions: &[INDEPENDENT_PAIRS, NORMAL_DIFFERENCES],
+        result: "EquivalenceResult",
+        fields: EQUIVALENCE_FIELDS,
+    },
+    Test {
+        module: "equivalence",
+        function: "t_test_two_samples",
+        summary: "Equivalence, non-inferiority, or superiority T-test of two samples",
+        parameters: &[
+            DATA1,
+            DATA2,
+            HYPOTHESIS,
+            parameter("equal_variances", "bool", Shape::Flag, "pool the variances (Student) rather than not (Welch)"),
+            SIGNIFICANCE_LEVEL,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, "Each sample is normally distributed, or large enough for its mean to be"],
+        result: "EquivalenceResult",
+        fields: EQUIVALENCE_FIELDS,
+    },
+    Test {
+        module: "equivalence",
+        function: "proportions",
+        summary: "Equivalence, non-inferiority, or superiority Z-test of two proportions",
+        parameters: &[
+            parameter("successes1", "u64", Shape::Count, "the number of successes in the first sample"),
+            parameter("trials1", "u64", Shape::Count, "the number of trials in the first sample"),
+            parameter("successes2", "u64", Shape::Count, "the number of successes in the second sample"),
+            parameter("trials2", "u64", Shape::Count, "the number of trials in the second sample"),
+            HYPOTHESIS,
+            SIGNIFICANCE_LEVEL,
+        ],
+        assumptions: &[INDEPENDENT_TRIALS, INDEPENDENT_SAMPLES, LARGE_COUNTS],
+        result: "EquivalenceResult",
+        fields: EQUIVALENCE_FIELDS,
+    },
+    Test {
+        module: "permutation",
+        function: "test",
+        summary: "Permutation test of a user-defined statistic of two or more samples",
+        parameters: &[
+            parameter("samples", "&[Sample], where Sample: AsRef<[Number]>", Shape::Samples, "at least 2 samples, e.g. &[control, treatment]"),
+            parameter("statistic", "impl Fn(&[&[f64]]) -> f64 + Sync", Shape::Statistic, "computes the statistic from the samples, in order"),
+            ALTERNATIVE,
+            RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, EXCHANGEABLE],
+        result: "PermutationResult",
+        fields: PERMUTATION_FIELDS,
+    },
+    Test {
+        module: "permutation",
+        function: "test_stratified",
+        summary: "Permutation test of a user-defined statistic, exchanging observations only within strata",
+        parameters: &[
+            parameter("samples", "&[Sample], where Sample: AsRef<[Number]>", Shape::Samples, "at least 2 samples, e.g. &[control, treatment]"),
+            parameter("strata", "&[Strata], where Strata: AsRef<[usize]>", Shape::Samples, "the stratum of every observation, in the same shape as samples"),
+            parameter("statistic", "impl Fn(&[&[f64]]) -> f64 + Sync", Shape::Statistic, "computes the statistic from the samples, in order"),
+            ALTERNATIVE,
+            RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, "Under the null hypothesis, the observations are exchangeable between the samples within each stratum"],
+        result: "PermutationResult",
+        fields: PERMUTATION_FIELDS,
+    },
+    Test {
+        module: "permutation",
+        function: "test_paired",
+        summary: "Paired permutation test of a user-defined statistic, swapping the observations of each pair",
+        parameters: &[
+            DATA1,
+            DATA2,
+            parameter("statistic", "impl Fn(&[f64], &[f64]) -> f64 + Sync", Shape::Statistic, "computes the statistic from the first and second observations of each pair"),
+            ALTERNATIVE,
+            RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT_PAIRS, "Under the null hypothesis, the two observations of each pair are exchangeable"],
+        result: "PermutationResult",
+        fields: PERMUTATION_FIELDS,
+    },
+    Test {
+        module: "permutation",
+        function: "test_sign_flip",
+        summary: "Sign-flip permutation test of a user-defined statistic, reflecting observations about the null value",
+        parameters: &[
+            DATA,
+            parameter("statistic", "impl Fn(&[f64]) -> f64 + Sync", Shape::Statistic, "computes the statistic from the data"),
+            parameter("alternative", "impl Into<Alternative>", Shape::Choice, "e.g. Tails::BOTH or Alternative::greater(0.0); the null value defaults to 0"),
+            RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, "Under the null hypothesis, the data is symmetric about the null value"],
+        result: "PermutationResult",
+        fields: PERMUTATION_FIELDS,
+    },
+    Test {
+        module: "bootstrap",
+        function: "interval",
+        summary: "Bootstrap confidence interval of a user-defined statistic, resampling each sample independently",
+        parameters: &[
+            parameter("samples", "&[Sample], where Sample: AsRef<[Number]>", Shape::Samples, "e.g. &[data] or &[revenue, sessions]"),
+            BOOTSTRAP_STATISTIC,
+            BOOTSTRAP_INTERVAL,
+            CONFIDENCE_LEVEL,
+            BOOTSTRAP_RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, REPRESENTATIVE],
+        result: "IntervalResult",
+        fields: INTERVAL_FIELDS,
+    },
+    Test {
+        module: "bootstrap",
+        function: "interval_blocks",
+        summary: "Moving block bootstrap confidence interval of a user-defined statistic of a time series",
+        parameters: &[
+            parameter("data", SAMPLE_TYPE, Shape::Sample, "the series, in order of time"),
+            parameter("block_length", "usize", Shape::Count, "the number of consecutive observations in each block, less than the length of the series"),
+            parameter("statistic", "impl Fn(&[f64]) -> f64 + Sync", Shape::Statistic, "computes the statistic from the series"),
+            BOOTSTRAP_INTERVAL,
+            CONFIDENCE_LEVEL,
+            BOOTSTRAP_RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &["The series is stationary", "Observations further apart than the block length are nearly independent", REPRESENTATIVE],
+        result: "IntervalResult",
+        fields: INTERVAL_FIELDS,
+    },
+    Test {
+        module: "bootstrap",
+        function: "interval_clusters",
+        summary: "Cluster bootstrap confidence interval of a user-defined statistic, resampling whole clusters",
+        parameters: &[
+            parameter("samples", "&[Sample], where Sample: AsRef<[Number]>", Shape::Samples, "e.g. &[data] or &[revenue, sessions]"),
+            parameter("clusters", "&[Clusters], where Clusters: AsRef<[usize]>", Shape::Samples, "the cluster of every observation, in the same shape as samples"),
+            BOOTSTRAP_STATISTIC,
+            BOOTSTRAP_INTERVAL,
+            CONFIDENCE_LEVEL,
+            BOOTSTRAP_RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &["Clusters are independent of each other, while observations within a cluster may be correlated", "There are enough clusters to represent the population of clusters"],
+        result: "IntervalResult",
+        fields: INTERVAL_FIELDS,
+    },
+    Test {
+        module: "bootstrap",
+        function: "t_test",
+        summary: "Bootstrap-t test for the mean of a sample",
+        parameters: &[DATA, EXPECTED_MEAN, ALTERNATIVE, BOOTSTRAP_RESAMPLING, MISSING],
+        assumptions: &[INDEPENDENT, REPRESENTATIVE],
+        result: "BootstrapResult",
+        fields: BOOTSTRAP_FIELDS,
+    },
+    Test {
+        module: "bootstrap",
+        function: "t_test_two_samples",
+        summary: "Bootstrap-t test for the difference between the means of two samples",
+        parameters: &[
+            DATA1,
+            DATA2,
+            parameter("alternative", "impl Into<Alternative>", Shape::Choice, "e.g. Tails::BOTH or Alternative::greater(0.0); the null difference defaults to 0"),
+            BOOTSTRAP_RESAMPLING,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, "The two samples differ only in location under the null hypothesis", REPRESENTATIVE],
+        result: "BootstrapResult",
+        fields: BOOTSTRAP_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "t_test",
+        summary: "JZS Bayes factor for the mean of a sample",
+        parameters: &[DATA, EXPECTED_MEAN, PRIOR_SCALE, MISSING],
+        assumptions: &[INDEPENDENT, NORMAL_DATA],
+        result: "BayesFactorResult",
+        fields: BAYES_FACTOR_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "t_test_paired",
+        summary: "JZS Bayes factor for the mean difference of paired observations",
+        parameters: &[
+            parameter("data1", SAMPLE_TYPE, Shape::Sample, "the first observation of each pair"),
+            parameter("data2", SAMPLE_TYPE, Shape::Sample, "the second observation of each pair"),
+            PRIOR_SCALE,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT_PAIRS, "The differences are normally distributed"],
+        result: "BayesFactorResult",
+        fields: BAYES_FACTOR_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "t_test_two_samples",
+        summary: "JZS Bayes factor for the difference between the means of two samples",
+        parameters: &[DATA1, DATA2, PRIOR_SCALE, MISSING],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, NORMAL_DATA, "The two samples share the same variance"],
+        result: "BayesFactorResult",
+        fields: BAYES_FACTOR_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "t_test_dataless",
+        summary: "JZS Bayes factor of a 1-sample or paired T statistic",
+        parameters: &[
+            parameter("statistic", "f64", Shape::Scalar, "the T statistic, e.g. from t::test"),
+            parameter("sample_size", "impl ToPrimitive", Shape::Count, "the number of observations or pairs"),
+            PRIOR_SCALE,
+        ],
+        assumptions: &[INDEPENDENT, NORMAL_DATA],
+        result: "BayesFactorResult",
+        fields: BAYES_FACTOR_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "t_test_two_samples_dataless",
+        summary: "JZS Bayes factor of a pooled-variance 2-sample T statistic",
+        parameters: &[
+            parameter("statistic", "f64", Shape::Scalar, "Student's T statistic, with the pooled variance"),
+            parameter("sample_size1", "impl ToPrimitive", Shape::Count, "the size of the first sample"),
+            parameter("sample_size2", "impl ToPrimitive", Shape::Count, "the size of the second sample"),
+            PRIOR_SCALE,
+        ],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, NORMAL_DATA, "The two samples share the same variance"],
+        result: "BayesFactorResult",
+        fields: BAYES_FACTOR_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "beta_binomial",
+        summary: "Beta-Binomial comparison of the proportions of successes of two variants",
+        parameters: &[
+            parameter("successes_a", "u64", Shape::Count, "the number of successes of A"),
+            parameter("trials_a", "u64", Shape::Count, "the number of trials of A"),
+            parameter("successes_b", "u64", Shape::Count, "the number of successes of B"),
+            parameter("trials_b", "u64", Shape::Count, "the number of trials of B"),
+            parameter("prior", "(f64, f64)", Shape::Distribution, "the α and β of the Beta prior of both proportions, e.g. (1.0, 1.0)"),
+            CREDIBLE_LEVEL,
+        ],
+        assumptions: &[INDEPENDENT_TRIALS, "The two variants are independent of each other"],
+        result: "ComparisonResult",
+        fields: COMPARISON_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "normal_gamma",
+        summary: "Normal-Gamma posterior of the mean of a sample",
+        parameters: &[DATA, NORMAL_GAMMA, CREDIBLE_LEVEL, MISSING],
+        assumptions: &[INDEPENDENT, NORMAL_DATA],
+        result: "PosteriorResult",
+        fields: POSTERIOR_FIELDS,
+    },
+    Test {
+        module: "bayes",
+        function: "normal_gamma_compare",
+        summary: "Normal-Gamma comparison of the means of two variants",
+        parameters: &[
+            parameter("data_a", SAMPLE_TYPE, Shape::Sample, "the sample of A"),
+            parameter("data_b", SAMPLE_TYPE, Shape::Sample, "the sample of B"),
+            NORMAL_GAMMA,
+            CREDIBLE_LEVEL,
+            MISSING,
+        ],
+        assumptions: &[INDEPENDENT, INDEPENDENT_SAMPLES, "Each sample is normally distributed, with its own variance"],
+        result: "ComparisonResult",
+        fields: COMPARISON_FIELDS,
+    },
+    Test {
+        module: "multiple",
+        function: "adjust",
+        summary: "Adjust p-values for multiple testing",
+        parameters: &[
+            parameter("p_values", "&[f64]", Shape::Sequence, "the p-value of each hypothesis"),
+            parameter("method", "Correction", Shape::Choice, "Correction::Bonferroni, Holm, Hochberg, BenjaminiHochberg or BenjaminiYekutieli"),
+            parameter("level", "f64", Shape::Scalar, "the FWER or FDR to control"),
+        ],
+        assumptions: &["Bonferroni, Holm, and Benjamini-Yekutieli hold under any dependence between the tests", "Hochberg and Benjamini-Hochberg need independent or positively dependent tests"],
+        result: "MultipleResult",
+        fields: MULTIPLE_FIELDS,
+    },
+    Test {
+        module: "multiple",
+        function: "adjust_results",
+        summary: "Adjust the p-values of test results for multiple testing",
+        parameters: &[
+            parameter("results", "&[R], where R: PValue", Shape::Results, "e.g. a slice of ZResult"),
+            parameter("method", "Correction", Shape::Choice, "Correction::Bonferroni, Holm, Hochberg, BenjaminiHochberg or BenjaminiYekutieli"),
+            parameter("level", "f64", Shape::Scalar, "the FWER or FDR to control"),
+        ],
+        assumptions: &["Bonferroni, Holm, and Benjamini-Yekutieli hold under any dependence between the tests", "Hochberg and Benjamini-Hochberg need independent or positively dependent tests"],
+        result: "MultipleResult",
+        fields: MULTIPLE_FIELDS,
+    },
+    Test {
+        module: "multiple",
+        function: "qvalues",
+        summary: "Storey's q-values",
+        parameters: &[
+            parameter("p_values", "&[f64]", Shape::Sequence, "the p-value of each hypothesis"),
+            parameter("lambda", "f64", Shape::Scalar, "the tuning parameter in [0, 1) used to estimate pi0; 0.5 is common"),
+            parameter("level", "f64", Shape::Scalar, "the FDR to control"),
+        ],
+        assumptions: &["The tests are independent or weakly dependent", "p-values of true null hypotheses are uniformly distributed"],
+        result: "QValueResult",
+        fields: QVALUE_FIELDS,
+    },
+];
+
+/// Returns the test at `name`, such as `t::test_paired`.
+pub fn find(name: &str) -> Option<&'static Test> {
+    let (module, function) = name.split_once("::")?;
+    TESTS.iter().find(|test| test.module == module && test.function == function)
+}
+
+/// Returns the tests of `module`, in order.
+pub fn tests(module: &str) -> impl Iterator<Item = &'static Test> + '_ {
+    TESTS.iter().filter(move |test| test.module == module)
+}
+
+/// Returns the section of `help` describing every test, with the fields of each result listed where it first appears.
+pub fn manual() -> String {
+    let mut manual = String::new();
+    let mut described: Vec<&str> = Vec::new();
+    for module in MODULES {
+        manual.push_str(&format!("➤  {}\n", module.title));
+        for test in tests(module.name) {
+            manual.push_str(&format!("\n    ➜  (FN) hyte::{}\n            <>\n            Intended use: {}.\n            <>\n            args:\n", test.name(), test.summary));
+            for (index, parameter) in test.parameters.iter().enumerate() {
+                manual.push_str(&format!("                {}. {}: {}; {}\n", index + 1, parameter.name, parameter.rust_type, parameter.description));
+            }
+            manual.push_str("            assumptions:\n");
+            for assumption in test.assumptions {
+                manual.push_str(&format!("                - {}\n", assumption));
+            }
+            manual.push_str(&format!("            returns: Option<{}>\n", test.result));
+            if !described.contains(&test.result) {
+                described.push(test.result);
+                manual.push_str("                Fields:\n");
+                for field in test.fields {
+                    manual.push_str(&format!("                    - {}: {}, ({})\n", field.name, field.rust_type, field.description));
+                }
+            }
+        }
+        manual.push('\n');
+    }
+    manual
+}
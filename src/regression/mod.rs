@@ -0,0 +1,341 @@
+//! Module responsible for all items needed to fit linear regressions and test their coefficients.
+
+use statrs::distribution::{ContinuousCDF, StudentsT};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Tails, Conclusion, PValue, conclude, chi_squared_tail, f_tail, t_tail, symmetric_p, MissingPolicy, Observation, clean_rows, TestType};
+
+/// A struct for storing the estimate and T-test of a single regression coefficient.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Coefficient {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub estimate: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub standard_error: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "(crate::utils::non_finite::Float, crate::utils::non_finite::Float)"))]
+    pub confidence_interval: (f64, f64),
+}
+
+/// A struct for storing the Breusch-Pagan test for heteroscedasticity of the residuals.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BreuschPagan {
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    pub df: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+}
+
+/// A struct for storing a fitted ordinary least squares regression.
+///
+/// `coefficients[0]` is the intercept, followed by one coefficient per predictor in the order they were passed in.
+/// `statistic`, `df1`, `df2`, `p`, and `log_p` (the natural logarithm of `p`) describe the overall F-test
+/// that every slope is zero.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RegressionResult {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    pub coefficients: Vec<Coefficient>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub r_squared: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub adjusted_r_squared: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub statistic: f64,
+    pub df1: usize,
+    pub df2: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<crate::utils::non_finite::Float>"))]
+    pub residuals: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub durbin_watson: f64,
+    pub breusch_pagan: BreuschPagan,
+    pub omitted: usize,
+}
+
+/// Test type name of a regression.
+const ORDINARY_LEAST_SQUARES: TestType = "Ordinary Least Squares Regression";
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![[ORDINARY_LEAST_SQUARES]];
+
+/// Inverts a symmetric positive-definite `m` by `m` matrix using Gauss-Jordan elimination with partial pivoting.
+fn invert(matrix: &[f64], m: usize) -> Option<Vec<f64>> {
+    let mut a = matrix.to_vec();
+    let mut inverse = vec![0.0; m * m];
+    for i in 0..m { inverse[i * m + i] = 1.0 };
+
+    let scale = a.iter().fold(0.0_f64, |acc, &x| acc.max(x.abs()));
+    for col in 0..m {
+        let pivot = (col..m).max_by(|&r1, &r2| a[r1 * m + col].abs().total_cmp(&a[r2 * m + col].abs())).unwrap();
+        if a[pivot * m + col].abs() <= 1e-12 * scale { return None };
+        for j in 0..m {
+            a.swap(col * m + j, pivot * m + j);
+            inverse.swap(col * m + j, pivot * m + j);
+        }
+        let diagonal = a[col * m + col];
+        for j in 0..m {
+            a[col * m + j] /= diagonal;
+            inverse[col * m + j] /= diagonal;
+        }
+        for row in 0..m {
+            if row == col { continue };
+            let factor = a[row * m + col];
+            if factor == 0.0 { continue };
+            for j in 0..m {
+                a[row * m + j] -= factor * a[col * m + j];
+                inverse[row * m + j] -= factor * inverse[col * m + j];
+            }
+        }
+    }
+    Some(inverse)
+}
+
+/// Fits `response` on the columns of `design` (row-major, `p` columns), returning the coefficients,
+/// the fitted values, and `(X'X)^-1`.
+fn least_squares(design: &[f64], response: &[f64], p: usize) -> Option<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+    let n = response.len();
+    let mut xtx = vec![0.0; p * p];
+    let mut xty = vec![0.0; p];
+    for row in 0..n {
+        let x = &design[row * p..(row + 1) * p];
+        for i in 0..p {
+            xty[i] += x[i] * response[row];
+            for j in 0..p {
+                xtx[i * p + j] += x[i] * x[j];
+            }
+        }
+    }
+    let xtx_inverse = invert(&xtx, p)?;
+    let beta: Vec<f64> = (0..p).map(|i| (0..p).map(|j| xtx_inverse[i * p + j] * xty[j]).sum()).collect();
+    let fitted: Vec<f64> = (0..n)
+        .map(|row| design[row * p..(row + 1) * p].iter().zip(&beta).map(|(x, b)| x * b).sum())
+        .collect();
+    Some((beta, fitted, xtx_inverse))
+}
+
+fn coefficient_of_determination(response: &[f64], fitted: &[f64]) -> f64 {
+    let n = response.len() as f64;
+    let response_mean = response.iter().sum::<f64>() / n;
+    let total: f64 = response.iter().map(|&y| (y - response_mean).powi(2)).sum();
+    let residual: f64 = response.iter().zip(fitted).map(|(y, f)| (y - f).powi(2)).sum();
+    1.0 - residual / total
+}
+
+/// Fits an ordinary least squares regression of `response` on one or more `predictors` with an intercept.
+/// Each predictor is passed as a column, such as a `Vec<Number>` or `&[Number]`, of the same length as `response`.
+///
+/// For each coefficient, a T-test that it is zero is performed with `n - p` degrees of freedom, where `p`
+/// is the number of coefficients including the intercept, along with a confidence interval at `confidence_level`.
+/// The result also contains R² and adjusted R², the overall F-test, the residuals, the Durbin-Watson
+/// statistic for autocorrelation, and the studentised Breusch-Pagan test for heteroscedasticity.
+///
+/// Missing values are removed listwise under `MissingPolicy::Omit`, so a row is dropped when the response or any predictor is missing.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::regression;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let hours = vec![1, 2, 3, 4, 5, 6, 7, 8];
+///     let sleep = vec![8, 7, 7, 6, 8, 6, 7, 6];
+///     let score = vec![52, 55, 61, 64, 72, 71, 80, 83];
+///     let unwrapped_results = regression::linear(vec![hours, sleep], score, 0.95, MissingPolicy::Propagate);
+///
+///     assert_ne!(unwrapped_results, None);
+///
+///     let results = unwrapped_results.unwrap();
+///     println!("{:#?}", results);
+///
+///     assert_eq!(results.test_type, "Ordinary Least Squares Regression");
+///     assert_eq!(results.coefficients.len(), 3);
+///     assert_eq!((results.df1, results.df2), (2, 5));
+///     assert!(results.r_squared > 0.95);
+///     assert!(results.coefficients[1].p < 0.001);
+/// }
+/// ```
+pub fn linear<Number: Observation, Column: AsRef<[Number]>>(predictors: impl AsRef<[Column]>, response: impl AsRef<[Number]>, confidence_level: f64, missing: MissingPolicy) -> Option<RegressionResult> {
+    let (predictors, response) = (predictors.as_ref(), response.as_ref());
+    if predictors.is_empty() || response.is_empty() { return None };
+    let n = response.len();
+    if predictors.iter().any(|column| column.as_ref().len() != n) {
+        panic!("\n[HYTE-Panic] Every predictor must have the same length as the response ({})!\n", n);
+    }
+    let mut columns: Vec<&[Number]> = predictors.iter().map(AsRef::as_ref).collect();
+    columns.push(response);
+    let (mut columns, omitted) = clean_rows(&columns, missing);
+    let y = columns.pop().unwrap();
+    let n = y.len();
+    let k = predictors.len();
+    let p = k + 1;
+    if n <= p { panic!("\n[HYTE-Panic] Regression requires more observations than coefficients!\n") };
+    if !(0.0..1.0).contains(&confidence_level) { panic!("\n[HYTE-Panic] Confidence level must be in the interval [0, 1)!\n") };
+
+    if y.iter().all(|&v| v == y[0]) { panic!("\n[HYTE-Panic] All values in the response are identical!\n") };
+
+    let mut design = Vec::with_capacity(n * p);
+    for row in 0..n {
+        design.push(1.0);
+        for column in &columns {
+            design.push(column[row]);
+        }
+    }
+
+    let (beta, fitted, xtx_inverse) = least_squares(&design, &y, p)
+        .unwrap_or_else(|| panic!("\n[HYTE-Panic] Predictors must not be perfectly collinear!\n"));
+    let residuals: Vec<f64> = y.iter().zip(&fitted).map(|(y, f)| y - f).collect();
+    let rss: f64 = residuals.iter().map(|e| e * e).sum();
+
+    let df_residual = n - p;
+    let sigma_squared = rss / df_residual as f64;
+    let t_distribution = StudentsT::new(0.0, 1.0, df_residual as f64).unwrap();
+    let critical = t_distribution.inverse_cdf((1.0 + confidence_level) / 2.0);
+    let coefficients: Vec<Coefficient> = beta.iter().enumerate()
+        .map(|(i, &estimate)| {
+            let standard_error = (sigma_squared * xtx_inverse[i * p + i]).sqrt();
+            let statistic = estimate / standard_error;
+            let (p, log_p) = symmetric_p(statistic, &Tails::BOTH, |t| t_tail(t, df_residual as f64));
+            Coefficient {
+                estimate,
+                standard_error,
+                statistic,
+                p,
+                log_p,
+                confidence_interval: (estimate - critical * standard_error, estimate + critical * standard_error),
+            }
+        })
+        .collect();
+
+    let r_squared = coefficient_of_determination(&y, &fitted);
+    let adjusted_r_squared = 1.0 - (1.0 - r_squared) * (n - 1) as f64 / df_residual as f64;
+    let statistic = (r_squared / k as f64) / ((1.0 - r_squared) / df_residual as f64);
+    let (p_value, log_p) = f_tail(statistic, k as f64, df_residual as f64);
+
+    let durbin_watson = residuals.windows(2).map(|pair| (pair[1] - pair[0]).powi(2)).sum::<f64>() / rss;
+
+    // Studentised (Koenker) Breusch-Pagan: n R² from regressing squared residuals on the predictors
+    let squared_residuals: Vec<f64> = residuals.iter().map(|e| e * e).collect();
+    let auxiliary_r_squared = match least_squares(&design, &squared_residuals, p) {
+        Some((_, auxiliary_fitted, _)) if squared_residuals.iter().any(|&e| e != squared_residuals[0]) => coefficient_of_determination(&squared_residuals, &auxiliary_fitted),
+        _ => 0.0,
+    };
+    let bp_statistic = n as f64 * auxiliary_r_squared;
+    let breusch_pagan = BreuschPagan {
+        statistic: bp_statistic,
+        df: k,
+        p: chi_squared_tail(bp_statistic, k as f64).0,
+    };
+
+    let results = RegressionResult {
+        test_type: ORDINARY_LEAST_SQUARES,
+        coefficients,
+        r_squared,
+        adjusted_r_squared,
+        statistic,
+        df1: k,
+        df2: df_residual,
+        p: p_value,
+        log_p,
+        residuals,
+        durbin_watson,
+        breusch_pagan,
+        omitted,
+    };
+
+    Some(results)
+}
+
+/// Fits a simple linear regression of `y` on a single predictor `x`, then calls `regression::linear`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::regression;
+/// use hyte::utils::MissingPolicy;
+///
+/// fn main() {
+///     let x = vec![1, 2, 3, 4, 5, 6, 7, 8];
+///     let y = vec![52, 55, 61, 64, 72, 71, 80, 83];
+///     let results = regression::simple(x, y, 0.95, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!((results.coefficients[1].estimate * 1e4).round() / 1e4, 4.5238);
+///     assert!(results.p < 0.001);
+/// }
+/// ```
+pub fn simple<Number: Observation>(x: impl AsRef<[Number]>, y: impl AsRef<[Number]>, confidence_level: f64, missing: MissingPolicy) -> Option<RegressionResult> {
+    let x = x.as_ref();
+    if x.is_empty() { return None };
+    linear([x], y, confidence_level, missing)
+}
+
+impl RegressionResult {
+    /// Concludes if the overall F-test of a `RegressionResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
+    }
+
+    /// Concludes if the overall F-test of a `RegressionResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for RegressionResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for RegressionResult {
+    fn report(&self) -> Report {
+        let mut summary = Report::new(self.test_type);
+        for (i, coefficient) in self.coefficients.iter().enumerate() {
+            let name = if i == 0 { "(Intercept)".to_string() } else { format!("x{}", i) };
+            summary = summary.row(&name, format!("estimate = {:.4}, SE = {:.4}, t = {:.2}, p-value = {}", coefficient.estimate, coefficient.standard_error, coefficient.statistic, report::p_value(coefficient.p)));
+        }
+        summary
+            .row("R-squared", format!("{:.4}", self.r_squared))
+            .row("Adjusted R-squared", format!("{:.4}", self.adjusted_r_squared))
+            .row("F test statistic", format!("{:.2}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .row("Degrees of freedom", format!("({}, {})", self.df1, self.df2))
+            .row("Durbin-Watson", format!("{:.4}", self.durbin_watson))
+            .row("Breusch-Pagan", format!("{:.4} (p-value = {})", self.breusch_pagan.statistic, report::p_value(self.breusch_pagan.p)))
+            .apa(format!("R² = {}, F({}, {}) = {:.2}, {}", report::bounded(self.r_squared, 2), self.df1, self.df2, self.statistic, report::apa_p(self.p)))
+    }
+}
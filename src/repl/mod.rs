@@ -0,0 +1,491 @@
+//! Module responsible for all items needed to run tests interactively with `hyte repl`.
+//!
+//! A `Session` holds a loaded CSV or TSV file, the result of the previous test, and the p-values of every test run
+//! since the last `clear`, which can be adjusted together with `adjust` or `qvalues`. Tests take the same arguments as
+//! the `hyte` subcommands, and `t a vs b` is a shorthand for `t2 a b`. Every command which changes the session is kept,
+//! so that the session can be exported with `export` and replayed with `hyte repl --script`.
+//!
+//! This module is only available with the `repl` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use hyte::repl::Session;
+//!
+//! fn main() {
+//!     let mut session = Session::new();
+//!     session.execute("load scores.csv").unwrap();
+//!     session.execute("t control vs treatment --tail lower").unwrap();
+//!     session.execute("t control --mean 3").unwrap();
+//!     let adjusted = session.execute("adjust holm").unwrap().unwrap();
+//!
+//!     println!("{}", adjusted);
+//!     println!("{}", session.script());
+//! }
+//! ```
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::panic::{self, AssertUnwindSafe};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use clap::error::ErrorKind;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use crate::cli::{self, InputArgs, OutputFormat, SubCommands, Table, EXIT_DO_NOT_REJECT, EXIT_ERROR};
+use crate::multiple::{self, Correction};
+use crate::record::Record;
+use crate::report::{self, Reportable};
+use crate::utils::MissingPolicy;
+
+/// The first line of every exported script.
+const SCRIPT_HEADER: &str = "# hyte repl session; replay it with `hyte repl --script <file>`";
+
+#[derive(Parser)]
+#[derive(Debug)]
+#[command(multicall = true, help_template = "Commands:\n{subcommands}")]
+struct Line {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+#[derive(Debug)]
+enum Command {
+    /// Load a CSV or TSV file, replacing the loaded one
+    Load(LoadArgs),
+    /// List the columns of the loaded file
+    Columns,
+    #[command(flatten)]
+    Test(SubCommands),
+    /// Adjust the p-values of every test run since the last `clear`
+    Adjust(AdjustArgs),
+    /// Compute Storey's q-values of every test run since the last `clear`
+    Qvalues(QValuesArgs),
+    /// Forget the tests run so far, so that the next adjustment starts a new family
+    Clear,
+    /// Show the previous result again
+    Show(ShowArgs),
+    /// List every command entered in this session
+    History,
+    /// Write the commands which produced this session to a script
+    Export {
+        /// File the script is written to
+        path: String,
+    },
+    /// Leave the session
+    #[command(alias = "exit")]
+    Quit,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+struct LoadArgs {
+    /// CSV or TSV file to read
+    path: String,
+    /// Field delimiter [default: a tab for .tsv and .tab files, otherwise a comma]
+    #[arg(short, long, value_name = "CHAR")]
+    delimiter: Option<char>,
+    /// Read the first row as data rather than as column names
+    #[arg(long)]
+    no_header: bool,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+struct AdjustArgs {
+    /// Correction for multiple testing
+    #[arg(value_enum)]
+    method: Correction,
+    /// Level at which the adjusted p-values are compared
+    #[arg(short, long, default_value_t = 0.05)]
+    level: f64,
+    /// Output format
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+struct QValuesArgs {
+    /// Tuning parameter used to estimate the proportion of true null hypotheses
+    #[arg(long, default_value_t = 0.5)]
+    lambda: f64,
+    /// Level at which the q-values are compared
+    #[arg(short, long, default_value_t = 0.05)]
+    level: f64,
+    /// Output format
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+#[derive(Debug)]
+struct ShowArgs {
+    /// Output format
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+/// Returns the parser of a session line, without the subcommands which cannot run within a session.
+fn parser() -> clap::Command {
+    ["repl", "serve"].into_iter().fold(Line::command(), |command, name| {
+        if command.find_subcommand(name).is_some() { command.mut_subcommand(name, |subcommand| subcommand.hide(true)) } else { command }
+    })
+}
+
+/// An interactive session: the loaded file, the previous result, the tests run so far, and the command history.
+#[derive(Default)]
+pub struct Session {
+    table: Option<Table>,
+    last: Option<Record>,
+    family: Vec<(String, f64)>,
+    history: Vec<String>,
+    script: Vec<String>,
+}
+
+/// Splits a command line into words, keeping text within single or double quotes together.
+fn split(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    for character in line.chars() {
+        match (quote, character) {
+            (Some(open), _) if character == open => quote = None,
+            (Some(_), _) => word.get_or_insert_with(String::new).push(character),
+            (None, '"' | '\'') => {
+                quote = Some(character);
+                word.get_or_insert_with(String::new);
+            },
+            (None, _) if character.is_whitespace() => words.extend(word.take()),
+            (None, _) => word.get_or_insert_with(String::new).push(character),
+        }
+    }
+    if quote.is_some() { return Err("the line has an unclosed quote".to_string()) };
+    words.extend(word);
+    Ok(words)
+}
+
+/// Rewrites `t a vs b` as `t2 a b`, and drops `vs` between the columns of any other test.
+fn expand_versus(mut words: Vec<String>) -> Vec<String> {
+    if words.get(2).is_some_and(|word| word == "vs") {
+        words.remove(2);
+        if words[0] == "t" { words[0] = "t2".to_string() };
+    }
+    words
+}
+
+fn verdict(reject: bool) -> &'static str {
+    if reject { "reject" } else { "do not reject" }
+}
+
+impl Session {
+    /// Creates a session with no file loaded.
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    /// Runs a single command line and returns its output, or `None` once the session has been quit.
+    /// Blank lines and lines starting with `#` do nothing. Invalid commands and input, including anything
+    /// that makes a test panic, are returned as an `Err` message and leave the session unchanged.
+    pub fn execute(&mut self, line: &str) -> Result<Option<String>, String> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { return Ok(Some(String::new())) };
+        self.history.push(line.to_string());
+        let words = expand_versus(split(line)?);
+        let command = match parser().try_get_matches_from(&words).and_then(|matches| Line::from_arg_matches(&matches)) {
+            Ok(parsed) => parsed.command,
+            Err(error) if matches!(error.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion) => return Ok(Some(error.to_string().trim_end().to_string())),
+            Err(error) => return Err(error.to_string().trim_end().trim_start_matches("error: ").to_string()),
+        };
+        let output = match command {
+            Command::Load(args) => self.load(&args)?,
+            Command::Columns => return self.columns().map(Some),
+            Command::Test(command) => self.test(&command, line)?,
+            Command::Adjust(args) => {
+                let p_values = self.p_values()?;
+                let result = caught(|| multiple::adjust(&p_values, args.method, args.level))?.ok_or("there are no p-values to adjust")?;
+                let report = self.family.iter().zip(result.adjusted.iter().zip(&result.reject)).enumerate().fold(result.report(), |report, (index, ((label, p), (&adjusted, &reject)))| {
+                    report.row(&format!("Adjusted p-value of [{}] {}", index + 1, label), format!("{} (p = {}), {}", report::p_value(adjusted), report::p_value(*p), verdict(reject)))
+                });
+                let record = Record::new(result).input("p_values", &p_values).parameter("level", args.level);
+                self.adjusted(report, record, args.format)?
+            },
+            Command::Qvalues(args) => {
+                let p_values = self.p_values()?;
+                let result = caught(|| multiple::qvalues(&p_values, args.lambda, args.level))?.ok_or("there are no p-values to adjust")?;
+                let report = self.family.iter().zip(result.q.iter().zip(&result.reject)).enumerate().fold(result.report(), |report, (index, ((label, p), (&q, &reject)))| {
+                    report.row(&format!("q-value of [{}] {}", index + 1, label), format!("{} (p = {}), {}", report::p_value(q), report::p_value(*p), verdict(reject)))
+                });
+                let record = Record::new(result).input("p_values", &p_values).parameter("lambda", args.lambda).parameter("level", args.level);
+                self.adjusted(report, record, args.format)?
+            },
+            Command::Clear => {
+                let cleared = self.family.len();
+                self.family.clear();
+                format!("Cleared {} test{}", cleared, if cleared == 1 { "" } else { "s" })
+            },
+            Command::Show(args) => {
+                let record = self.last.as_ref().ok_or("no test has been run yet")?;
+                let report = match (&record.conclusion, record.significance_level) {
+                    (Some(conclusion), Some(level)) => record.result.report().row("Conclusion", format!("{:?} at significance level {}", conclusion, level)),
+                    _ => record.result.report(),
+                };
+                return cli::render(report, record, args.format).map(Some);
+            },
+            Command::History => {
+                let width = self.history.len().to_string().len();
+                return Ok(Some(self.history.iter().enumerate().map(|(index, line)| format!("{:>width$}  {}", index + 1, line)).collect::<Vec<_>>().join("\n")));
+            },
+            Command::Export { path } => {
+                fs::write(&path, self.script()).map_err(|error| format!("cannot write `{}`: {}", path, error))?;
+                return Ok(Some(format!("Exported {} commands to `{}`", self.script.len(), path)));
+            },
+            Command::Quit => return Ok(None),
+        };
+        self.script.push(line.to_string());
+        Ok(Some(output))
+    }
+
+    fn load(&mut self, args: &LoadArgs) -> Result<String, String> {
+        if args.path == "-" { return Err("the standard input cannot be loaded in a session".to_string()) };
+        let input = InputArgs { file: args.path.clone(), delimiter: args.delimiter, no_header: args.no_header, missing: MissingPolicy::Omit };
+        let table = Table::read(&input, io::empty())?;
+        let output = format!("Loaded {} rows from `{}` with the columns {}", table.len(), args.path, table.names().join(", "));
+        self.table = Some(table);
+        Ok(output)
+    }
+
+    fn columns(&self) -> Result<String, String> {
+        let table = self.table.as_ref().ok_or("no file is loaded; use `load <path>` first")?;
+        let names = table.names();
+        let width = names.iter().map(|name| name.chars().count()).max().unwrap_or(0);
+        Ok(names.iter().map(|name| {
+            let summary = match table.column(name) {
+                Ok((_, values)) => format!("{} values, {} missing", values.len(), values.iter().filter(|value| value.is_none()).count()),
+                Err(_) => "not numeric".to_string(),
+            };
+            format!("{:<width$}  {}", name, summary)
+        }).collect::<Vec<_>>().join("\n"))
+    }
+
+    fn test(&mut self, command: &SubCommands, line: &str) -> Result<String, String> {
+        let input = match command {
+            SubCommands::Z(args) | SubCommands::T(args) => &args.input,
+            SubCommands::T2(args) | SubCommands::Paired(args) => &args.input,
+            SubCommands::ChisqToi(args) => &args.input,
+            SubCommands::ChisqGof(args) => &args.input,
+            SubCommands::Normality(args) => &args.input,
+            SubCommands::Ks(args) => &args.input,
+            SubCommands::Ks2(args) => &args.input,
+            SubCommands::Correlation(args) => &args.input,
+            SubCommands::Binomial(args) => &args.input,
+            SubCommands::Poisson(args) => &args.input,
+            #[cfg(feature = "serve")]
+            SubCommands::Serve(_) => return Err("`serve` is not available in a session".to_string()),
+            #[cfg(feature = "repl")]
+            SubCommands::Repl(_) => return Err("a session cannot be started within a session".to_string()),
+        };
+        if input.file != "-" { return Err("tests run on the loaded file; use `load <path>` to read another one".to_string()) };
+        let table = self.table.as_ref().ok_or("no file is loaded; use `load <path>` first")?;
+        let outcome = cli::perform(command, table)?;
+        if let Some(p) = outcome.record.result.p_value() {
+            self.family.push((line.to_string(), p));
+        }
+        self.last = Some(outcome.record);
+        Ok(outcome.output)
+    }
+
+    fn p_values(&self) -> Result<Vec<f64>, String> {
+        if self.family.is_empty() { return Err("no test has been run since the session started or since the last `clear`".to_string()) };
+        Ok(self.family.iter().map(|&(_, p)| p).collect())
+    }
+
+    fn adjusted(&mut self, report: report::Report, record: Record, format: OutputFormat) -> Result<String, String> {
+        let output = cli::render(report, &record, format)?;
+        self.last = Some(record);
+        Ok(output)
+    }
+
+    /// Returns every command entered so far, including those which failed.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Returns a script of the commands which loaded files, ran tests, and adjusted p-values, in order.
+    /// Replaying it with `Session::run_script` or `hyte repl --script` reproduces every result of the session.
+    pub fn script(&self) -> String {
+        std::iter::once(SCRIPT_HEADER).chain(self.script.iter().map(String::as_str)).map(|line| format!("{}\n", line)).collect()
+    }
+
+    /// Runs every line of `script`, returning the outputs of its commands. Each output is preceded by its
+    /// command, and the first failing command stops the script with an `Err` naming its line.
+    pub fn run_script(&mut self, script: &str) -> Result<String, String> {
+        let mut outputs = Vec::new();
+        for (number, line) in script.lines().enumerate() {
+            match self.execute(line) {
+                Ok(Some(output)) if output.is_empty() => {},
+                Ok(Some(output)) => outputs.push(format!("> {}\n{}", line.trim(), output)),
+                Ok(None) => break,
+                Err(message) => return Err(format!("line {}: {}", number + 1, message)),
+            }
+        }
+        Ok(outputs.join("\n\n"))
+    }
+
+    /// Returns where the word under the cursor at `pos` starts, and its possible completions: command names
+    /// for the first word, option names after `-`, the values of options and arguments which have a fixed set
+    /// of values, and otherwise the columns of the loaded file.
+    pub fn complete(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+        let before = &line[..pos];
+        let start = before.rfind(char::is_whitespace).map_or(0, |index| index + 1);
+        let prefix = &before[start..];
+        let words: Vec<&str> = before[..start].split_whitespace().collect();
+        let root = parser();
+        let candidates: Vec<String> = match words.first() {
+            None => root.get_subcommands().filter(|command| !command.is_hide_set()).flat_map(|command| std::iter::once(command.get_name()).chain(command.get_all_aliases())).map(str::to_string).collect(),
+            Some(&name) => {
+                let name = if name == "t" && words.get(2) == Some(&"vs") { "t2" } else { name };
+                let Some(command) = root.find_subcommand(name) else { return (start, Vec::new()) };
+                let option = |word: &str| command.get_arguments().find(|arg| {
+                    arg.get_action().takes_values() && match word.strip_prefix("--") {
+                        Some(long) => arg.get_long() == Some(long),
+                        None => word.strip_prefix('-').is_some_and(|short| short.len() == 1 && arg.get_short() == short.chars().next()),
+                    }
+                });
+                let mut positionals = 0;
+                for (index, word) in words.iter().enumerate().skip(1) {
+                    let is_value = option(words[index - 1]).is_some() && index > 1;
+                    if !word.starts_with('-') && !is_value && *word != "vs" { positionals += 1 };
+                }
+                let option = words.last().and_then(|word| option(word)).filter(|_| words.len() > 1);
+                let positional = command.get_positionals().nth(positionals);
+                if prefix.starts_with('-') {
+                    command.get_arguments().filter_map(|arg| arg.get_long()).map(|long| format!("--{}", long)).collect()
+                } else if let Some(arg) = option.or(positional.filter(|arg| !arg.get_possible_values().is_empty())) {
+                    arg.get_possible_values().iter().map(|value| value.get_name().to_string()).collect()
+                } else if matches!(name, "load" | "export") || option.is_some() {
+                    Vec::new()
+                } else {
+                    let columns = self.table.as_ref().map(Table::names).unwrap_or_default();
+                    columns.into_iter().chain((words.len() == 2 && name != "chisq-toi").then(|| "vs".to_string())).collect()
+                }
+            },
+        };
+        (start, candidates.into_iter().filter(|candidate| candidate.starts_with(prefix)).collect())
+    }
+}
+
+/// Runs `closure`, returning a hyte panic as an `Err` message.
+fn caught<T>(closure: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(closure)).map_err(|payload| {
+        let message = payload.downcast_ref::<String>().map(String::as_str).or_else(|| payload.downcast_ref::<&str>().copied()).unwrap_or("the command failed");
+        message.trim().trim_start_matches("[HYTE-Panic] ").to_string()
+    })
+}
+
+/// Completes command names, options, and columns while a session is edited with `rustyline`.
+struct SessionHelper {
+    session: Session,
+}
+
+impl Completer for SessionHelper {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(self.session.complete(line, pos))
+    }
+}
+
+impl Hinter for SessionHelper {
+    type Hint = String;
+}
+
+impl Highlighter for SessionHelper {}
+
+impl Validator for SessionHelper {}
+
+impl Helper for SessionHelper {}
+
+/// The file which keeps the lines entered in interactive sessions, `hyte/history` under `state_home`
+/// (`$XDG_STATE_HOME`), or under `~/.local/state` when it is unset or empty. Returns `None` if neither is known.
+pub(crate) fn history_path(state_home: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    let state_home = match state_home.filter(|path| !path.is_empty()) {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(home.filter(|path| !path.is_empty())?).join(".local").join("state"),
+    };
+    Some(state_home.join("hyte").join("history"))
+}
+
+/// Runs `script` if it is given, or otherwise an interactive session, after loading `file` if it is given.
+/// Returns `EXIT_ERROR` if a command of the script fails, and `EXIT_DO_NOT_REJECT` otherwise.
+pub fn main(file: Option<&str>, script: Option<&str>) -> u8 {
+    let mut session = Session::new();
+    if let Some(file) = file {
+        if let Err(message) = session.execute(&format!("load '{}'", file)) {
+            eprintln!("hyte: {}", message);
+            return EXIT_ERROR;
+        }
+    }
+    if let Some(path) = script {
+        let outcome = fs::read_to_string(path).map_err(|error| format!("cannot open `{}`: {}", path, error)).and_then(|script| session.run_script(&script));
+        return match outcome {
+            Ok(output) => {
+                println!("{}", output);
+                EXIT_DO_NOT_REJECT
+            },
+            Err(message) => {
+                eprintln!("hyte: {}", message);
+                EXIT_ERROR
+            },
+        };
+    }
+
+    println!("{}\n\nType `help` for the commands, `load <path>` to read a CSV or TSV file, and `quit` to leave.\n", crate::banner());
+    let mut editor = match Editor::<SessionHelper, DefaultHistory>::new() {
+        Ok(editor) => editor,
+        Err(error) => {
+            eprintln!("hyte: {}", error);
+            return EXIT_ERROR;
+        },
+    };
+    editor.set_helper(Some(SessionHelper { session }));
+    let history = history_path(env::var_os("XDG_STATE_HOME"), env::var_os("HOME"));
+    if let Some(path) = &history {
+        match editor.load_history(path) {
+            Ok(()) => {},
+            Err(ReadlineError::Io(error)) if error.kind() == io::ErrorKind::NotFound => {},
+            Err(error) => eprintln!("hyte: cannot read history from `{}`: {}", path.display(), error),
+        }
+    }
+    loop {
+        let line = match editor.readline("hyte> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => {
+                eprintln!("hyte: {}", error);
+                return EXIT_ERROR;
+            },
+        };
+        let _ = editor.add_history_entry(line.as_str());
+        match editor.helper_mut().unwrap().session.execute(&line) {
+            Ok(Some(output)) if output.is_empty() => {},
+            Ok(Some(output)) => println!("{}\n", output),
+            Ok(None) => break,
+            Err(message) => eprintln!("error: {}\n", message),
+        }
+    }
+    if let Some(path) = &history {
+        let saved = path.parent().map_or(Ok(()), fs::create_dir_all).map_err(ReadlineError::from).and_then(|()| editor.save_history(path));
+        if let Err(error) = saved { eprintln!("hyte: cannot write history to `{}`: {}", path.display(), error) };
+    }
+    EXIT_DO_NOT_REJECT
+}
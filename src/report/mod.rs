@@ -0,0 +1,184 @@
+//! Module responsible for all items needed to render test results as plain text, Markdown, LaTeX, or APA-style sentences.
+
+use std::fmt;
+
+/// The formats a `Report` can be rendered in.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Format {
+    /// The test name and hypotheses followed by one `quantity = value` line per row.
+    Text,
+    /// The test name in bold, then the hypotheses and a two-column table of the rows.
+    Markdown,
+    /// A two-column `tabular` environment, headed by the test name.
+    Latex,
+    /// An APA-style sentence fragment such as `t(7) = -2.31, p = .027, d = 0.82`.
+    Apa,
+}
+
+/// A rendered summary of a test result, created by `Reportable::report`.
+///
+/// A `Report` implements `Display`, so it can be printed with `println!("{}", results.report())`,
+/// and `with_format` chooses between plain text (the default), Markdown, LaTeX, and APA style.
+/// Nothing in `hyte` writes to the standard output on its own; printing a report is left to the caller.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::t;
+/// use hyte::report::{Format, Reportable};
+/// use hyte::utils::{Tails, MissingPolicy};
+///
+/// fn main() {
+///     let results = t::test(vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2], 3, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+///
+///     println!("{}", results.report());
+///     println!("{}", results.report().with_format(Format::Markdown));
+///     assert_eq!(results.report().render(Format::Apa), "t(7) = -1.73, p = .063, d = -0.61");
+/// }
+/// ```
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Report {
+    pub title: String,
+    pub hypotheses: Option<String>,
+    pub rows: Vec<(String, String)>,
+    pub apa: String,
+    pub format: Format,
+}
+
+/// A trait for test results which can be summarised as a `Report`.
+pub trait Reportable {
+    /// Returns a plain-text `Report` of the result, which can be changed to another format with `Report::with_format`.
+    fn report(&self) -> Report;
+}
+
+impl Report {
+    pub(crate) fn new(title: &str) -> Report {
+        Report {
+            title: title.to_string(),
+            hypotheses: None,
+            rows: Vec::new(),
+            apa: String::new(),
+            format: Format::Text,
+        }
+    }
+
+    pub(crate) fn hypotheses(mut self, hypotheses: &str) -> Report {
+        self.hypotheses = Some(hypotheses.to_string());
+        self
+    }
+
+    pub(crate) fn row(mut self, quantity: &str, value: impl fmt::Display) -> Report {
+        self.rows.push((quantity.to_string(), value.to_string()));
+        self
+    }
+
+    pub(crate) fn apa(mut self, sentence: impl Into<String>) -> Report {
+        self.apa = sentence.into();
+        self
+    }
+
+    /// Returns the same report, displayed in `format`.
+    pub fn with_format(mut self, format: Format) -> Report {
+        self.format = format;
+        self
+    }
+
+    /// Renders the report in `format`, regardless of the format it is displayed in.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => {
+                let mut text = self.title.clone();
+                if let Some(hypotheses) = &self.hypotheses { text.push_str(&format!("\n{}", hypotheses)) };
+                text.push('\n');
+                for (quantity, value) in &self.rows {
+                    text.push_str(&format!("\n{} = {}", quantity, value));
+                }
+                text
+            },
+            Format::Markdown => {
+                let mut text = format!("**{}**\n", self.title);
+                if let Some(hypotheses) = &self.hypotheses { text.push_str(&format!("\n{}\n", hypotheses)) };
+                text.push_str("\n| Quantity | Value |\n| --- | --- |");
+                for (quantity, value) in &self.rows {
+                    text.push_str(&format!("\n| {} | {} |", quantity.replace('|', "\\|"), value.replace('|', "\\|")));
+                }
+                text
+            },
+            Format::Latex => {
+                let mut text = format!("\\begin{{tabular}}{{lr}}\n\\hline\n\\multicolumn{{2}}{{l}}{{{}}} \\\\\n", latex_escape(&self.title));
+                if let Some(hypotheses) = &self.hypotheses {
+                    text.push_str(&format!("\\multicolumn{{2}}{{l}}{{{}}} \\\\\n", latex_escape(hypotheses)));
+                }
+                text.push_str("\\hline\n");
+                for (quantity, value) in &self.rows {
+                    text.push_str(&format!("{} & {} \\\\\n", latex_escape(quantity), latex_escape(value)));
+                }
+                text.push_str("\\hline\n\\end{tabular}");
+                text
+            },
+            Format::Apa => self.apa.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(self.format))
+    }
+}
+
+fn latex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Formats a p-value for a report row.
+pub(crate) fn p_value(p: f64) -> String {
+    format!("{:.3e}", p)
+}
+
+/// Formats a p-value in APA style, with three decimals, no leading zero, and `p < .001` for small values.
+pub(crate) fn apa_p(p: f64) -> String {
+    if p < 0.001 {
+        "p < .001".to_string()
+    } else if p > 0.999 {
+        "p > .999".to_string()
+    } else {
+        format!("p = {}", bounded(p, 3))
+    }
+}
+
+/// Formats a quantity which cannot exceed 1 in magnitude, such as a correlation, without a leading zero as APA style requires.
+pub(crate) fn bounded(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    if let Some(rest) = text.strip_prefix("0.") {
+        format!(".{}", rest)
+    } else if let Some(rest) = text.strip_prefix("-0.") {
+        format!("-.{}", rest)
+    } else {
+        text
+    }
+}
+
+/// Formats degrees of freedom, which are only shown with decimals when they are not whole numbers.
+pub(crate) fn df(df: f64) -> String {
+    if df.fract() == 0.0 { format!("{}", df) } else { format!("{:.2}", df) }
+}
@@ -0,0 +1,932 @@
+//! Module responsible for all items needed to serve every test as a JSON endpoint over HTTP.
+//!
+//! Each test is exposed as `POST /v1/<module>/<function>`, taking the arguments of the function as a JSON object and
+//! returning a `record::Record`. Optional arguments, such as `alternative` (two-sided by default) and `missing`
+//! (`Omit` by default), may be left out, and an `alpha` query parameter such as `?alpha=0.05` adds a conclusion.
+//! `GET /healthz` reports that the server is up, `GET /v1/tests` lists every test of `registry::TESTS` with its
+//! endpoints, and `GET /openapi.json` returns an OpenAPI 3.1 description of every endpoint. Errors are returned as
+//! `{"error": {"status": ..., "code": ..., "message": ...}}`. Requests asking for more work than the `Limits` of
+//! the server allow, such as a binomial test of a billion trials, are rejected with `422` errors.
+//!
+//! This module is only available with the `serve` feature, which also adds `hyte serve` to the command line.
+//!
+//! # Examples
+//!
+//! ```
+//! use hyte::serve;
+//!
+//! fn main() {
+//!     let response = serve::handle("POST", "/v1/t/test?alpha=0.1", br#"{"data": [2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2], "expected_mean": 3, "alternative": {"direction": "Less"}}"#);
+//!
+//!     assert_eq!(response.status, 200);
+//!     assert!(response.body.contains(r#""conclusion": "Reject""#));
+//! }
+//! ```
+
+use std::io::{self, Read};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use schemars::{JsonSchema, SchemaGenerator};
+use schemars::generate::SchemaSettings;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use statrs::distribution::{ContinuousCDF, Exp, Normal, Uniform};
+use crate::{bayes, binomial, bootstrap, chisquare, correlation, equivalence, homogeneity, ks, multiple, normality, poisson, registry, regression, t, z};
+use crate::bayes::NormalGamma;
+use crate::binomial::Interval;
+use crate::multiple::Correction;
+use crate::poisson::Approximation;
+use crate::record::{Record, TestResult};
+use crate::utils::{Alternative, Matrix, MissingPolicy, Resampling, Tails, Weights};
+
+/// The largest request body accepted by default, in bytes.
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1 << 20;
+
+/// Limits on the work a single request may ask for, whose cost grows with numbers in the body rather than its size.
+/// Requests exceeding them are rejected with `422` errors with the code `invalid_input`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Limits {
+    /// The most trials of a binomial test, which sums every outcome, including the `count1 + count2` trials of `poisson/compare`.
+    pub max_trials: u64,
+    /// The largest expected count `rate * exposure` of `poisson/test`, whose outcomes are summed up to about twice it.
+    pub max_expected_count: f64,
+    /// The most resamples of a bootstrap test.
+    pub max_resamples: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits { max_trials: 10_000_000, max_expected_count: 10_000_000.0, max_resamples: 100_000 }
+    }
+}
+
+impl Limits {
+    fn exceeded(name: &str, limit: impl std::fmt::Display) -> ApiError {
+        ApiError::new(422, "invalid_input", format!("{} must not exceed {} on this server", name, limit))
+    }
+
+    fn trials(&self, trials: u64) -> Result<(), ApiError> {
+        if trials > self.max_trials { return Err(Limits::exceeded("the number of trials", self.max_trials)) };
+        Ok(())
+    }
+
+    fn expected_count(&self, rate: f64, exposure: f64) -> Result<(), ApiError> {
+        if rate * exposure > self.max_expected_count { return Err(Limits::exceeded("the expected count", self.max_expected_count)) };
+        Ok(())
+    }
+
+    fn resamples(&self, resampling: &Resampling) -> Result<(), ApiError> {
+        if resampling.resamples > self.max_resamples { return Err(Limits::exceeded("the number of resamples", self.max_resamples)) };
+        Ok(())
+    }
+}
+
+/// A JSON response, with its HTTP status code.
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The body of every error response.
+#[derive(Serialize, JsonSchema)]
+struct ErrorResponse {
+    error: ApiError,
+}
+
+/// An error, with a stable machine-readable `code` and a human-readable `message`.
+#[derive(Debug)]
+#[derive(Serialize, JsonSchema)]
+struct ApiError {
+    status: u16,
+    code: &'static str,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: u16, code: &'static str, message: impl Into<String>) -> ApiError {
+        ApiError { status, code, message: message.into() }
+    }
+
+    fn response(self) -> Response {
+        Response { status: self.status, body: to_json(&ErrorResponse { error: self }) }
+    }
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).unwrap()
+}
+
+fn two_sided() -> Alternative {
+    Tails::BOTH.into()
+}
+
+fn omit() -> MissingPolicy {
+    MissingPolicy::Omit
+}
+
+fn confidence() -> f64 {
+    0.95
+}
+
+fn medium() -> f64 {
+    bayes::MEDIUM
+}
+
+fn uniform() -> (f64, f64) {
+    (1.0, 1.0)
+}
+
+fn reference() -> NormalGamma {
+    NormalGamma::reference()
+}
+
+/// `z/test` and `t/test`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct OneSampleRequest {
+    data: Vec<Option<f64>>,
+    expected_mean: f64,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `z/test_dataless` and `t/test_dataless`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SummaryRequest {
+    observed_mean: f64,
+    expected_mean: f64,
+    sample_size: u64,
+    pop_sd: f64,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+}
+
+/// `z/test_weighted` and `t/test_weighted`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WeightedRequest {
+    data: Vec<Option<f64>>,
+    weights: Vec<f64>,
+    kind: Weights,
+    expected_mean: f64,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `t/test_two_samples`, `t/test_paired`, and `ks/test_two_samples`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TwoSampleRequest {
+    data1: Vec<Option<f64>>,
+    data2: Vec<Option<f64>>,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `z/test_two_samples_weighted` and `t/test_two_samples_weighted`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TwoSampleWeightedRequest {
+    data1: Vec<Option<f64>>,
+    weights1: Vec<f64>,
+    data2: Vec<Option<f64>>,
+    weights2: Vec<f64>,
+    kind: Weights,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `chisquare/independence`, on a contingency table given as rows of counts.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct IndependenceRequest {
+    table: Vec<Vec<Option<f64>>>,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `chisquare/goodness_of_fit`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct GoodnessOfFitRequest {
+    observed: Vec<Option<f64>>,
+    probabilities: Vec<f64>,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// The tests of normality and `ks/lilliefors`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SampleRequest {
+    data: Vec<Option<f64>>,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// A fully specified continuous distribution for `ks/test`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(tag = "name", rename_all = "snake_case", deny_unknown_fields)]
+enum Distribution {
+    Normal { mean: f64, sd: f64 },
+    Uniform { min: f64, max: f64 },
+    Exponential { rate: f64 },
+}
+
+/// `ks/test`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct DistributionRequest {
+    data: Vec<Option<f64>>,
+    distribution: Distribution,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// The tests of homogeneity of variance.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct GroupsRequest {
+    groups: Vec<Vec<Option<f64>>>,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `correlation/pearson`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PearsonRequest {
+    x: Vec<Option<f64>>,
+    y: Vec<Option<f64>>,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "confidence")]
+    confidence_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `correlation/spearman` and `correlation/kendall`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct RankCorrelationRequest {
+    x: Vec<Option<f64>>,
+    y: Vec<Option<f64>>,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `correlation/compare_independent`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CompareCorrelationsRequest {
+    r1: f64,
+    n1: u32,
+    r2: f64,
+    n2: u32,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+}
+
+/// `regression/linear`, with each predictor given as a column.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct LinearRegressionRequest {
+    predictors: Vec<Vec<Option<f64>>>,
+    response: Vec<Option<f64>>,
+    #[serde(default = "confidence")]
+    confidence_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `regression/simple`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct SimpleRegressionRequest {
+    x: Vec<Option<f64>>,
+    y: Vec<Option<f64>>,
+    #[serde(default = "confidence")]
+    confidence_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+fn clopper_pearson() -> Interval {
+    Interval::ClopperPearson
+}
+
+/// `binomial/test` and `binomial/mid_p_test`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct BinomialRequest {
+    successes: u64,
+    trials: u64,
+    expected_probability: f64,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "clopper_pearson")]
+    interval: Interval,
+    #[serde(default = "confidence")]
+    confidence_level: f64,
+}
+
+/// `poisson/test`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PoissonRequest {
+    count: u64,
+    exposure: f64,
+    rate: f64,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "confidence")]
+    confidence_level: f64,
+}
+
+/// `poisson/compare`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PoissonCompareRequest {
+    count1: u64,
+    exposure1: f64,
+    count2: u64,
+    exposure2: f64,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default = "confidence")]
+    confidence_level: f64,
+}
+
+/// `poisson/z_test`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PoissonZRequest {
+    count: u64,
+    exposure: f64,
+    rate: f64,
+    method: Approximation,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+}
+
+/// `poisson/compare_z`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PoissonCompareZRequest {
+    count1: u64,
+    exposure1: f64,
+    count2: u64,
+    exposure2: f64,
+    method: Approximation,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+}
+
+/// `equivalence/t_test`; `hypothesis` is an `Alternative`, usually with `margins`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct EquivalenceRequest {
+    data: Vec<Option<f64>>,
+    expected_mean: f64,
+    hypothesis: Alternative,
+    significance_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `equivalence/paired_t_test`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct PairedEquivalenceRequest {
+    data1: Vec<Option<f64>>,
+    data2: Vec<Option<f64>>,
+    hypothesis: Alternative,
+    significance_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `equivalence/t_test_two_samples`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TwoSampleEquivalenceRequest {
+    data1: Vec<Option<f64>>,
+    data2: Vec<Option<f64>>,
+    hypothesis: Alternative,
+    #[serde(default)]
+    equal_variances: bool,
+    significance_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `equivalence/proportions`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ProportionsRequest {
+    successes1: u64,
+    trials1: u64,
+    successes2: u64,
+    trials2: u64,
+    hypothesis: Alternative,
+    significance_level: f64,
+}
+
+/// `bootstrap/t_test`; `resampling` defaults to 9999 resamples with seed 0.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct BootstrapRequest {
+    data: Vec<Option<f64>>,
+    expected_mean: f64,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default)]
+    resampling: Resampling,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `bootstrap/t_test_two_samples`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TwoSampleBootstrapRequest {
+    data1: Vec<Option<f64>>,
+    data2: Vec<Option<f64>>,
+    #[serde(default = "two_sided")]
+    alternative: Alternative,
+    #[serde(default)]
+    resampling: Resampling,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `bayes/t_test`; `prior_scale` defaults to `bayes::MEDIUM`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct BayesFactorRequest {
+    data: Vec<Option<f64>>,
+    expected_mean: f64,
+    #[serde(default = "medium")]
+    prior_scale: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `bayes/t_test_paired` and `bayes/t_test_two_samples`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TwoSampleBayesFactorRequest {
+    data1: Vec<Option<f64>>,
+    data2: Vec<Option<f64>>,
+    #[serde(default = "medium")]
+    prior_scale: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `bayes/t_test_dataless`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct BayesFactorSummaryRequest {
+    statistic: f64,
+    sample_size: u64,
+    #[serde(default = "medium")]
+    prior_scale: f64,
+}
+
+/// `bayes/t_test_two_samples_dataless`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TwoSampleBayesFactorSummaryRequest {
+    statistic: f64,
+    sample_size1: u64,
+    sample_size2: u64,
+    #[serde(default = "medium")]
+    prior_scale: f64,
+}
+
+/// `bayes/beta_binomial`; `prior` defaults to the uniform `Beta(1, 1)`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct BetaBinomialRequest {
+    successes_a: u64,
+    trials_a: u64,
+    successes_b: u64,
+    trials_b: u64,
+    #[serde(default = "uniform")]
+    prior: (f64, f64),
+    #[serde(default = "confidence")]
+    credible_level: f64,
+}
+
+/// `bayes/normal_gamma`; `prior` defaults to `NormalGamma::reference()`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct NormalGammaRequest {
+    data: Vec<Option<f64>>,
+    #[serde(default = "reference")]
+    prior: NormalGamma,
+    #[serde(default = "confidence")]
+    credible_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `bayes/normal_gamma_compare`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct NormalGammaCompareRequest {
+    data_a: Vec<Option<f64>>,
+    data_b: Vec<Option<f64>>,
+    #[serde(default = "reference")]
+    prior: NormalGamma,
+    #[serde(default = "confidence")]
+    credible_level: f64,
+    #[serde(default = "omit")]
+    missing: MissingPolicy,
+}
+
+/// `multiple/adjust`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct AdjustRequest {
+    p_values: Vec<f64>,
+    method: Correction,
+    level: f64,
+}
+
+fn half() -> f64 {
+    0.5
+}
+
+/// `multiple/qvalues`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct QValuesRequest {
+    p_values: Vec<f64>,
+    #[serde(default = "half")]
+    lambda: f64,
+    level: f64,
+}
+
+/// Wraps the result of a test in a `Record`, or reports that there was not enough data to perform it.
+fn finish<R: Into<TestResult>>(result: Option<R>, alternative: Option<Alternative>) -> Result<Record, ApiError> {
+    let record = Record::new(result.ok_or_else(|| ApiError::new(422, "insufficient_data", "there is not enough data to perform the test"))?);
+    Ok(match alternative {
+        Some(alternative) => record.alternative(alternative),
+        None => record,
+    })
+}
+
+fn distribution(distribution: &Distribution) -> Result<Box<dyn ContinuousCDF<f64, f64>>, ApiError> {
+    let invalid = |error: statrs::StatsError| ApiError::new(422, "invalid_input", format!("invalid distribution: {}", error));
+    Ok(match *distribution {
+        Distribution::Normal { mean, sd } => Box::new(Normal::new(mean, sd).map_err(invalid)?),
+        Distribution::Uniform { min, max } => Box::new(Uniform::new(min, max).map_err(invalid)?),
+        Distribution::Exponential { rate } => Box::new(Exp::new(rate).map_err(invalid)?),
+    })
+}
+
+fn parse<'a, T: Deserialize<'a>>(body: &'a [u8]) -> Result<T, ApiError> {
+    serde_json::from_slice(body).map_err(|error| ApiError::new(400, "invalid_json", error.to_string()))
+}
+
+macro_rules! endpoints {
+    ($($path:literal: $request:ty => $test:literal, |$args:ident, $limits:tt| $call:expr;)*) => {
+        /// Every test endpoint as `(path, test)`, where `test` names the test in `registry::TESTS` it performs.
+        pub const ENDPOINTS: &[(&str, &str)] = &[$(($path, $test)),*];
+
+        fn dispatch(path: &str, body: &[u8], limits: &Limits) -> Option<Result<Record, ApiError>> {
+            match path {
+                $($path => Some(parse::<$request>(body).and_then(|$args| {
+                    let $limits = limits;
+                    $call
+                })),)*
+                _ => None,
+            }
+        }
+
+        fn request_schemas(generator: &mut SchemaGenerator) -> Vec<schemars::Schema> {
+            vec![$(generator.subschema_for::<$request>()),*]
+        }
+    };
+}
+
+endpoints! {
+    "/v1/z/test": OneSampleRequest => "z::test",
+        |a, _| finish(z::test(&a.data, a.expected_mean, a.alternative, a.missing), Some(a.alternative));
+    "/v1/z/test_dataless": SummaryRequest => "z::test_dataless",
+        |a, _| finish(z::test_dataless(a.observed_mean, a.expected_mean, a.sample_size, a.pop_sd, a.alternative), Some(a.alternative));
+    "/v1/z/test_weighted": WeightedRequest => "z::test_weighted",
+        |a, _| finish(z::test_weighted(&a.data, &a.weights, a.kind, a.expected_mean, a.alternative, a.missing), Some(a.alternative));
+    "/v1/z/test_two_samples_weighted": TwoSampleWeightedRequest => "z::test_two_samples_weighted",
+        |a, _| finish(z::test_two_samples_weighted(&a.data1, &a.weights1, &a.data2, &a.weights2, a.kind, a.alternative, a.missing), Some(a.alternative));
+    "/v1/t/test": OneSampleRequest => "t::test",
+        |a, _| finish(t::test(&a.data, a.expected_mean, a.alternative, a.missing), Some(a.alternative));
+    "/v1/t/test_dataless": SummaryRequest => "t::test_dataless",
+        |a, _| finish(t::test_dataless(a.observed_mean, a.expected_mean, a.sample_size, a.pop_sd, a.alternative), Some(a.alternative));
+    "/v1/t/test_weighted": WeightedRequest => "t::test_weighted",
+        |a, _| finish(t::test_weighted(&a.data, &a.weights, a.kind, a.expected_mean, a.alternative, a.missing), Some(a.alternative));
+    "/v1/t/test_two_samples": TwoSampleRequest => "t::test_two_samples",
+        |a, _| finish(t::test_two_samples(&a.data1, &a.data2, a.alternative, a.missing), Some(a.alternative));
+    "/v1/t/test_paired": TwoSampleRequest => "t::test_paired",
+        |a, _| finish(t::test_paired(&a.data1, &a.data2, a.alternative, a.missing), Some(a.alternative));
+    "/v1/t/test_two_samples_weighted": TwoSampleWeightedRequest => "t::test_two_samples_weighted",
+        |a, _| finish(t::test_two_samples_weighted(&a.data1, &a.weights1, &a.data2, &a.weights2, a.kind, a.alternative, a.missing), Some(a.alternative));
+    "/v1/chisquare/independence": IndependenceRequest => "chisquare::test",
+        |a, _| finish(chisquare::test("toi", Matrix::TwoDimensional(a.table), None, a.missing), None);
+    "/v1/chisquare/goodness_of_fit": GoodnessOfFitRequest => "chisquare::test",
+        |a, _| finish(chisquare::test("gof", Matrix::OneDimensional(a.observed), Some(&a.probabilities), a.missing), None);
+    "/v1/normality/shapiro_wilk": SampleRequest => "normality::shapiro_wilk",
+        |a, _| finish(normality::shapiro_wilk(&a.data, a.missing), None);
+    "/v1/normality/anderson_darling": SampleRequest => "normality::anderson_darling",
+        |a, _| finish(normality::anderson_darling(&a.data, a.missing), None);
+    "/v1/normality/dagostino_pearson": SampleRequest => "normality::dagostino_pearson",
+        |a, _| finish(normality::dagostino_pearson(&a.data, a.missing), None);
+    "/v1/normality/jarque_bera": SampleRequest => "normality::jarque_bera",
+        |a, _| finish(normality::jarque_bera(&a.data, a.missing), None);
+    "/v1/ks/test": DistributionRequest => "ks::test",
+        |a, _| finish(ks::test(&a.data, distribution(&a.distribution)?.as_ref(), a.alternative, a.missing), Some(a.alternative));
+    "/v1/ks/test_two_samples": TwoSampleRequest => "ks::test_two_samples",
+        |a, _| finish(ks::test_two_samples(&a.data1, &a.data2, a.alternative, a.missing), Some(a.alternative));
+    "/v1/ks/lilliefors": SampleRequest => "ks::lilliefors",
+        |a, _| finish(ks::lilliefors(&a.data, a.missing), None);
+    "/v1/homogeneity/levene": GroupsRequest => "homogeneity::levene",
+        |a, _| finish(homogeneity::levene(&a.groups, a.missing), None);
+    "/v1/homogeneity/brown_forsythe": GroupsRequest => "homogeneity::brown_forsythe",
+        |a, _| finish(homogeneity::brown_forsythe(&a.groups, a.missing), None);
+    "/v1/homogeneity/bartlett": GroupsRequest => "homogeneity::bartlett",
+        |a, _| finish(homogeneity::bartlett(&a.groups, a.missing), None);
+    "/v1/homogeneity/fligner_killeen": GroupsRequest => "homogeneity::fligner_killeen",
+        |a, _| finish(homogeneity::fligner_killeen(&a.groups, a.missing), None);
+    "/v1/correlation/pearson": PearsonRequest => "correlation::pearson",
+        |a, _| finish(correlation::pearson(&a.x, &a.y, a.alternative, a.confidence_level, a.missing), Some(a.alternative));
+    "/v1/correlation/spearman": RankCorrelationRequest => "correlation::spearman",
+        |a, _| finish(correlation::spearman(&a.x, &a.y, a.alternative, a.missing), Some(a.alternative));
+    "/v1/correlation/kendall": RankCorrelationRequest => "correlation::kendall",
+        |a, _| finish(correlation::kendall(&a.x, &a.y, a.alternative, a.missing), Some(a.alternative));
+    "/v1/correlation/compare_independent": CompareCorrelationsRequest => "correlation::compare_independent",
+        |a, _| finish(correlation::compare_independent(a.r1, a.n1, a.r2, a.n2, a.alternative), Some(a.alternative));
+    "/v1/regression/linear": LinearRegressionRequest => "regression::linear",
+        |a, _| finish(regression::linear(&a.predictors, &a.response, a.confidence_level, a.missing), None);
+    "/v1/regression/simple": SimpleRegressionRequest => "regression::simple",
+        |a, _| finish(regression::simple(&a.x, &a.y, a.confidence_level, a.missing), None);
+    "/v1/binomial/test": BinomialRequest => "binomial::test",
+        |a, limits| limits.trials(a.trials).and_then(|_| finish(binomial::test(a.successes, a.trials, a.expected_probability, a.alternative, a.interval, a.confidence_level), Some(a.alternative)));
+    "/v1/binomial/mid_p_test": BinomialRequest => "binomial::mid_p_test",
+        |a, limits| limits.trials(a.trials).and_then(|_| finish(binomial::mid_p_test(a.successes, a.trials, a.expected_probability, a.alternative, a.interval, a.confidence_level), Some(a.alternative)));
+    "/v1/poisson/test": PoissonRequest => "poisson::test",
+        |a, limits| limits.expected_count(a.rate, a.exposure).and_then(|_| finish(poisson::test(a.count, a.exposure, a.rate, a.alternative, a.confidence_level), Some(a.alternative)));
+    "/v1/poisson/compare": PoissonCompareRequest => "poisson::compare",
+        |a, limits| limits.trials(a.count1.saturating_add(a.count2)).and_then(|_| finish(poisson::compare(a.count1, a.exposure1, a.count2, a.exposure2, a.alternative, a.confidence_level), Some(a.alternative)));
+    "/v1/poisson/z_test": PoissonZRequest => "poisson::z_test",
+        |a, _| finish(poisson::z_test(a.count, a.exposure, a.rate, a.method, a.alternative), Some(a.alternative));
+    "/v1/poisson/compare_z": PoissonCompareZRequest => "poisson::compare_z",
+        |a, _| finish(poisson::compare_z(a.count1, a.exposure1, a.count2, a.exposure2, a.method, a.alternative), Some(a.alternative));
+    "/v1/equivalence/t_test": EquivalenceRequest => "equivalence::t_test",
+        |a, _| finish(equivalence::t_test(&a.data, a.expected_mean, a.hypothesis, a.significance_level, a.missing), Some(a.hypothesis));
+    "/v1/equivalence/paired_t_test": PairedEquivalenceRequest => "equivalence::paired_t_test",
+        |a, _| finish(equivalence::paired_t_test(&a.data1, &a.data2, a.hypothesis, a.significance_level, a.missing), Some(a.hypothesis));
+    "/v1/equivalence/t_test_two_samples": TwoSampleEquivalenceRequest => "equivalence::t_test_two_samples",
+        |a, _| finish(equivalence::t_test_two_samples(&a.data1, &a.data2, a.hypothesis, a.equal_variances, a.significance_level, a.missing), Some(a.hypothesis));
+    "/v1/equivalence/proportions": ProportionsRequest => "equivalence::proportions",
+        |a, _| finish(equivalence::proportions(a.successes1, a.trials1, a.successes2, a.trials2, a.hypothesis, a.significance_level), Some(a.hypothesis));
+    "/v1/bootstrap/t_test": BootstrapRequest => "bootstrap::t_test",
+        |a, limits| limits.resamples(&a.resampling).and_then(|_| finish(bootstrap::t_test(&a.data, a.expected_mean, a.alternative, a.resampling, a.missing), Some(a.alternative)));
+    "/v1/bootstrap/t_test_two_samples": TwoSampleBootstrapRequest => "bootstrap::t_test_two_samples",
+        |a, limits| limits.resamples(&a.resampling).and_then(|_| finish(bootstrap::t_test_two_samples(&a.data1, &a.data2, a.alternative, a.resampling, a.missing), Some(a.alternative)));
+    "/v1/bayes/t_test": BayesFactorRequest => "bayes::t_test",
+        |a, _| finish(bayes::t_test(&a.data, a.expected_mean, a.prior_scale, a.missing), None);
+    "/v1/bayes/t_test_paired": TwoSampleBayesFactorRequest => "bayes::t_test_paired",
+        |a, _| finish(bayes::t_test_paired(&a.data1, &a.data2, a.prior_scale, a.missing), None);
+    "/v1/bayes/t_test_two_samples": TwoSampleBayesFactorRequest => "bayes::t_test_two_samples",
+        |a, _| finish(bayes::t_test_two_samples(&a.data1, &a.data2, a.prior_scale, a.missing), None);
+    "/v1/bayes/t_test_dataless": BayesFactorSummaryRequest => "bayes::t_test_dataless",
+        |a, _| finish(bayes::t_test_dataless(a.statistic, a.sample_size, a.prior_scale), None);
+    "/v1/bayes/t_test_two_samples_dataless": TwoSampleBayesFactorSummaryRequest => "bayes::t_test_two_samples_dataless",
+        |a, _| finish(bayes::t_test_two_samples_dataless(a.statistic, a.sample_size1, a.sample_size2, a.prior_scale), None);
+    "/v1/bayes/beta_binomial": BetaBinomialRequest => "bayes::beta_binomial",
+        |a, _| finish(bayes::beta_binomial(a.successes_a, a.trials_a, a.successes_b, a.trials_b, a.prior, a.credible_level), None);
+    "/v1/bayes/normal_gamma": NormalGammaRequest => "bayes::normal_gamma",
+        |a, _| finish(bayes::normal_gamma(&a.data, a.prior, a.credible_level, a.missing), None);
+    "/v1/bayes/normal_gamma_compare": NormalGammaCompareRequest => "bayes::normal_gamma_compare",
+        |a, _| finish(bayes::normal_gamma_compare(&a.data_a, &a.data_b, a.prior, a.credible_level, a.missing), None);
+    "/v1/multiple/adjust": AdjustRequest => "multiple::adjust",
+        |a, _| finish(multiple::adjust(&a.p_values, a.method, a.level), None);
+    "/v1/multiple/qvalues": QValuesRequest => "multiple::qvalues",
+        |a, _| finish(multiple::qvalues(&a.p_values, a.lambda, a.level), None);
+}
+
+/// Returns the `alpha` query parameter, if there is one.
+fn alpha(query: Option<&str>) -> Result<Option<f64>, ApiError> {
+    let Some(query) = query else { return Ok(None) };
+    for pair in query.split('&') {
+        if let Some(value) = pair.strip_prefix("alpha=") {
+            return match value.parse::<f64>() {
+                Ok(alpha) if alpha > 0.0 && alpha < 1.0 => Ok(Some(alpha)),
+                _ => Err(ApiError::new(400, "invalid_query", format!("`alpha` must be a number between 0 and 1, not `{}`", value))),
+            };
+        }
+    }
+    Ok(None)
+}
+
+/// Handles a single request, given its method, URL (path and query), and body, without any network access.
+/// Panics raised by a test on invalid input are returned as `422` errors with the code `invalid_input`.
+pub fn handle(method: &str, url: &str, body: &[u8]) -> Response {
+    handle_within(method, url, body, &Limits::default())
+}
+
+/// Handles a single request like `handle`, rejecting requests which exceed `limits` instead of the default `Limits`.
+pub fn handle_within(method: &str, url: &str, body: &[u8], limits: &Limits) -> Response {
+    let (path, query) = match url.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (url, None),
+    };
+    match (method, path) {
+        ("GET", "/healthz") => return Response { status: 200, body: to_json(&json!({"status": "ok", "version": env!("CARGO_PKG_VERSION")})) },
+        ("GET", "/openapi.json") => return Response { status: 200, body: to_json(&openapi()) },
+        ("GET", "/v1/tests") => return Response { status: 200, body: to_json(&tests()) },
+        _ => {},
+    }
+    if !ENDPOINTS.iter().any(|(endpoint, _)| *endpoint == path) {
+        return ApiError::new(404, "not_found", format!("there is no endpoint at `{}`", path)).response();
+    }
+    if method != "POST" {
+        return ApiError::new(405, "method_not_allowed", format!("`{}` only accepts POST", path)).response();
+    }
+    let alpha = match alpha(query) {
+        Ok(alpha) => alpha,
+        Err(error) => return error.response(),
+    };
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| dispatch(path, body, limits).unwrap()));
+    let record = match outcome {
+        Ok(Ok(record)) => record,
+        Ok(Err(error)) => return error.response(),
+        Err(payload) => {
+            let message = payload.downcast_ref::<String>().map(String::as_str).or_else(|| payload.downcast_ref::<&str>().copied()).unwrap_or("the test failed");
+            return match message.trim().strip_prefix("[HYTE-Panic] ") {
+                Some(message) => ApiError::new(422, "invalid_input", message),
+                None => ApiError::new(500, "internal_error", message.trim()),
+            }.response();
+        },
+    };
+    let record = match alpha {
+        Some(alpha) => record.conclude(alpha),
+        None => record,
+    };
+    Response { status: 200, body: to_json(&record) }
+}
+
+/// Returns every test of `registry::TESTS`, each with the paths of the endpoints which perform it.
+/// Tests which take summaries or other results rather than data, such as `t::test_stats`, have no endpoint.
+pub fn tests() -> serde_json::Value {
+    registry::TESTS.iter().map(|test| {
+        let mut value = serde_json::to_value(test).unwrap();
+        let name = test.name();
+        let endpoints: Vec<&str> = ENDPOINTS.iter().filter(|(_, endpoint)| *endpoint == name).map(|(path, _)| *path).collect();
+        value["endpoints"] = json!(endpoints);
+        value
+    }).collect()
+}
+
+/// Returns the OpenAPI 3.1 description of every endpoint, with the request and response bodies as JSON Schemas.
+pub fn openapi() -> serde_json::Value {
+    let mut generator = SchemaSettings::draft2020_12().with(|settings| settings.definitions_path = "/components/schemas".into()).into_generator();
+    let requests = request_schemas(&mut generator);
+    let record = generator.subschema_for::<Record>();
+    let error = generator.subschema_for::<ErrorResponse>();
+    let error_response = |description: &str| json!({"description": description, "content": {"application/json": {"schema": error}}});
+    let mut paths = serde_json::Map::new();
+    for ((path, test), request) in ENDPOINTS.iter().zip(requests) {
+        let test = registry::find(test).unwrap();
+        paths.insert(path.to_string(), json!({
+            "post": {
+                "summary": test.summary,
+                "description": test.description(),
+                "parameters": [{
+                    "name": "alpha",
+                    "in": "query",
+                    "required": false,
+                    "description": "Significance level at which the result is concluded",
+                    "schema": {"type": "number", "exclusiveMinimum": 0, "exclusiveMaximum": 1},
+                }],
+                "requestBody": {"required": true, "content": {"application/json": {"schema": request}}},
+                "responses": {
+                    "200": {"description": "The result of the test", "content": {"application/json": {"schema": record}}},
+                    "400": error_response("The body is not valid JSON for this endpoint, or the query is invalid"),
+                    "413": error_response("The body is larger than the server accepts"),
+                    "422": error_response("The test cannot be performed on the given data, or would exceed the limits of the server"),
+                },
+            },
+        }));
+    }
+    paths.insert("/healthz".to_string(), json!({
+        "get": {
+            "summary": "Reports that the server is up",
+            "responses": {"200": {"description": "The server is up", "content": {"application/json": {"schema": {
+                "type": "object",
+                "properties": {"status": {"const": "ok"}, "version": {"type": "string"}},
+            }}}}},
+        },
+    }));
+    paths.insert("/v1/tests".to_string(), json!({
+        "get": {
+            "summary": "Lists every test in hyte, with its parameters, assumptions, result fields, and endpoints",
+            "responses": {"200": {"description": "The tests of `registry::TESTS`", "content": {"application/json": {"schema": {"type": "array", "items": {"type": "object"}}}}}},
+        },
+    }));
+    paths.insert("/openapi.json".to_string(), json!({
+        "get": {"summary": "This document", "responses": {"200": {"description": "The OpenAPI description of the server"}}},
+    }));
+    json!({
+        "openapi": "3.1.0",
+        "info": {"title": "hyte", "version": env!("CARGO_PKG_VERSION"), "description": "Hypothesis tests as JSON endpoints."},
+        "paths": paths,
+        "components": {"schemas": generator.take_definitions(true)},
+    })
+}
+
+/// An HTTP server answering requests with `serve::handle`.
+pub struct Server {
+    server: tiny_http::Server,
+    max_body_bytes: usize,
+    limits: Limits,
+    workers: AtomicUsize,
+}
+
+impl Server {
+    /// Binds a server to `address`, rejecting request bodies larger than `max_body_bytes` with `413`.
+    pub fn bind(address: impl ToSocketAddrs, max_body_bytes: usize) -> io::Result<Server> {
+        let server = tiny_http::Server::http(address).map_err(io::Error::other)?;
+        Ok(Server { server, max_body_bytes, limits: Limits::default(), workers: AtomicUsize::new(0) })
+    }
+
+    /// Rejects requests which exceed `limits` rather than the default `Limits`.
+    pub fn limits(mut self, limits: Limits) -> Server {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the address the server is listening on, such as the port chosen when binding to port 0.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.server.server_addr().to_ip().unwrap()
+    }
+
+    /// Answers requests on `threads` threads, until `stop` is called.
+    pub fn run(&self, threads: usize) {
+        let threads = threads.max(1);
+        self.workers.store(threads, Ordering::SeqCst);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    for request in self.server.incoming_requests() {
+                        self.respond(request);
+                    }
+                });
+            }
+        });
+    }
+
+    /// Makes `run` return once every request in progress has been answered.
+    pub fn stop(&self) {
+        for _ in 0..self.workers.load(Ordering::SeqCst) {
+            self.server.unblock();
+        }
+    }
+
+    fn respond(&self, mut request: tiny_http::Request) {
+        let too_large = || ApiError::new(413, "payload_too_large", format!("the body must not exceed {} bytes", self.max_body_bytes)).response();
+        let response = if request.body_length().is_some_and(|length| length > self.max_body_bytes) {
+            too_large()
+        } else {
+            let mut body = Vec::new();
+            match request.as_reader().take(self.max_body_bytes as u64 + 1).read_to_end(&mut body) {
+                Ok(_) if body.len() > self.max_body_bytes => too_large(),
+                Ok(_) => handle_within(request.method().as_str(), request.url(), &body, &self.limits),
+                Err(error) => ApiError::new(400, "unreadable_body", error.to_string()).response(),
+            }
+        };
+        let header = tiny_http::Header::from_bytes("Content-Type", "application/json").unwrap();
+        let reply = tiny_http::Response::from_string(response.body).with_status_code(response.status).with_header(header);
+        let _ = request.respond(reply);
+    }
+}
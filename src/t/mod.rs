@@ -1,20 +1,61 @@
 //! Module responsible for all items needed to perform T-tests.
 
-use statrs::distribution::StudentsT;
-use statrs::distribution::ContinuousCDF;
-use crate::utils::{Tails, Conclusion, mean, variance}; 
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Conclusion, PValue, MissingPolicy, conclude, Observation, RunningStats, ToPrimitive, Weights, clean, clean_rows, clean_weighted, to_f64, weighted_summary, t_tail, symmetric_p, tailed, TestType};
 
 /// A struct for storing the resulting test statistic and p-value from T-tests.
+///
+/// `log_p` is the natural logarithm of the p-value, which remains finite even when `p` underflows to 0.
+/// `cohens_d` is the standardised mean difference: `(mean - expected_mean) / sd` for one sample, or the difference
+/// between the means divided by `sqrt((variance1 + variance2) / 2)` for two samples.
 #[derive(Debug)]
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TResult {
-    pub test_type: &'static str,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
     pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
     pub df: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
     pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub cohens_d: f64,
+    pub hypotheses: String,
+    pub omitted: usize,
 }
 
-/// Derives observed mean, sample size, and standard deviation from a `Vec<Number>` data,
+/// Lower-tailed, upper-tailed, and two-sided test type names of each T-test.
+const ONE_SAMPLE: [TestType; 3] = [
+    "(1-Sample) One-Sided T-Test for Mean (Lower-Tailed)",
+    "(1-Sample) One-Sided T-Test for Mean (Upper-Tailed)",
+    "(1-Sample) Two-Sided T-Test for Mean",
+];
+const PAIRED: [TestType; 3] = [
+    "(Paired) One-Sided T-Test for Mean Difference (Lower-Tailed)",
+    "(Paired) One-Sided T-Test for Mean Difference (Upper-Tailed)",
+    "(Paired) Two-Sided T-Test for Mean Difference",
+];
+const TWO_SAMPLE: [TestType; 3] = [
+    "(2-Sample) One-Sided T-Test for Mean (Lower-Tailed)",
+    "(2-Sample) One-Sided T-Test for Mean (Upper-Tailed)",
+    "(2-Sample) T-Test for Mean",
+];
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![ONE_SAMPLE, PAIRED, TWO_SAMPLE];
+
+/// Derives observed mean, sample size, and standard deviation from data passed as a `Vec<Number>`, `&Vec<Number>` or `&[Number]`,
 /// then calls `t::test_dataless`.
 /// 
 /// # Examples
@@ -25,11 +66,11 @@ pub struct TResult {
 /// 
 /// ```
 /// use hyte::t;
-/// use hyte::utils::Tails;
+/// use hyte::utils::{Tails, MissingPolicy};
 ///
 /// fn main() {
 ///     let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
-///     let unwrapped_results = t::test(data, 3, Tails::LOWER, true);
+///     let unwrapped_results = t::test(data, 3, Tails::LOWER, MissingPolicy::Propagate);
 ///
 ///     assert_ne!(unwrapped_results, None);
 ///
@@ -37,22 +78,85 @@ pub struct TResult {
 ///     println!("{:#?}", results);
 ///
 ///     assert_eq!(results.test_type, "(1-Sample) One-Sided T-Test for Mean (Lower-Tailed)");
-///     assert_eq!(results.statistic, -1.7320508075688763);
+///     assert_eq!(results.statistic, -1.732050807568876);
 ///     assert_eq!(results.df, 7.0);
 ///     assert_eq!(results.p, 0.06343518346183559);
 /// }
 /// ```
-pub fn test<Number: Into<f64> + Copy, Number2: Into<f64> + Copy>(data: Vec<Number>, expected_mean: Number2, tail: Tails, print_output: bool) -> Option<TResult> {
-    if data.len() == 0 { return None };
-    let observed_mean = mean(&data).unwrap();
-    let sample_size = data.len() as u32;
-    let sd = variance(&data).unwrap().sqrt();
-    test_dataless(observed_mean, expected_mean.into(), sample_size, sd, tail, print_output)
+pub fn test<Number: Observation, Number2: ToPrimitive>(data: impl AsRef<[Number]>, expected_mean: Number2, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<TResult> {
+    let (data, omitted) = clean(data.as_ref(), missing);
+    let stats: RunningStats = data.into_iter().collect();
+    let mut results = test_stats(&stats, expected_mean, alternative)?;
+    results.omitted = omitted;
+    Some(results)
+}
+
+/// Performs the same test as `t::test`, but takes the summaries from a `RunningStats` accumulator instead
+/// of the data itself, so that samples too large to hold in memory can be streamed or merged from chunks.
+/// Returns `None` if the accumulator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::t;
+/// use hyte::utils::{RunningStats, Tails};
+///
+/// fn main() {
+///     let mut stats: RunningStats = vec![2.5, 2.9, 3.1, 2.6].into_iter().collect();
+///     stats.merge(&vec![2.7, 2.8, 3.0, 3.2].into_iter().collect());
+///
+///     let results = t::test_stats(&stats, 3, Tails::LOWER).unwrap();
+///
+///     assert_eq!(results.df, 7.0);
+///     assert!((results.p - 0.06343518346183559).abs() < 1e-12);
+/// }
+/// ```
+pub fn test_stats<Number: ToPrimitive>(stats: &RunningStats, expected_mean: Number, alternative: impl Into<Alternative>) -> Option<TResult> {
+    let observed_mean = stats.mean()?;
+    let sd = stats.sd().unwrap_or_else(|| panic!("\n[HYTE-Panic] The standard deviation cannot be estimated from fewer than 2 observations!\n"));
+    if sd == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+    dataless(observed_mean, to_f64(expected_mean), stats.count() as f64, sd, alternative.into())
+}
+
+/// Performs the same test as `t::test` on weighted data, using the weighted mean and variance (see
+/// `utils::weighted_variance`). The sample size, and so the degrees of freedom `n - 1`, is the sum of the weights
+/// for `Weights::Frequency`, and Kish's effective sample size for `Weights::Reliability`.
+/// Returns `None` if there is no data or every weight is 0.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::t;
+/// use hyte::utils::{Tails, Weights, MissingPolicy};
+///
+/// fn main() {
+///     let data = vec![2.5, 2.9, 3.1, 2.6, 2.7, 2.8, 3.0, 3.2];
+///     let weights = vec![1.2, 0.8, 1.0, 1.5, 0.6, 1.1, 0.9, 1.3];
+///     let results = t::test_weighted(&data, &weights, Weights::Reliability, 3, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+///
+///     assert!(results.df < 7.0);
+///
+///     let repeated = t::test(vec![2.5, 2.5, 2.9, 3.1], 3, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+///     let frequency = t::test_weighted(vec![2.5, 2.9, 3.1], vec![2, 1, 1], Weights::Frequency, 3, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+///     assert!((frequency.p - repeated.p).abs() < 1e-12);
+/// }
+/// ```
+pub fn test_weighted<Number: Observation, Weight: ToPrimitive + Copy, Number2: ToPrimitive>(data: impl AsRef<[Number]>, weights: impl AsRef<[Weight]>, kind: Weights, expected_mean: Number2, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<TResult> {
+    let (data, weights, omitted) = clean_weighted(data.as_ref(), weights.as_ref(), missing);
+    let (observed_mean, variance, sample_size) = weighted_summary(&data, &weights, kind)?;
+    let sd = variance.unwrap_or_else(|| panic!("\n[HYTE-Panic] The standard deviation cannot be estimated from a sample size of 1 or less!\n")).sqrt();
+    if sd == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+    let mut results = dataless(observed_mean, to_f64(expected_mean), sample_size, sd, alternative.into())?;
+    results.omitted = omitted;
+    Some(results)
 }
 
 /// Calculates T-score and p-value, given relevant numerical summaries. 
 /// It returns a `TResult` instance with T-score and p-value as fields `statistic` and `p` respectively.
 /// 
+/// A `pop_sd` of 0 is accepted as a degenerate case: the statistic is infinite with a p-value of 0 or 1,
+/// or NaN when `observed_mean` equals `expected_mean`.
+/// 
 /// # Examples
 /// 
 /// The following is an example of how you can perform a lower-tailed 1-sample T-test.
@@ -64,7 +168,7 @@ pub fn test<Number: Into<f64> + Copy, Number2: Into<f64> + Copy>(data: Vec<Numbe
 /// use hyte::utils::Tails;
 ///
 /// fn main() {
-///     let unwrapped_results = t::test_dataless(1.2, 1.0, 30, 0.5, Tails::LOWER, true);
+///     let unwrapped_results = t::test_dataless(1.2, 1.0, 30, 0.5, Tails::LOWER);
 ///
 ///     assert_ne!(unwrapped_results, None);
 ///
@@ -77,62 +181,51 @@ pub fn test<Number: Into<f64> + Copy, Number2: Into<f64> + Copy>(data: Vec<Numbe
 ///     assert_eq!(results.p, 0.9816776580257508);
 /// }
 /// ```
-pub fn test_dataless<Number: Into<f64> + Copy>(observed_mean: Number, expected_mean: Number, sample_size: u32, pop_sd: Number, tail: Tails, print_output: bool) -> Option<TResult> {
-    if sample_size == 0 { panic!("\n[HYTE-Panic] Sample size must be greater than 0!\n") };
-    if pop_sd.into() < 0.0 { panic!("\n[HYTE-Panic] Standard deviation must not be a negative number!\n") };
+pub fn test_dataless<Number: ToPrimitive>(observed_mean: Number, expected_mean: Number, sample_size: impl ToPrimitive, pop_sd: Number, alternative: impl Into<Alternative>) -> Option<TResult> {
+    dataless(to_f64(observed_mean), to_f64(expected_mean), sample_size.to_u64().unwrap_or(0) as f64, to_f64(pop_sd), alternative.into())
+}
+
+fn dataless(observed_mean: f64, expected_mean: f64, sample_size: f64, pop_sd: f64, alternative: Alternative) -> Option<TResult> {
+    let alternative = alternative.with_null(expected_mean);
+    let tail = alternative.tails();
+    if sample_size <= 0.0 { panic!("\n[HYTE-Panic] Sample size must be greater than 0!\n") };
+    if pop_sd < 0.0 { panic!("\n[HYTE-Panic] Standard deviation must not be a negative number!\n") };
     
-    let statistic: f64 = (observed_mean.into() - expected_mean.into()) / (pop_sd.into() / (sample_size as f64).sqrt());
-    let df = (sample_size - 1) as f64;
-    let t_distribution = StudentsT::new(0.0, 1.0, df).unwrap();
-    let p: f64;
-    let test_type: &'static str;
-
-    match tail {
-        Tails::LOWER => {
-            p = t_distribution.cdf(statistic);
-            test_type = "(1-Sample) One-Sided T-Test for Mean (Lower-Tailed)";
-        },
-        Tails::UPPER => {
-            p = 1.0 - t_distribution.cdf(statistic);
-            test_type = "(1-Sample) One-Sided T-Test for Mean (Upper-Tailed)";
-        },
-        Tails::BOTH => {
-            p = 2.0 * t_distribution.cdf(-statistic.abs());
-            test_type = "(1-Sample) Two-Sided T-Test for Mean"
-        },
-    }
+    let statistic: f64 = (observed_mean - expected_mean) / (pop_sd / sample_size.sqrt());
+    let df = sample_size - 1.0;
+    let (p, log_p) = symmetric_p(statistic, &tail, |t| t_tail(t, df));
+    let test_type = tailed(ONE_SAMPLE, &tail);
 
     let results = TResult {
         test_type,
         statistic,
         df,
         p,
+        log_p,
+        cohens_d: (observed_mean - expected_mean) / pop_sd,
+        hypotheses: alternative.statement("μ"),
+        omitted: 0,
     };
 
-    if print_output {
-        match tail {
-            Tails::LOWER | Tails::UPPER => println!("\n----------------------- HYTE -----------------------\n\n{}\n\nZ test statistic = {:.2}\np-value = {:.3e}\nDegrees of freedom = {}\n\n----------------------- HYTE -----------------------\n", results.test_type, results.statistic, results.p, results.df),
-            Tails::BOTH => println!("\n--------------- HYTE ---------------\n\n{}\n\nZ test statistic = {:.2}\np-value = {:.3e}\nDegrees of freedom = {}\n\n--------------- HYTE ---------------\n", results.test_type, results.statistic, results.p, results.df),
-        }
-    }
-
     Some(results)
 }
 
-/// Calculates T-score and p-value for a 2-sample T-test, given two groups of data each of type `Vec<Number>``. 
+/// Calculates T-score and p-value for a 2-sample T-test, given two groups of data each passed as a `Vec<Number>`, `&Vec<Number>` or `&[Number]`. 
 /// It returns a `TResult` instance with T-score and p-value as fields `statistic` and `p` respectively.
 /// 
 /// # Examples
 /// 
 /// The following is an example of how you can perform a 2-sample T-test.
+/// To test a non-zero difference `μ₁ - μ₂ = Δ₀`, pass in an `Alternative` such as `Alternative::two_sided(Δ₀)`.
 /// 
 /// ```
 /// use hyte::t;
+/// use hyte::utils::{Tails, MissingPolicy};
 ///
 /// fn main() {
 ///     let group1 = vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18];
 ///     let group2 = vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24];
-///     let unwrapped_results = t::test_two_samples(group1, group2, true);
+///     let unwrapped_results = t::test_two_samples(group1, group2, Tails::BOTH, MissingPolicy::Propagate);
 ///
 ///     assert_ne!(unwrapped_results, None);
 ///
@@ -141,63 +234,176 @@ pub fn test_dataless<Number: Into<f64> + Copy>(observed_mean: Number, expected_m
 ///
 ///     assert_eq!(results.test_type, "(2-Sample) T-Test for Mean");
 ///     assert_eq!(results.statistic, -6.196773353931866);
-///     assert_eq!(results.df, 16.513761467889907);
-///     assert_eq!(results.p, 0.000011111614734799414);
+///     assert_eq!(results.df, 16.513761467889914);
+///     assert_eq!(results.p, 0.000011111614734799805);
+///     assert_eq!(results.hypotheses, "H₀: μ₁ - μ₂ = 0.0 vs H₁: μ₁ - μ₂ ≠ 0.0");
 /// }
 /// ```
-pub fn test_two_samples<Number: Into<f64> + Copy>(data1: Vec<Number>, data2: Vec<Number>, print_output: bool) -> Option<TResult> {
-    if data1.len() == 0 {
-        return None
-    } else if data2.len() == 0 {
-        return None
+pub fn test_two_samples<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<TResult> {
+    let (data1, omitted1) = clean(data1.as_ref(), missing);
+    let (data2, omitted2) = clean(data2.as_ref(), missing);
+    let stats1: RunningStats = data1.into_iter().collect();
+    let stats2: RunningStats = data2.into_iter().collect();
+    let mut results = test_two_samples_stats(&stats1, &stats2, alternative)?;
+    results.omitted = omitted1 + omitted2;
+    Some(results)
+}
+
+/// Performs the same test as `t::test_two_samples`, but takes the summaries of each group from a
+/// `RunningStats` accumulator. Returns `None` if either accumulator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::t;
+/// use hyte::utils::{RunningStats, Tails};
+///
+/// fn main() {
+///     let group1: RunningStats = vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18].into_iter().collect();
+///     let group2: RunningStats = vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24].into_iter().collect();
+///     let results = t::test_two_samples_stats(&group1, &group2, Tails::BOTH).unwrap();
+///
+///     assert_eq!(results.test_type, "(2-Sample) T-Test for Mean");
+///     assert_eq!(results.statistic, -6.196773353931866);
+/// }
+/// ```
+pub fn test_two_samples_stats(stats1: &RunningStats, stats2: &RunningStats, alternative: impl Into<Alternative>) -> Option<TResult> {
+    let (data1_mean, data2_mean) = (stats1.mean()?, stats2.mean()?);
+    let (data1_variance, data2_variance) = match (stats1.variance(), stats2.variance()) {
+        (Some(variance1), Some(variance2)) => (variance1, variance2),
+        _ => panic!("\n[HYTE-Panic] Each sample must contain at least 2 observations!\n"),
     };
-    let data1_mean = mean(&data1).unwrap();
-    let data2_mean = mean(&data2).unwrap();
-    let data1_variance = variance(&data1).unwrap();
-    let data2_variance = variance(&data2).unwrap();
-    let n1 = data1.len() as f64;
-    let n2 = data2.len() as f64;
+    welch((data1_mean, data1_variance, stats1.count() as f64), (data2_mean, data2_variance, stats2.count() as f64), alternative.into())
+}
+
+/// Performs a paired T-test on the mean of the differences `data1 - data2`, which must have equal lengths.
+/// A pair is treated as missing if either of its values is. The null difference defaults to 0, and another
+/// can be tested by passing an `Alternative` such as `Alternative::greater(0.5)`.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::t;
+/// use hyte::utils::{Tails, MissingPolicy};
+///
+/// fn main() {
+///     let before = vec![72, 75, 68, 80, 77, 74];
+///     let after = vec![70, 73, 69, 76, 74, 70];
+///     let results = t::test_paired(before, after, Tails::UPPER, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "(Paired) One-Sided T-Test for Mean Difference (Upper-Tailed)");
+///     assert_eq!(results.df, 5.0);
+///     assert_eq!(results.hypotheses, "H₀: μ₁ - μ₂ = 0.0 vs H₁: μ₁ - μ₂ > 0.0");
+/// }
+/// ```
+pub fn test_paired<Number: Observation>(data1: impl AsRef<[Number]>, data2: impl AsRef<[Number]>, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<TResult> {
+    let (data1, data2) = (data1.as_ref(), data2.as_ref());
+    if data1.is_empty() || data2.is_empty() { return None };
+    if data1.len() != data2.len() {
+        panic!("\n[HYTE-Panic] The lengths of data1 ({}) and data2 ({}) do not match!\n", data1.len(), data2.len());
+    }
+    let (pairs, omitted) = clean_rows(&[data1, data2], missing);
+    let stats: RunningStats = pairs[0].iter().zip(&pairs[1]).map(|(x, y)| x - y).collect();
+    let alternative = alternative.into().or_null(0.0);
+    let mut results = test_stats(&stats, alternative.null_value.unwrap(), alternative)?;
+    results.test_type = tailed(PAIRED, &alternative.tails());
+    results.hypotheses = alternative.statement("μ₁ - μ₂");
+    results.omitted = omitted;
+    Some(results)
+}
+
+/// Performs Welch's T-test on the difference between the means of two samples summarised as `(mean, variance, n)`.
+fn welch(sample1: (f64, f64, f64), sample2: (f64, f64, f64), alternative: Alternative) -> Option<TResult> {
+    let (data1_mean, data1_variance, n1) = sample1;
+    let (data2_mean, data2_variance, n2) = sample2;
+    if data1_variance == 0.0 && data2_variance == 0.0 { panic!("\n[HYTE-Panic] Both samples have zero variance, so the test statistic is undefined!\n") };
 
     let numerator = ((data1_variance / n1) + (data2_variance / n2)).powi(2);
     let denominator = (data1_variance.powi(2) / (n1 * n1 * (n1 - 1.0))) + (data2_variance.powi(2) / (n2 * n2 * (n2 - 1.0)));
     let df = numerator / denominator;
     
-    let statistic = (data1_mean - data2_mean) / ((data1_variance / n1) + (data2_variance / n2)).sqrt();
+    let alternative = alternative.or_null(0.0);
+    let difference = alternative.null_value.unwrap();
+    let statistic = (data1_mean - data2_mean - difference) / ((data1_variance / n1) + (data2_variance / n2)).sqrt();
 
-    let t_distribution = StudentsT::new(0.0, 1.0, df as f64).unwrap();
-    let p = 2.0 * t_distribution.cdf(-statistic.abs());   
+    let tail = alternative.tails();
+    let (p, log_p) = symmetric_p(statistic, &tail, |t| t_tail(t, df));
+    let test_type = tailed(TWO_SAMPLE, &tail);
     
     let results = TResult {
-        test_type: "(2-Sample) T-Test for Mean",
+        test_type,
         statistic,
         df,
         p,
+        log_p,
+        cohens_d: (data1_mean - data2_mean) / ((data1_variance + data2_variance) / 2.0).sqrt(),
+        hypotheses: alternative.statement("μ₁ - μ₂"),
+        omitted: 0,
     };
 
-    if print_output {println!("\n---------- HYTE ----------\n\n{}\n\nZ test statistic = {:.2}\np-value = {:.3e}\nDegrees of freedom = {:.2}\n\n---------- HYTE ----------\n", results.test_type, results.statistic, results.p, results.df)};
+    Some(results)
+}
 
+/// Performs the same test as `t::test_two_samples` on weighted data. Each group's sample size, used in both the
+/// standard error and the Welch-Satterthwaite degrees of freedom, is determined by `kind` as in `t::test_weighted`.
+/// Returns `None` if either group has no data or only zero weights.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::t;
+/// use hyte::utils::{Tails, Weights, MissingPolicy};
+///
+/// fn main() {
+///     let group1 = vec![20, 22, 19, 20, 21, 20, 19, 21, 22, 18];
+///     let group2 = vec![22, 24, 23, 24, 25, 23, 24, 23, 22, 24];
+///     let ones = vec![1; 10];
+///     let weighted = t::test_two_samples_weighted(&group1, &ones, &group2, &ones, Weights::Reliability, Tails::BOTH, MissingPolicy::Propagate).unwrap();
+///
+///     assert!((weighted.statistic - t::test_two_samples(group1, group2, Tails::BOTH, MissingPolicy::Propagate).unwrap().statistic).abs() < 1e-12);
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn test_two_samples_weighted<Number: Observation, Weight: ToPrimitive + Copy>(data1: impl AsRef<[Number]>, weights1: impl AsRef<[Weight]>, data2: impl AsRef<[Number]>, weights2: impl AsRef<[Weight]>, kind: Weights, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<TResult> {
+    let (data1, weights1, omitted1) = clean_weighted(data1.as_ref(), weights1.as_ref(), missing);
+    let (data2, weights2, omitted2) = clean_weighted(data2.as_ref(), weights2.as_ref(), missing);
+    let (mean1, variance1, n1) = weighted_summary(&data1, &weights1, kind)?;
+    let (mean2, variance2, n2) = weighted_summary(&data2, &weights2, kind)?;
+    let (variance1, variance2) = match (variance1, variance2) {
+        (Some(variance1), Some(variance2)) => (variance1, variance2),
+        _ => panic!("\n[HYTE-Panic] Each sample must have a sample size greater than 1!\n"),
+    };
+    let mut results = welch((mean1, variance1, n1), (mean2, variance2, n2), alternative.into())?;
+    results.omitted = omitted1 + omitted2;
     Some(results)
 }
 
 impl TResult {
     /// Concludes if a `TResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
-    pub fn conclude(&self, significance_level: f64, print_output: bool) -> Conclusion {
-        if self.p < significance_level {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = ({:.3e})\n\np-value < s. l, therefore reject H_0\n\nThere is sufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p, significance_level);};
-            Conclusion::Reject
-        } else {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = ({:.3e})\n\np-value > s. l, therefore do not reject H_0\n\nThere is insufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p, significance_level);};
-            Conclusion::DoNotReject
-        }
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
     }
+
     /// Concludes if a `TResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
-    pub fn conclude_by_convention(&self, print_output: bool) -> Conclusion {
-        if self.p < 0.05 {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = 0.5\n\np-value < s. l, therefore reject H_0\n\nThere is sufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p);};
-            Conclusion::Reject
-        } else {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = 0.5\n\np-value > s. l, therefore do not reject H_0\n\nThere is insufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p);};
-            Conclusion::DoNotReject
-        }
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
     }
-}
\ No newline at end of file
+}
+
+impl PValue for TResult {
+    fn p_value(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Reportable for TResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("T test statistic", format!("{:.2}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .row("Degrees of freedom", report::df(self.df))
+            .row("Cohen's d", format!("{:.2}", self.cohens_d))
+            .apa(format!("t({}) = {:.2}, {}, d = {:.2}", report::df(self.df), self.statistic, report::apa_p(self.p), self.cohens_d))
+    }
+}
@@ -1,27 +1,547 @@
 //! Module for data structures and additional utilities which the `z`, `t`, and `chisquare` modules depend on.
 
-/// An enum for representing multidimensional vectors
-pub enum Matrix<Number> {
-    TwoDimensional(Vec<Vec<Number>>),
-    OneDimensional(Vec<Number>),
+use std::f64::consts::{LN_2, PI};
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal, StudentsT};
+use statrs::function::beta::{beta_reg, ln_beta};
+use statrs::function::gamma::ln_gamma;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Re-exported so that callers can name the bound on numeric input without depending on `num-traits` themselves.
+pub use num_traits::ToPrimitive;
+
+/// The name of a test, as stored in the `test_type` field of every result.
+///
+/// Every name is a string literal of `hyte`. Results deserialised with the `serde` feature take the matching name
+/// from those `hyte` produces, so they do not borrow from their input, and any other name is rejected.
+pub type TestType = &'static str;
+
+/// Every name a test in `hyte` stores in `test_type`, gathered from the `TEST_TYPES` of each module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = test_types![
+    crate::z::TEST_TYPES,
+    crate::t::TEST_TYPES,
+    crate::chisquare::TEST_TYPES,
+    crate::normality::TEST_TYPES,
+    crate::ks::TEST_TYPES,
+    crate::homogeneity::TEST_TYPES,
+    crate::correlation::TEST_TYPES,
+    crate::regression::TEST_TYPES,
+    crate::binomial::TEST_TYPES,
+    crate::poisson::TEST_TYPES,
+    crate::equivalence::TEST_TYPES,
+    crate::permutation::TEST_TYPES,
+    crate::bootstrap::TEST_TYPES,
+    crate::bayes::TEST_TYPES,
+    crate::multiple::TEST_TYPES,
+];
+
+/// Flattens constant arrays or slices of test type names into a single `&'static [TestType]`, so that the
+/// `TEST_TYPES` of a module is assembled from the same constants its tests take their names from.
+#[cfg(feature = "serde")]
+macro_rules! test_types {
+    ($($group:expr),* $(,)?) => {{
+        const COUNT: usize = 0 $(+ $group.len())*;
+        const FLAT: [$crate::utils::TestType; COUNT] = {
+            let mut flat = [""; COUNT];
+            let mut at = 0;
+            $(
+                let group: &[$crate::utils::TestType] = &$group;
+                let mut i = 0;
+                while i < group.len() {
+                    flat[at] = group[i];
+                    at += 1;
+                    i += 1;
+                }
+            )*
+            flat
+        };
+        &FLAT
+    }};
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use test_types;
+
+/// Picks the name of a test for `tail` from its lower-tailed, upper-tailed, and two-sided names, in that order.
+pub(crate) fn tailed(test_types: [TestType; 3], tail: &Tails) -> TestType {
+    match tail {
+        Tails::LOWER => test_types[0],
+        Tails::UPPER => test_types[1],
+        Tails::BOTH => test_types[2],
+    }
+}
+
+/// An enum for representing multidimensional vectors, whose rows may be owned or borrowed,
+/// e.g. `Vec<f64>`, `&[i32]` or `&Vec<Option<f64>>`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Matrix<Row> {
+    TwoDimensional(Vec<Row>),
+    OneDimensional(Row),
 }
 
 /// An enum for specifying if a 1-sample test is lower-tailed, upper-tailed, or 2-sided.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum Tails {
     LOWER,
     UPPER,
     BOTH,
 }
 
+/// An enum for specifying the direction of an alternative hypothesis relative to the null value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Direction {
+    Less,
+    Greater,
+    TwoSided,
+}
+
+/// A struct for specifying the alternative hypothesis of a test, which every test taking `Tails` also accepts.
+///
+/// `null_value` is the hypothesised value of the parameter, such as μ₀ or Δ₀. If it is `None`, the test's
+/// default is used (e.g. the expected mean of a 1-sample test, or 0 for a difference).
+/// `margins` are only used by the `equivalence` module. With `Direction::TwoSided`, the alternative is that
+/// the parameter lies strictly between `null_value + margins.0` and `null_value + margins.1`; with
+/// `Direction::Greater` it is above `null_value + margins.0`, and with `Direction::Less` below `null_value + margins.1`.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::{Alternative, Tails};
+///
+/// fn main() {
+///     let alternative = Alternative::less(4.0);
+///     assert_eq!(alternative.statement("μ"), "H₀: μ = 4.0 vs H₁: μ < 4.0");
+///
+///     let from_tails: Alternative = Tails::BOTH.into();
+///     assert_eq!(from_tails, Alternative { direction: hyte::utils::Direction::TwoSided, null_value: None, margins: None });
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Alternative {
+    pub direction: Direction,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub null_value: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<(crate::utils::non_finite::Float, crate::utils::non_finite::Float)>"))]
+    pub margins: Option<(f64, f64)>,
+}
+
+impl Alternative {
+    /// Creates a lower-tailed alternative `H₁: θ < null_value`.
+    pub fn less(null_value: f64) -> Alternative {
+        Alternative { direction: Direction::Less, null_value: Some(null_value), margins: None }
+    }
+
+    /// Creates an upper-tailed alternative `H₁: θ > null_value`.
+    pub fn greater(null_value: f64) -> Alternative {
+        Alternative { direction: Direction::Greater, null_value: Some(null_value), margins: None }
+    }
+
+    /// Creates a two-sided alternative `H₁: θ ≠ null_value`.
+    pub fn two_sided(null_value: f64) -> Alternative {
+        Alternative { direction: Direction::TwoSided, null_value: Some(null_value), margins: None }
+    }
+
+    /// Creates an equivalence alternative `H₁: null_value + lower_margin < θ < null_value + upper_margin`.
+    pub fn equivalence(null_value: f64, lower_margin: f64, upper_margin: f64) -> Alternative {
+        Alternative { direction: Direction::TwoSided, null_value: Some(null_value), margins: Some((lower_margin, upper_margin)) }
+    }
+
+    /// Renders the null and alternative hypotheses about `parameter` as a human-readable statement,
+    /// such as `H₀: μ = 4.0 vs H₁: μ < 4.0`.
+    pub fn statement(&self, parameter: &str) -> String {
+        let null_value = self.null_value.unwrap_or(0.0);
+        match (self.margins, self.direction) {
+            (None, direction) => statement(parameter, &format!("{:?}", null_value), direction),
+            (Some((lower, upper)), Direction::TwoSided) => {
+                let (lower, upper) = (null_value + lower, null_value + upper);
+                format!("H₀: {p} ≤ {lower:?} or {p} ≥ {upper:?} vs H₁: {lower:?} < {p} < {upper:?}", p = parameter)
+            }
+            (Some((lower, _)), Direction::Greater) => format!("H₀: {p} ≤ {b:?} vs H₁: {p} > {b:?}", p = parameter, b = null_value + lower),
+            (Some((_, upper)), Direction::Less) => format!("H₀: {p} ≥ {b:?} vs H₁: {p} < {b:?}", p = parameter, b = null_value + upper),
+        }
+    }
+
+    /// Converts the direction back into `Tails`.
+    pub(crate) fn tails(&self) -> Tails {
+        match self.direction {
+            Direction::Less => Tails::LOWER,
+            Direction::Greater => Tails::UPPER,
+            Direction::TwoSided => Tails::BOTH,
+        }
+    }
+
+    /// Fills in `default` if no null value was given, for tests which accept any null value.
+    pub(crate) fn or_null(self, default: f64) -> Alternative {
+        self.reject_margins();
+        self.keeping_margins(default, false)
+    }
+
+    /// Fills in `expected` as the null value, for tests whose null value is already passed in as an argument.
+    pub(crate) fn with_null(self, expected: f64) -> Alternative {
+        self.reject_margins();
+        self.keeping_margins(expected, true)
+    }
+
+    /// Same as `with_null` if `fixed` is `true`, or `or_null` otherwise, but allows equivalence margins.
+    pub(crate) fn keeping_margins(self, null_value: f64, fixed: bool) -> Alternative {
+        match self.null_value {
+            Some(given) if fixed && given != null_value => {
+                panic!("\n[HYTE-Panic] The null value of the alternative ({}) does not match the expected value ({})!\n", given, null_value);
+            }
+            Some(_) => self,
+            None => Alternative { null_value: Some(null_value), ..self },
+        }
+    }
+
+    /// Fills in `only` as the null value, for tests which cannot test any other null value.
+    pub(crate) fn only_null(self, only: f64, test_name: &str) -> Alternative {
+        if self.null_value.is_some_and(|null_value| null_value != only) {
+            panic!("\n[HYTE-Panic] {} only supports a null value of {:?}!\n", test_name, only);
+        }
+        self.or_null(only)
+    }
+
+    /// Checks that no null value was given, for tests whose null hypothesis is not about a single value.
+    pub(crate) fn without_null(self, test_name: &str) -> Alternative {
+        self.reject_margins();
+        if self.null_value.is_some() { panic!("\n[HYTE-Panic] {} does not take a null value!\n", test_name) };
+        self
+    }
+
+    fn reject_margins(&self) {
+        if self.margins.is_some() { panic!("\n[HYTE-Panic] Equivalence margins are only supported by the `equivalence` module!\n") };
+    }
+}
+
+impl From<Tails> for Alternative {
+    fn from(tail: Tails) -> Alternative {
+        let direction = match tail {
+            Tails::LOWER => Direction::Less,
+            Tails::UPPER => Direction::Greater,
+            Tails::BOTH => Direction::TwoSided,
+        };
+        Alternative { direction, null_value: None, margins: None }
+    }
+}
+
+/// Renders `H₀: parameter = null vs H₁: parameter <op> null` for a `direction`.
+pub(crate) fn statement(parameter: &str, null: &str, direction: Direction) -> String {
+    let operator = match direction {
+        Direction::Less => "<",
+        Direction::Greater => ">",
+        Direction::TwoSided => "≠",
+    };
+    format!("H₀: {p} = {null} vs H₁: {p} {operator} {null}", p = parameter)
+}
+
 /// An enum for concluding if a test result should be rejected.
 #[derive(PartialEq)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Conclusion {
     Reject,
     DoNotReject,
 }
 
-/// Computes the average of a vector/array of numbers `$[Number]`
+/// An enum for choosing how tests treat missing observations, which are `None` values and NaNs.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum MissingPolicy {
+    /// Keep missing observations as NaN and pass them to the test unchanged. Most tests then return a NaN statistic
+    /// and p-value, so this is only appropriate when the data is known to be complete.
+    Propagate,
+    /// Remove missing observations before testing. Paired observations, such as the `x` and `y` of a correlation
+    /// or the rows of a regression or of a contingency table, are removed together when any of their values is missing.
+    Omit,
+    /// Panic if any observation is missing.
+    Error,
+}
+
+/// A single observation which may be missing: any primitive integer or float, which is missing if it is NaN,
+/// or an `Option` of one, which is also missing if it is `None`.
+pub trait Observation: Copy {
+    /// Returns the observation as an `f64`, or `None` if it is missing.
+    fn value(self) -> Option<f64>;
+}
+
+macro_rules! impl_observation {
+    ($($number:ty),*) => {
+        $(
+            impl Observation for $number {
+                fn value(self) -> Option<f64> {
+                    let value = self as f64;
+                    if value.is_nan() { None } else { Some(value) }
+                }
+            }
+
+            impl Observation for Option<$number> {
+                fn value(self) -> Option<f64> {
+                    self.and_then(Observation::value)
+                }
+            }
+        )*
+    };
+}
+
+impl_observation!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+pub(crate) fn missing_error(count: usize) -> ! {
+    panic!("\n[HYTE-Panic] The data contains {} missing observation{}!\n", count, if count == 1 { "" } else { "s" })
+}
+
+/// Applies `policy` to a sample, returning the values to test and the number of observations omitted.
+pub(crate) fn clean<Number: Observation>(data: &[Number], policy: MissingPolicy) -> (Vec<f64>, usize) {
+    let values: Vec<Option<f64>> = data.iter().map(|&x| x.value()).collect();
+    let missing = values.iter().filter(|value| value.is_none()).count();
+    match policy {
+        MissingPolicy::Propagate => (values.iter().map(|value| value.unwrap_or(f64::NAN)).collect(), 0),
+        MissingPolicy::Omit => (values.into_iter().flatten().collect(), missing),
+        MissingPolicy::Error => {
+            if missing > 0 { missing_error(missing) };
+            (values.into_iter().flatten().collect(), 0)
+        }
+    }
+}
+
+/// Applies `policy` to columns of equal length whose values are paired by row, removing a whole row when
+/// any of its values is missing. Returns the cleaned columns and the number of rows omitted.
+pub(crate) fn clean_rows<Number: Observation>(columns: &[&[Number]], policy: MissingPolicy) -> (Vec<Vec<f64>>, usize) {
+    let columns: Vec<Vec<Option<f64>>> = columns.iter().map(|column| column.iter().map(|&x| x.value()).collect()).collect();
+    let rows = columns.first().map_or(0, Vec::len);
+    let complete: Vec<bool> = (0..rows).map(|row| columns.iter().all(|column| column[row].is_some())).collect();
+    let missing = complete.iter().filter(|&&complete| !complete).count();
+    match policy {
+        MissingPolicy::Propagate => (columns.iter().map(|column| column.iter().map(|value| value.unwrap_or(f64::NAN)).collect()).collect(), 0),
+        MissingPolicy::Omit | MissingPolicy::Error => {
+            if policy == MissingPolicy::Error && missing > 0 { missing_error(missing) };
+            let kept = columns.iter().map(|column| column.iter().zip(&complete).filter(|(_, &complete)| complete).map(|(value, _)| value.unwrap()).collect()).collect();
+            (kept, missing)
+        }
+    }
+}
+
+/// Applies `policy` to weighted data, removing the weights of omitted observations along with them.
+pub(crate) fn clean_weighted<Number: Observation, Weight: ToPrimitive + Copy>(data: &[Number], weights: &[Weight], policy: MissingPolicy) -> (Vec<f64>, Vec<f64>, usize) {
+    if data.len() != weights.len() {
+        panic!("\n[HYTE-Panic] The lengths of data ({}) and weights ({}) do not match!\n", data.len(), weights.len());
+    }
+    let weights: Vec<f64> = weights.iter().map(|&w| to_f64(w)).collect();
+    if policy == MissingPolicy::Omit {
+        let kept: Vec<(f64, f64)> = data.iter().zip(&weights).filter_map(|(&x, &w)| Some((x.value()?, w))).collect();
+        let omitted = data.len() - kept.len();
+        let (data, weights) = kept.into_iter().unzip();
+        return (data, weights, omitted);
+    }
+    (clean(data, policy).0, weights, 0)
+}
+
+/// A struct for configuring how tests which resample their data, such as `permutation::test` or `bootstrap::interval`,
+/// draw resamples.
+///
+/// `resamples` random resamples are drawn from a ChaCha8 generator seeded with `seed`, so the same seed always gives
+/// the same p-value or interval. Permutation tests instead evaluate every permutation when there are at most `exact_limit` of them.
+/// With the `parallel` feature, setting `parallel` draws the resamples on rayon's global thread pool. Resamples are
+/// drawn in fixed blocks, each from its own stream of the generator, so the result does not depend on `parallel`
+/// or on the number of threads. When deserialised, any field left out takes its default.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::Resampling;
+///
+/// fn main() {
+///     let resampling = Resampling { seed: 7, ..Resampling::default() };
+///
+///     assert_eq!(resampling.resamples, 9999);
+///     assert_eq!(resampling, Resampling::seeded(7));
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Resampling {
+    pub resamples: usize,
+    pub seed: u64,
+    pub exact_limit: usize,
+    pub parallel: bool,
+}
+
+impl Default for Resampling {
+    fn default() -> Resampling {
+        Resampling { resamples: 9999, seed: 0, exact_limit: 10000, parallel: false }
+    }
+}
+
+impl Resampling {
+    /// Returns the default settings with a different `seed`.
+    pub fn seeded(seed: u64) -> Resampling {
+        Resampling { seed, ..Resampling::default() }
+    }
+}
+
+/// Number of resamples drawn from each stream of the generator.
+const RESAMPLE_BLOCK: usize = 1024;
+
+/// Splits `resampling.resamples` into blocks and calls `draw` with the generator and size of each block,
+/// returning what it returns for every block in order.
+pub(crate) fn resample<T: Send>(resampling: &Resampling, draw: impl Fn(&mut ChaCha8Rng, usize) -> T + Sync) -> Vec<T> {
+    if resampling.resamples == 0 { panic!("\n[HYTE-Panic] The number of resamples must be at least 1!\n") };
+    let blocks = resampling.resamples.div_ceil(RESAMPLE_BLOCK);
+    let block = |index: usize| {
+        let mut rng = ChaCha8Rng::seed_from_u64(resampling.seed);
+        rng.set_stream(index as u64);
+        draw(&mut rng, RESAMPLE_BLOCK.min(resampling.resamples - index * RESAMPLE_BLOCK))
+    };
+    #[cfg(feature = "parallel")]
+    if resampling.parallel {
+        use rayon::prelude::*;
+        return (0..blocks).into_par_iter().map(block).collect();
+    }
+    (0..blocks).map(block).collect()
+}
+
+/// A struct for accumulating the count, sum, mean, and variance of a stream of numbers in a single pass,
+/// without storing them.
+///
+/// The mean and sum of squared deviations are updated with Welford's algorithm, and the sum is kept with
+/// Neumaier's compensated summation. Accumulators built over separate chunks of data can be combined with
+/// `merge`, which uses the parallel algorithm of Chan et al. (1979). A `RunningStats` can be passed directly to
+/// `z::test_stats`, `t::test_stats`, and `t::test_two_samples_stats`.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::RunningStats;
+///
+/// fn main() {
+///     let mut first: RunningStats = vec![1, 2, 3].into_iter().collect();
+///     let mut second = RunningStats::new();
+///     for x in [4, 5, 6] {
+///         second.push(x);
+///     }
+///     first.merge(&second);
+///
+///     assert_eq!(first.count(), 6);
+///     assert_eq!(first.mean(), Some(3.5));
+///     assert_eq!(first.variance(), Some(3.5));
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    sum: f64,
+    compensation: f64,
+}
+
+impl RunningStats {
+    /// Creates an empty accumulator.
+    pub fn new() -> RunningStats {
+        RunningStats::default()
+    }
+
+    /// Adds a single value.
+    pub fn push<Number: ToPrimitive>(&mut self, value: Number) {
+        let value = to_f64(value);
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.add_to_sum(value);
+    }
+
+    /// Combines the values accumulated by `other` into `self`, as if they had all been pushed to `self`.
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 { return };
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let (n1, n2, n) = (self.count as f64, other.count as f64, count as f64);
+        self.mean += delta * n2 / n;
+        self.m2 += other.m2 + delta * delta * n1 * n2 / n;
+        self.count = count;
+        self.add_to_sum(other.sum);
+        self.compensation += other.compensation;
+    }
+
+    /// Neumaier's variant of Kahan summation, which also handles terms larger than the running sum.
+    fn add_to_sum(&mut self, value: f64) {
+        let total = self.sum + value;
+        self.compensation += if self.sum.abs() >= value.abs() { (self.sum - total) + value } else { (value - total) + self.sum };
+        self.sum = total;
+    }
+
+    /// Returns the number of values accumulated.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the compensated sum of the values accumulated.
+    pub fn sum(&self) -> f64 {
+        self.sum + self.compensation
+    }
+
+    /// Returns the mean, or `None` if no values have been accumulated.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 { return None };
+        Some(self.sum() / self.count as f64)
+    }
+
+    /// Returns the sample variance (with divisor `n - 1`), or `None` if fewer than 2 values have been accumulated.
+    pub fn variance(&self) -> Option<f64> {
+        if self.count < 2 { return None };
+        // Clamp rounding error below zero without turning a NaN into 0, as `f64::max` would
+        let m2 = if self.m2 < 0.0 { 0.0 } else { self.m2 };
+        Some(m2 / (self.count - 1) as f64)
+    }
+
+    /// Returns the sample standard deviation, or `None` if fewer than 2 values have been accumulated.
+    pub fn sd(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+}
+
+impl<Number: ToPrimitive> Extend<Number> for RunningStats {
+    fn extend<I: IntoIterator<Item = Number>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+impl<Number: ToPrimitive> FromIterator<Number> for RunningStats {
+    fn from_iter<I: IntoIterator<Item = Number>>(values: I) -> RunningStats {
+        let mut stats = RunningStats::new();
+        stats.extend(values);
+        stats
+    }
+}
+
+/// Converts any primitive integer or float to `f64`, panicking for values of other `ToPrimitive` types
+/// which have no `f64` representation.
+pub(crate) fn to_f64<Number: ToPrimitive>(value: Number) -> f64 {
+    value.to_f64().unwrap_or_else(|| panic!("\n[HYTE-Panic] Every value must be representable as an f64!\n"))
+}
+
+/// Computes the average of a vector/array of numbers `$[Number]` using compensated summation
 /// 
 /// # Examples
 /// ```
@@ -35,22 +555,12 @@ pub enum Conclusion {
 ///     assert_eq!(mean, 5.5);
 /// }
 /// ```
-pub fn mean<Number: Into<f64> + Copy>(numbers: &[Number]) -> Option<f64> {
-    let mut sum: f64 = 0.0;
-    for &num in numbers {
-        sum += num.into();
-    }
-    let count = numbers.len();
-
-    // Check to prevent division by zero
-    if count > 0 {
-        Some(sum / count as f64)
-    } else {
-        None
-    }
+pub fn mean<Number: ToPrimitive + Copy>(numbers: &[Number]) -> Option<f64> {
+    numbers.iter().copied().collect::<RunningStats>().mean()
 }
 
-/// Computes the variance of a vector/array of numbers `$[Number]`
+/// Computes the sample variance of a vector/array of numbers `$[Number]` in a single pass.
+/// Returns `None` if there are fewer than 2 numbers, since the sample variance is then undefined.
 /// 
 /// # Examples
 /// ```
@@ -62,21 +572,828 @@ pub fn mean<Number: Into<f64> + Copy>(numbers: &[Number]) -> Option<f64> {
 ///     println!("variance: {:?}", variance);
 ///
 ///     assert_eq!(variance, 3.5);
+///     assert_eq!(hyte::utils::variance(&[1.0]), None);
+/// }
+/// ```
+pub fn variance<Number: ToPrimitive + Copy>(data: &[Number]) -> Option<f64> {
+    data.iter().copied().collect::<RunningStats>().variance()
+}
+
+/// Kinds of observation weights accepted by the weighted statistics and tests.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Weights {
+    /// Each weight counts how many times its observation occurred, so the sample size is the sum of the weights.
+    Frequency,
+    /// Each weight is proportional to the precision of its observation, as with survey or importance weights,
+    /// so the sample size is Kish's effective sample size.
+    Reliability,
+}
+
+/// The sums needed by every weighted statistic.
+struct WeightedSums {
+    total: f64,
+    total_squared: f64,
+    mean: f64,
+    squared_deviations: f64,
+}
+
+fn checked_weights<Weight: ToPrimitive + Copy>(weights: &[Weight]) -> Vec<f64> {
+    let weights: Vec<f64> = weights.iter().map(|&w| to_f64(w)).collect();
+    if weights.iter().any(|&w| !(w >= 0.0 && w.is_finite())) { panic!("\n[HYTE-Panic] Weights must be finite and not negative!\n") };
+    weights
+}
+
+/// Validates `data` and `weights`, and returns `None` if there is no data or every weight is 0.
+fn weighted_sums<Number: ToPrimitive + Copy, Weight: ToPrimitive + Copy>(data: &[Number], weights: &[Weight]) -> Option<WeightedSums> {
+    if data.len() != weights.len() {
+        panic!("\n[HYTE-Panic] The lengths of data ({}) and weights ({}) do not match!\n", data.len(), weights.len());
+    }
+    let weights = checked_weights(weights);
+    let data: Vec<f64> = data.iter().map(|&x| to_f64(x)).collect();
+
+    let total: f64 = weights.iter().sum();
+    if data.is_empty() || total == 0.0 { return None };
+    let mean = data.iter().zip(&weights).map(|(x, w)| w * x).sum::<f64>() / total;
+    Some(WeightedSums {
+        total,
+        total_squared: weights.iter().map(|w| w * w).sum(),
+        mean,
+        squared_deviations: data.iter().zip(&weights).map(|(x, w)| w * (x - mean).powi(2)).sum(),
+    })
+}
+
+impl WeightedSums {
+    fn sample_size(&self, kind: Weights) -> f64 {
+        match kind {
+            Weights::Frequency => self.total,
+            Weights::Reliability => self.total * self.total / self.total_squared,
+        }
+    }
+
+    fn variance(&self, kind: Weights) -> Option<f64> {
+        let denominator = match kind {
+            Weights::Frequency => self.total - 1.0,
+            Weights::Reliability => self.total - self.total_squared / self.total,
+        };
+        if self.sample_size(kind) <= 1.0 || denominator <= 0.0 { return None };
+        Some(self.squared_deviations / denominator)
+    }
+}
+
+/// Computes the weighted mean, sample variance, and sample size of `data` for the `z` and `t` weighted tests.
+pub(crate) fn weighted_summary<Number: ToPrimitive + Copy, Weight: ToPrimitive + Copy>(data: &[Number], weights: &[Weight], kind: Weights) -> Option<(f64, Option<f64>, f64)> {
+    let sums = weighted_sums(data, weights)?;
+    Some((sums.mean, sums.variance(kind), sums.sample_size(kind)))
+}
+
+/// Computes the weighted mean `Σ wᵢxᵢ / Σ wᵢ`. Returns `None` if there are no numbers or every weight is 0.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::weighted_mean;
+///
+/// fn main() {
+///     assert_eq!(weighted_mean(&[1, 2, 3], &[3, 1, 0]), Some(1.25));
+/// }
+/// ```
+pub fn weighted_mean<Number: ToPrimitive + Copy, Weight: ToPrimitive + Copy>(data: &[Number], weights: &[Weight]) -> Option<f64> {
+    Some(weighted_sums(data, weights)?.mean)
+}
+
+/// Computes the weighted sample variance. With `Weights::Frequency`, the divisor is `Σ wᵢ - 1`, matching the
+/// variance of the data with every observation repeated `wᵢ` times. With `Weights::Reliability`, the divisor is
+/// `Σ wᵢ - Σ wᵢ² / Σ wᵢ`, which makes the estimate unbiased whatever the scale of the weights.
+/// Returns `None` if the sample size (see `Weights`) is not greater than 1.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::{variance, weighted_variance, Weights};
+///
+/// fn main() {
+///     let frequency = weighted_variance(&[1, 2, 3], &[2, 1, 1], Weights::Frequency);
+///
+///     assert_eq!(frequency, variance(&[1, 1, 2, 3]));
+///     assert_eq!(weighted_variance(&[1, 2, 3], &[0.5, 0.5, 0.5], Weights::Reliability), Some(1.0));
+/// }
+/// ```
+pub fn weighted_variance<Number: ToPrimitive + Copy, Weight: ToPrimitive + Copy>(data: &[Number], weights: &[Weight], kind: Weights) -> Option<f64> {
+    weighted_sums(data, weights)?.variance(kind)
+}
+
+/// Computes Kish's effective sample size `(Σ wᵢ)² / Σ wᵢ²`, the number of unweighted observations which would
+/// estimate a mean as precisely as the weighted ones. Returns `None` if there are no weights or they are all 0.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::effective_sample_size;
+///
+/// fn main() {
+///     assert_eq!(effective_sample_size(&[1.0, 1.0, 1.0, 1.0]), Some(4.0));
+///     assert_eq!(effective_sample_size(&[3.0, 1.0]), Some(1.6));
+/// }
+/// ```
+pub fn effective_sample_size<Weight: ToPrimitive + Copy>(weights: &[Weight]) -> Option<f64> {
+    let weights = checked_weights(weights);
+    let total: f64 = weights.iter().sum();
+    if total == 0.0 { return None };
+    Some(total * total / weights.iter().map(|w| w * w).sum::<f64>())
+}
+
+/// Collects `data` as `f64` values sorted in ascending order.
+fn sorted_f64<Number: ToPrimitive + Copy>(data: &[Number]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = data.iter().map(|&x| to_f64(x)).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted
+}
+
+/// Computes the second, third, and fourth central moments (with divisor `n`) of `data`.
+pub(crate) fn central_moments(data: &[f64]) -> (f64, f64, f64) {
+    let n = data.len() as f64;
+    let data_mean = mean(data).unwrap();
+    let (mut m2, mut m3, mut m4) = (0.0, 0.0, 0.0);
+    for &x in data {
+        let d = x - data_mean;
+        let d2 = d * d;
+        m2 += d2;
+        m3 += d2 * d;
+        m4 += d2 * d2;
+    }
+    (m2 / n, m3 / n, m4 / n)
+}
+
+/// The nine sample quantile definitions of Hyndman and Fan (1996), numbered as in R's `quantile`.
+///
+/// Types 1 to 3 are discontinuous and always return one of the observations, while types 4 to 9
+/// interpolate linearly between adjacent order statistics.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum QuantileType {
+    /// Inverse of the empirical distribution function.
+    Type1,
+    /// Inverse of the empirical distribution function, averaging at discontinuities.
+    Type2,
+    /// Nearest even order statistic, as in SAS.
+    Type3,
+    /// Linear interpolation of the empirical distribution function.
+    Type4,
+    /// Piecewise linear function whose knots are the midpoints of the steps of the empirical distribution function.
+    Type5,
+    /// Linear interpolation with `p(k) = k / (n + 1)`, as in Minitab and SPSS.
+    Type6,
+    /// Linear interpolation with `p(k) = (k - 1) / (n - 1)`; the default in R, NumPy, and Excel.
+    Type7,
+    /// Approximately median-unbiased regardless of the distribution; recommended by Hyndman and Fan.
+    Type8,
+    /// Approximately unbiased for the expected order statistics of a normal distribution.
+    Type9,
+}
+
+/// Computes the `probability` quantile of data which is already sorted in ascending order.
+pub(crate) fn sorted_quantile(sorted: &[f64], probability: f64, method: QuantileType) -> f64 {
+    if !(0.0..=1.0).contains(&probability) { panic!("\n[HYTE-Panic] Probabilities must be between 0 and 1!\n") };
+    let n = sorted.len() as f64;
+    let fuzz = 4.0 * f64::EPSILON;
+    let (j, h) = match method {
+        QuantileType::Type1 | QuantileType::Type2 | QuantileType::Type3 => {
+            let nppm = if method == QuantileType::Type3 { n * probability - 0.5 } else { n * probability };
+            let j = (nppm + fuzz).floor();
+            let h = match method {
+                QuantileType::Type1 => if nppm > j { 1.0 } else { 0.0 },
+                QuantileType::Type2 => if nppm > j { 1.0 } else { 0.5 },
+                _ => if nppm != j || j.rem_euclid(2.0) == 1.0 { 1.0 } else { 0.0 },
+            };
+            (j, h)
+        }
+        _ => {
+            let (a, b) = match method {
+                QuantileType::Type4 => (0.0, 1.0),
+                QuantileType::Type5 => (0.5, 0.5),
+                QuantileType::Type6 => (0.0, 0.0),
+                QuantileType::Type7 => (1.0, 1.0),
+                QuantileType::Type8 => (1.0 / 3.0, 1.0 / 3.0),
+                _ => (3.0 / 8.0, 3.0 / 8.0),
+            };
+            let nppm = a + probability * (n + 1.0 - a - b);
+            let j = (nppm + fuzz).floor();
+            let h = nppm - j;
+            (j, if h.abs() < fuzz { 0.0 } else { h })
+        }
+    };
+    // Order statistics below the first or above the last are clamped to the extremes
+    let order_statistic = |k: f64| sorted[(k.clamp(1.0, n) - 1.0) as usize];
+    let (lower, upper) = (order_statistic(j), order_statistic(j + 1.0));
+    if h == 0.0 || lower == upper {
+        lower
+    } else if h == 1.0 {
+        upper
+    } else {
+        (1.0 - h) * lower + h * upper
+    }
+}
+
+/// Computes the median of a vector/array of numbers `$[Number]`. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::median;
+///
+/// fn main() {
+///     assert_eq!(median(&[3, 1, 2]), Some(2.0));
+///     assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), Some(2.5));
+/// }
+/// ```
+pub fn median<Number: ToPrimitive + Copy>(data: &[Number]) -> Option<f64> {
+    if data.is_empty() { return None };
+    Some(sorted_quantile(&sorted_f64(data), 0.5, QuantileType::Type7))
+}
+
+/// Computes the `probability` quantile of a vector/array of numbers `$[Number]` using one of the nine
+/// Hyndman-Fan definitions. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::{quantile, QuantileType};
+///
+/// fn main() {
+///     let data: Vec<i32> = (1..=10).collect();
+///
+///     assert_eq!(quantile(&data, 0.25, QuantileType::Type7), Some(3.25));
+///     assert_eq!(quantile(&data, 0.25, QuantileType::Type6), Some(2.75));
+///     assert_eq!(quantile(&data, 0.25, QuantileType::Type1), Some(3.0));
+/// }
+/// ```
+pub fn quantile<Number: ToPrimitive + Copy>(data: &[Number], probability: f64, method: QuantileType) -> Option<f64> {
+    if data.is_empty() { return None };
+    Some(sorted_quantile(&sorted_f64(data), probability, method))
+}
+
+/// Computes several quantiles at once, sorting the data only once. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::{quantiles, QuantileType};
+///
+/// fn main() {
+///     let quartiles = quantiles(&[7, 1, 5, 3, 9], &[0.25, 0.5, 0.75], QuantileType::Type7).unwrap();
+///
+///     assert_eq!(quartiles, vec![3.0, 5.0, 7.0]);
+/// }
+/// ```
+pub fn quantiles<Number: ToPrimitive + Copy>(data: &[Number], probabilities: &[f64], method: QuantileType) -> Option<Vec<f64>> {
+    if data.is_empty() { return None };
+    let sorted = sorted_f64(data);
+    Some(probabilities.iter().map(|&probability| sorted_quantile(&sorted, probability, method)).collect())
+}
+
+/// Computes the interquartile range `Q3 - Q1` using the chosen quantile definition. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::{iqr, QuantileType};
+///
+/// fn main() {
+///     assert_eq!(iqr(&[7, 1, 5, 3, 9], QuantileType::Type7), Some(4.0));
+/// }
+/// ```
+pub fn iqr<Number: ToPrimitive + Copy>(data: &[Number], method: QuantileType) -> Option<f64> {
+    let quartiles = quantiles(data, &[0.25, 0.75], method)?;
+    Some(quartiles[1] - quartiles[0])
+}
+
+/// Computes the median absolute deviation from the median. If `normal_consistent` is `true`, it is scaled by
+/// 1.4826 (as in R's `mad`), making it a consistent estimator of the standard deviation for normal data.
+/// Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::mad;
+///
+/// fn main() {
+///     let data = vec![1, 1, 2, 2, 4, 6, 9];
+///
+///     assert_eq!(mad(&data, false), Some(1.0));
+///     assert_eq!(mad(&data, true), Some(1.4826));
+/// }
+/// ```
+pub fn mad<Number: ToPrimitive + Copy>(data: &[Number], normal_consistent: bool) -> Option<f64> {
+    let centre = median(data)?;
+    let deviations: Vec<f64> = data.iter().map(|&x| (to_f64(x) - centre).abs()).collect();
+    let deviation = median(&deviations)?;
+    Some(if normal_consistent { 1.4826 * deviation } else { deviation })
+}
+
+/// Computes the sample skewness. If `bias_corrected` is `false`, this is `g₁ = m₃ / m₂^(3/2)` from the central
+/// moments, otherwise it is the adjusted Fisher-Pearson coefficient `G₁ = g₁ √(n(n - 1)) / (n - 2)`, as reported
+/// by SAS and Excel. Returns `None` if there are too few numbers (fewer than 3 for `G₁`) or they are all identical.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::skewness;
+///
+/// fn main() {
+///     let data = vec![1, 2, 3, 4, 10];
+///
+///     assert_eq!((skewness(&data, false).unwrap() * 1e4).round() / 1e4, 1.1384);
+///     assert_eq!((skewness(&data, true).unwrap() * 1e4).round() / 1e4, 1.6971);
+/// }
+/// ```
+pub fn skewness<Number: ToPrimitive + Copy>(data: &[Number], bias_corrected: bool) -> Option<f64> {
+    let x: Vec<f64> = data.iter().map(|&v| to_f64(v)).collect();
+    let n = x.len() as f64;
+    if x.len() < if bias_corrected { 3 } else { 1 } { return None };
+    let (m2, m3, _) = central_moments(&x);
+    if m2 == 0.0 { return None };
+    let g1 = m3 / m2.powf(1.5);
+    Some(if bias_corrected { g1 * (n * (n - 1.0)).sqrt() / (n - 2.0) } else { g1 })
+}
+
+/// Computes the sample excess kurtosis. If `bias_corrected` is `false`, this is `g₂ = m₄ / m₂² - 3` from the central
+/// moments, otherwise it is `G₂ = ((n + 1) g₂ + 6)(n - 1) / ((n - 2)(n - 3))`, as reported by SAS and Excel.
+/// Returns `None` if there are too few numbers (fewer than 4 for `G₂`) or they are all identical.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::kurtosis;
+///
+/// fn main() {
+///     let data = vec![1, 2, 3, 4, 10];
+///
+///     assert_eq!((kurtosis(&data, false).unwrap() * 1e4).round() / 1e4, -0.212);
+///     assert_eq!((kurtosis(&data, true).unwrap() * 1e4).round() / 1e4, 3.152);
+/// }
+/// ```
+pub fn kurtosis<Number: ToPrimitive + Copy>(data: &[Number], bias_corrected: bool) -> Option<f64> {
+    let x: Vec<f64> = data.iter().map(|&v| to_f64(v)).collect();
+    let n = x.len() as f64;
+    if x.len() < if bias_corrected { 4 } else { 1 } { return None };
+    let (m2, _, m4) = central_moments(&x);
+    if m2 == 0.0 { return None };
+    let g2 = m4 / (m2 * m2) - 3.0;
+    Some(if bias_corrected { ((n + 1.0) * g2 + 6.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0)) } else { g2 })
+}
+
+/// Number of observations removed from (or replaced at) each end when trimming a `proportion` of `n` observations.
+fn trimmed_count(n: usize, proportion: f64) -> usize {
+    if !(0.0..0.5).contains(&proportion) { panic!("\n[HYTE-Panic] Proportion to trim must be in the interval [0, 0.5)!\n") };
+    (n as f64 * proportion).floor() as usize
+}
+
+/// Computes the mean after discarding the `floor(n × proportion)` smallest and largest numbers, as in R's
+/// `mean(x, trim = proportion)`. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::trimmed_mean;
+///
+/// fn main() {
+///     let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 100];
+///
+///     assert_eq!(trimmed_mean(&data, 0.1), Some(5.5));
+/// }
+/// ```
+pub fn trimmed_mean<Number: ToPrimitive + Copy>(data: &[Number], proportion: f64) -> Option<f64> {
+    let k = trimmed_count(data.len(), proportion);
+    if data.is_empty() { return None };
+    let sorted = sorted_f64(data);
+    mean(&sorted[k..sorted.len() - k])
+}
+
+/// Computes the mean after replacing the `floor(n × proportion)` smallest and largest numbers with the nearest
+/// remaining ones. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::winsorised_mean;
+///
+/// fn main() {
+///     let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 100];
+///
+///     assert_eq!(winsorised_mean(&data, 0.1), Some(5.5));
 /// }
 /// ```
-pub fn variance<Number: Into<f64> + Copy>(data: &[Number]) -> Option<f64> {
-    let len = data.len();
-    if len == 0 {
-        return None;
+pub fn winsorised_mean<Number: ToPrimitive + Copy>(data: &[Number], proportion: f64) -> Option<f64> {
+    let k = trimmed_count(data.len(), proportion);
+    if data.is_empty() { return None };
+    let mut sorted = sorted_f64(data);
+    let n = sorted.len();
+    let (lowest, highest) = (sorted[k], sorted[n - k - 1]);
+    sorted[..k].fill(lowest);
+    sorted[n - k..].fill(highest);
+    mean(&sorted)
+}
+
+/// Computes the geometric mean `exp(mean(ln x))`. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::geometric_mean;
+///
+/// fn main() {
+///     let geometric = geometric_mean(&[1, 10, 100]).unwrap();
+///
+///     assert!((geometric - 10.0).abs() < 1e-12);
+/// }
+/// ```
+pub fn geometric_mean<Number: ToPrimitive + Copy>(data: &[Number]) -> Option<f64> {
+    let logs: Vec<f64> = data.iter().map(|&x| {
+        let x = to_f64(x);
+        if x <= 0.0 { panic!("\n[HYTE-Panic] The geometric mean requires every value to be positive!\n") };
+        x.ln()
+    }).collect();
+    Some(mean(&logs)?.exp())
+}
+
+/// Computes the harmonic mean `n / Σ(1 / x)`. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::harmonic_mean;
+///
+/// fn main() {
+///     assert_eq!(harmonic_mean(&[1, 4, 4]), Some(2.0));
+/// }
+/// ```
+pub fn harmonic_mean<Number: ToPrimitive + Copy>(data: &[Number]) -> Option<f64> {
+    let reciprocals: Vec<f64> = data.iter().map(|&x| {
+        let x = to_f64(x);
+        if x <= 0.0 { panic!("\n[HYTE-Panic] The harmonic mean requires every value to be positive!\n") };
+        1.0 / x
+    }).collect();
+    Some(1.0 / mean(&reciprocals)?)
+}
+
+/// A struct for storing the descriptive statistics returned by `utils::describe`.
+///
+/// Quartiles and the IQR use `QuantileType::Type7`, `mad` is scaled to be consistent for normal data,
+/// `skewness` and `kurtosis` are the bias-corrected `G₁` and `G₂`, and the trimmed and winsorised means
+/// use a proportion of 0.1. Statistics which are undefined for the data, such as the geometric mean
+/// of data with a non-positive value, are `None`.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Description {
+    pub count: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub mean: f64,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub variance: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub sd: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub min: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub q1: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub median: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub q3: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub max: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub iqr: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub mad: f64,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub skewness: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub kurtosis: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub trimmed_mean: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub winsorised_mean: f64,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub geometric_mean: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<crate::utils::non_finite::Float>"))]
+    pub harmonic_mean: Option<f64>,
+}
+
+/// Computes every descriptive statistic in this module at once. Returns `None` if there are no numbers.
+///
+/// # Examples
+/// ```
+/// use hyte::utils::describe;
+///
+/// fn main() {
+///     let summary = describe(&[2, 4, 4, 4, 5, 5, 7, 9]).unwrap();
+///     println!("{:#?}", summary);
+///
+///     assert_eq!(summary.count, 8);
+///     assert_eq!(summary.mean, 5.0);
+///     assert_eq!(summary.median, 4.5);
+///     assert_eq!((summary.min, summary.max), (2.0, 9.0));
+///     assert_eq!(summary.iqr, 1.5);
+/// }
+/// ```
+pub fn describe<Number: ToPrimitive + Copy>(data: &[Number]) -> Option<Description> {
+    if data.is_empty() { return None };
+    let sorted = sorted_f64(data);
+    let stats: RunningStats = sorted.iter().copied().collect();
+    let quartile = |probability| sorted_quantile(&sorted, probability, QuantileType::Type7);
+    let all_positive = sorted[0] > 0.0;
+    Some(Description {
+        count: sorted.len(),
+        mean: stats.mean().unwrap(),
+        variance: stats.variance(),
+        sd: stats.sd(),
+        min: sorted[0],
+        q1: quartile(0.25),
+        median: quartile(0.5),
+        q3: quartile(0.75),
+        max: sorted[sorted.len() - 1],
+        iqr: quartile(0.75) - quartile(0.25),
+        mad: mad(&sorted, true).unwrap(),
+        skewness: skewness(&sorted, true),
+        kurtosis: kurtosis(&sorted, true),
+        trimmed_mean: trimmed_mean(&sorted, 0.1).unwrap(),
+        winsorised_mean: winsorised_mean(&sorted, 0.1).unwrap(),
+        geometric_mean: if all_positive { geometric_mean(&sorted) } else { None },
+        harmonic_mean: if all_positive { harmonic_mean(&sorted) } else { None },
+    })
+}
+
+/// A trait for test results that carry a p-value, allowing them to be passed to utilities such as `multiple::adjust_results`.
+pub trait PValue {
+    /// Returns the p-value of the test result.
+    fn p_value(&self) -> f64;
+}
+
+/// Concludes if a test with p-value `p` should be rejected given a specified significance level.
+pub(crate) fn conclude(p: f64, significance_level: f64) -> Conclusion {
+    if p < significance_level { Conclusion::Reject } else { Conclusion::DoNotReject }
+}
+
+/// Deserialises the `test_type` of a result as the matching name of `TEST_TYPES`, rejecting any other name.
+#[cfg(feature = "serde")]
+pub(crate) fn static_str<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<&'static str, D::Error> {
+    use serde::Deserialize;
+
+    let name = String::deserialize(deserializer)?;
+    TEST_TYPES.iter().find(|&&known| known == name).copied()
+        .ok_or_else(|| serde::de::Error::custom(format!("unknown test type `{}`", name)))
+}
+
+/// Serialises the `f64`s of results and records, which may be infinite or NaN, such as the unbounded end of a
+/// one-sided confidence interval or the statistic of a test run on missing data. JSON has no such numbers, so they
+/// are written as the strings `"-inf"`, `"inf"`, and `"nan"`, while finite values stay numbers.
+///
+/// Fields holding `f64`s, on their own or in an `Option`, `Vec`, pair, or map, use `serde(with = "crate::utils::non_finite")`,
+/// and `schemars(with = ...)` their type with each `f64` replaced by `Float`.
+#[cfg(feature = "serde")]
+pub(crate) mod non_finite {
+    use std::collections::BTreeMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::de::DeserializeOwned;
+
+    /// An `f64` as it is written: a number, or `"-inf"`, `"inf"`, or `"nan"`.
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+    #[serde(untagged)]
+    pub(crate) enum Float {
+        Finite(f64),
+        NonFinite(NonFinite),
+    }
+
+    /// A value with no JSON number.
+    #[derive(Serialize, Deserialize)]
+    #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+    pub(crate) enum NonFinite {
+        #[serde(rename = "-inf")]
+        NegativeInfinity,
+        #[serde(rename = "inf")]
+        Infinity,
+        #[serde(rename = "nan")]
+        NaN,
+    }
+
+    /// A value made of `f64`s, which is written with each of them as a `Float`.
+    pub(crate) trait Floats: Sized {
+        type Written: Serialize + DeserializeOwned;
+
+        fn write(&self) -> Self::Written;
+
+        fn read(written: Self::Written) -> Self;
     }
 
-    let mean = mean(data);
-    let variance = data.iter()
-                       .map(|&value| {
-                           let diff = value.into() - mean.unwrap();
-                           diff * diff
-                       })
-                       .sum::<f64>() / (len - 1) as f64;
+    impl Floats for f64 {
+        type Written = Float;
 
-    Some(variance)
-}
\ No newline at end of file
+        fn write(&self) -> Float {
+            match *self {
+                x if x.is_nan() => Float::NonFinite(NonFinite::NaN),
+                f64::NEG_INFINITY => Float::NonFinite(NonFinite::NegativeInfinity),
+                f64::INFINITY => Float::NonFinite(NonFinite::Infinity),
+                x => Float::Finite(x),
+            }
+        }
+
+        fn read(written: Float) -> f64 {
+            match written {
+                Float::Finite(x) => x,
+                Float::NonFinite(NonFinite::NegativeInfinity) => f64::NEG_INFINITY,
+                Float::NonFinite(NonFinite::Infinity) => f64::INFINITY,
+                Float::NonFinite(NonFinite::NaN) => f64::NAN,
+            }
+        }
+    }
+
+    impl<T: Floats> Floats for Option<T> {
+        type Written = Option<T::Written>;
+
+        fn write(&self) -> Self::Written {
+            self.as_ref().map(T::write)
+        }
+
+        fn read(written: Self::Written) -> Self {
+            written.map(T::read)
+        }
+    }
+
+    impl<T: Floats> Floats for Vec<T> {
+        type Written = Vec<T::Written>;
+
+        fn write(&self) -> Self::Written {
+            self.iter().map(T::write).collect()
+        }
+
+        fn read(written: Self::Written) -> Self {
+            written.into_iter().map(T::read).collect()
+        }
+    }
+
+    impl<A: Floats, B: Floats> Floats for (A, B) {
+        type Written = (A::Written, B::Written);
+
+        fn write(&self) -> Self::Written {
+            (self.0.write(), self.1.write())
+        }
+
+        fn read((a, b): Self::Written) -> Self {
+            (A::read(a), B::read(b))
+        }
+    }
+
+    impl<T: Floats> Floats for BTreeMap<String, T> {
+        type Written = BTreeMap<String, T::Written>;
+
+        fn write(&self) -> Self::Written {
+            self.iter().map(|(name, value)| (name.clone(), value.write())).collect()
+        }
+
+        fn read(written: Self::Written) -> Self {
+            written.into_iter().map(|(name, value)| (name, T::read(value))).collect()
+        }
+    }
+
+    pub(crate) fn serialize<T: Floats, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.write().serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, T: Floats, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::Written::deserialize(deserializer).map(T::read)
+    }
+}
+
+/// P-values below this are recomputed in log space by the `*_tail` functions and exact tests, since they lose
+/// precision and eventually underflow to 0 in linear space.
+pub(crate) const LOG_SPACE_THRESHOLD: f64 = 1e-280;
+
+/// Computes `ln(Σ exp(tᵢ))` for the logarithms `tᵢ` of probabilities without underflow, or negative infinity
+/// if every probability is 0.
+pub(crate) fn ln_sum_exp(terms: impl IntoIterator<Item = f64>) -> f64 {
+    let terms: Vec<f64> = terms.into_iter().collect();
+    let largest = terms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if largest == f64::NEG_INFINITY { return largest };
+    largest + terms.iter().map(|&term| (term - largest).exp()).sum::<f64>().ln()
+}
+
+/// Evaluates the continued fraction `b₀ + a₁ / (b₁ + a₂ / (b₂ + ...))` with the modified Lentz algorithm,
+/// where `term(k)` returns `(aₖ, bₖ)`.
+fn continued_fraction(b0: f64, term: impl Fn(f64) -> (f64, f64)) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut value = if b0 == 0.0 { TINY } else { b0 };
+    let mut c = value;
+    let mut d = 0.0;
+    for k in 1..=1000 {
+        let (a, b) = term(k as f64);
+        d = b + a * d;
+        if d.abs() < TINY { d = TINY };
+        c = b + a / c;
+        if c.abs() < TINY { c = TINY };
+        d = 1.0 / d;
+        let delta = c * d;
+        value *= delta;
+        if (delta - 1.0).abs() < 1e-15 { break };
+    }
+    value
+}
+
+/// Natural logarithm of the regularised incomplete beta function `I_x(a, b)`, for `x < (a + 1) / (a + b + 2)`.
+fn ln_beta_reg(a: f64, b: f64, x: f64) -> f64 {
+    let fraction = continued_fraction(1.0, |j| {
+        let m = (j / 2.0).floor();
+        let numerator = if j % 2.0 == 0.0 { m * (b - m) * x } else { -(a + m) * (a + b + m) * x };
+        (numerator / ((a + j - 1.0) * (a + j)), 1.0)
+    });
+    a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b) - a.ln() - fraction.ln()
+}
+
+/// Natural logarithm of the regularised upper incomplete gamma function `Q(a, x)`, for `x > a + 1`.
+fn ln_gamma_ur(a: f64, x: f64) -> f64 {
+    let fraction = continued_fraction(x + 1.0 - a, |i| (-i * (i - a), x + 2.0 * i + 1.0 - a));
+    -x + a * x.ln() - ln_gamma(a) - fraction.ln()
+}
+
+/// Computes the upper-tail probability `P(Z > z)` of the standard normal distribution, and its natural logarithm.
+pub(crate) fn normal_tail(z: f64) -> (f64, f64) {
+    let p = Normal::new(0.0, 1.0).unwrap().sf(z);
+    if p > LOG_SPACE_THRESHOLD { return (p, p.ln()) };
+    // Laplace's continued fraction P(Z > z) = φ(z) / (z + 1 / (z + 2 / (z + ...)))
+    let fraction = continued_fraction(z, |k| (k, z));
+    (p, -0.5 * z * z - 0.5 * (2.0 * PI).ln() - fraction.ln())
+}
+
+/// Computes the upper-tail probability `P(T > t)` of Student's T distribution with `df` degrees of freedom,
+/// and its natural logarithm.
+pub(crate) fn t_tail(t: f64, df: f64) -> (f64, f64) {
+    // statrs panics on a NaN statistic, which missing data can produce under `MissingPolicy::Propagate`
+    if t.is_nan() { return (f64::NAN, f64::NAN) };
+    let p = StudentsT::new(0.0, 1.0, df).unwrap().sf(t);
+    if p > LOG_SPACE_THRESHOLD || df.is_infinite() { return (p, p.ln()) };
+    // P(T > t) = I_x(df / 2, 1 / 2) / 2 with x = df / (df + t²)
+    (p, ln_beta_reg(df / 2.0, 0.5, df / (df + t * t)) - LN_2)
+}
+
+/// Computes the upper-tail probability of the Chi-squared distribution with `df` degrees of freedom at
+/// `statistic`, and its natural logarithm.
+pub(crate) fn chi_squared_tail(statistic: f64, df: f64) -> (f64, f64) {
+    if statistic.is_nan() { return (f64::NAN, f64::NAN) };
+    let p = ChiSquared::new(df).unwrap().sf(statistic);
+    if p > LOG_SPACE_THRESHOLD { return (p, p.ln()) };
+    (p, ln_gamma_ur(df / 2.0, statistic / 2.0))
+}
+
+/// Computes the upper-tail probability of the F distribution with `df1` and `df2` degrees of freedom at
+/// `statistic`, and its natural logarithm.
+pub(crate) fn f_tail(statistic: f64, df1: f64, df2: f64) -> (f64, f64) {
+    if statistic.is_nan() { return (f64::NAN, f64::NAN) };
+    if statistic <= 0.0 { return (1.0, 0.0) };
+    // P(F > f) = I_x(df2 / 2, df1 / 2) with x = df2 / (df2 + df1 f), which avoids cancellation in 1 - cdf
+    let x = df2 / (df2 + df1 * statistic);
+    let p = beta_reg(df2 / 2.0, df1 / 2.0, x);
+    if p > LOG_SPACE_THRESHOLD { return (p, p.ln()) };
+    (p, ln_beta_reg(df2 / 2.0, df1 / 2.0, x))
+}
+
+/// Computes the p-value and its natural logarithm for a statistic with a distribution symmetric about 0,
+/// given `upper_tail`, a function returning `P(X > x)` and its natural logarithm.
+pub(crate) fn symmetric_p(statistic: f64, tail: &Tails, upper_tail: impl Fn(f64) -> (f64, f64)) -> (f64, f64) {
+    match tail {
+        Tails::LOWER => upper_tail(-statistic),
+        Tails::UPPER => upper_tail(statistic),
+        Tails::BOTH => {
+            // Written without `f64::min`, which would turn a NaN p-value into 1
+            let (p, log_p) = upper_tail(statistic.abs());
+            if p > 0.5 { (1.0, 0.0) } else { (2.0 * p, log_p + LN_2) }
+        }
+    }
+}
+
+/// Ranks `data` from 1 to n, assigning tied values the average of their ranks.
+pub(crate) fn average_ranks(data: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    order.sort_by(|&a, &b| data[a].total_cmp(&data[b]));
+    let mut ranks = vec![0.0; data.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && data[order[end + 1]] == data[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end) as f64 / 2.0 + 1.0;
+        for &i in &order[start..=end] {
+            ranks[i] = rank;
+        }
+        start = end + 1;
+    }
+    ranks
+}
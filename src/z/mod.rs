@@ -1,34 +1,64 @@
 //! Module responsible for all items needed to perform Z-tests.
 
-use statrs::distribution::Normal;
-use statrs::distribution::ContinuousCDF;
-use crate::utils::{Tails, Conclusion, mean, variance};
+use crate::report::{self, Report, Reportable};
+use crate::utils::{Alternative, Conclusion, PValue, MissingPolicy, conclude, Observation, RunningStats, ToPrimitive, Weights, clean, clean_weighted, to_f64, weighted_summary, normal_tail, symmetric_p, tailed, TestType};
 
 /// A struct for storing the resulting test statistic and p-value from Z-tests.
+///
+/// `log_p` is the natural logarithm of the p-value, which remains finite even when `p` underflows to 0.
 #[derive(Debug)]
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ZResult {
-    pub test_type: &'static str,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::utils::static_str"))]
+    pub test_type: TestType,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
     pub statistic: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
     pub p: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::non_finite"))]
+    #[cfg_attr(feature = "schema", schemars(with = "crate::utils::non_finite::Float"))]
+    pub log_p: f64,
+    pub hypotheses: String,
+    pub omitted: usize,
 }
 
-/// Derives observed mean, sample size, and standard deviation from a `Vec<Number>` data,
+/// Lower-tailed, upper-tailed, and two-sided test type names of each Z-test.
+const ONE_SAMPLE: [TestType; 3] = [
+    "One-Sided Z-Test for Mean (Lower-Tailed)",
+    "One-Sided Z-Test for Mean (Upper-Tailed)",
+    "Two-Sided Z-Test for Mean",
+];
+const TWO_SAMPLE: [TestType; 3] = [
+    "(2-Sample) One-Sided Z-Test for Mean (Lower-Tailed)",
+    "(2-Sample) One-Sided Z-Test for Mean (Upper-Tailed)",
+    "(2-Sample) Z-Test for Mean",
+];
+
+/// The name of every test in this module.
+#[cfg(feature = "serde")]
+pub(crate) const TEST_TYPES: &[TestType] = crate::utils::test_types![ONE_SAMPLE, TWO_SAMPLE];
+
+/// Derives observed mean, sample size, and standard deviation from data passed as a `Vec<Number>`, `&Vec<Number>` or `&[Number]`,
 /// then calls `z::test_dataless`.
 /// 
 /// # Examples
 /// 
 /// The following is an example of how you can perform a lower-tailed 1-sample Z-test.
 /// To perform an upper-tailed or 2-sided 1-sample Z-test, simply replace `Tails::LOWER` 
-/// with `Tails::UPPER` or `Tails::BOTH` respectively.
+/// with `Tails::UPPER` or `Tails::BOTH` respectively. An `Alternative` such as `Alternative::less(3.5)`
+/// may be passed in place of `Tails`.
 /// 
 /// ```
 /// use hyte::z;
-/// use hyte::utils::Tails;
+/// use hyte::utils::{Tails, MissingPolicy};
 /// 
 /// fn main() {
 ///     let data = vec![1, 2, 3, 4, 5];
-///     let unwrapped_results = z::test(data, 3.5, Tails::LOWER, true);
+///     let unwrapped_results = z::test(data, 3.5, Tails::LOWER, MissingPolicy::Propagate);
 ///
 ///     assert_ne!(unwrapped_results, None);
 ///  
@@ -38,21 +68,129 @@ pub struct ZResult {
 ///     assert_eq!(results.test_type, "One-Sided Z-Test for Mean (Lower-Tailed)");
 ///     assert_eq!(results.statistic, -0.7071067811865475);
 ///     assert_eq!(results.p, 0.2397500610934768);
+///     assert_eq!(results.hypotheses, "H₀: μ = 3.5 vs H₁: μ < 3.5");
 /// }
 /// ````
 /// 
-pub fn test<Number: Into<f64> + Copy, Number2: Into<f64> + Copy>(data: Vec<Number>, expected_mean: Number2, tail: Tails, print_output: bool) -> Option<ZResult> {
-    if data.len() == 0 { return None };
-    let observed_mean = mean(&data).unwrap();
-    let sample_size = data.len() as u32;
-    let sd = variance(&data).unwrap().sqrt();
-    test_dataless(observed_mean, expected_mean.into(), sample_size, sd, tail, print_output)
+pub fn test<Number: Observation, Number2: ToPrimitive>(data: impl AsRef<[Number]>, expected_mean: Number2, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<ZResult> {
+    let (data, omitted) = clean(data.as_ref(), missing);
+    let stats: RunningStats = data.into_iter().collect();
+    let mut results = test_stats(&stats, expected_mean, alternative)?;
+    results.omitted = omitted;
+    Some(results)
+}
+
+/// Performs the same test as `z::test`, but takes the summaries from a `RunningStats` accumulator instead
+/// of the data itself, so that samples too large to hold in memory can be streamed or merged from chunks.
+/// Returns `None` if the accumulator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::z;
+/// use hyte::utils::{RunningStats, Tails, MissingPolicy};
+///
+/// fn main() {
+///     let mut stats = RunningStats::new();
+///     stats.extend(vec![1, 2, 3, 4, 5]);
+///
+///     let results = z::test_stats(&stats, 3.5, Tails::LOWER).unwrap();
+///
+///     assert_eq!(results, z::test(vec![1, 2, 3, 4, 5], 3.5, Tails::LOWER, MissingPolicy::Propagate).unwrap());
+/// }
+/// ```
+pub fn test_stats<Number: ToPrimitive>(stats: &RunningStats, expected_mean: Number, alternative: impl Into<Alternative>) -> Option<ZResult> {
+    let observed_mean = stats.mean()?;
+    let sd = stats.sd().unwrap_or_else(|| panic!("\n[HYTE-Panic] The standard deviation cannot be estimated from fewer than 2 observations!\n"));
+    if sd == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+    dataless(observed_mean, to_f64(expected_mean), stats.count() as f64, sd, alternative.into())
+}
+
+/// Performs the same test as `z::test` on weighted data, using the weighted mean and standard deviation (see
+/// `utils::weighted_variance`). The sample size is the sum of the weights for `Weights::Frequency`, and Kish's
+/// effective sample size for `Weights::Reliability`. Returns `None` if there is no data or every weight is 0.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::z;
+/// use hyte::utils::{Tails, Weights, MissingPolicy};
+///
+/// fn main() {
+///     let results = z::test_weighted(vec![1, 2, 3, 4, 5], vec![1, 1, 2, 1, 1], Weights::Frequency, 3.5, Tails::LOWER, MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results, z::test(vec![1, 2, 3, 3, 4, 5], 3.5, Tails::LOWER, MissingPolicy::Propagate).unwrap());
+/// }
+/// ```
+pub fn test_weighted<Number: Observation, Weight: ToPrimitive + Copy, Number2: ToPrimitive>(data: impl AsRef<[Number]>, weights: impl AsRef<[Weight]>, kind: Weights, expected_mean: Number2, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<ZResult> {
+    let (data, weights, omitted) = clean_weighted(data.as_ref(), weights.as_ref(), missing);
+    let (observed_mean, variance, sample_size) = weighted_summary(&data, &weights, kind)?;
+    let sd = variance.unwrap_or_else(|| panic!("\n[HYTE-Panic] The standard deviation cannot be estimated from a sample size of 1 or less!\n")).sqrt();
+    if sd == 0.0 { panic!("\n[HYTE-Panic] All values in the data are identical!\n") };
+    let mut results = dataless(observed_mean, to_f64(expected_mean), sample_size, sd, alternative.into())?;
+    results.omitted = omitted;
+    Some(results)
+}
+
+/// Performs a large-sample 2-sample Z-test on the difference between the means of two groups of weighted data,
+/// with standard error `√(s₁²/n₁ + s₂²/n₂)`, where each group's sample size is determined by `kind` as in
+/// `z::test_weighted`. Returns `None` if either group has no data or only zero weights.
+///
+/// # Examples
+///
+/// ```
+/// use hyte::z;
+/// use hyte::utils::{Alternative, Weights, MissingPolicy};
+///
+/// fn main() {
+///     let group1 = vec![20.0, 22.0, 19.0, 20.0, 21.0, 20.0];
+///     let group2 = vec![22.0, 24.0, 23.0, 24.0, 25.0, 23.0];
+///     let weights1 = vec![0.9, 1.1, 1.0, 1.2, 0.8, 1.0];
+///     let weights2 = vec![1.0, 0.7, 1.3, 1.0, 1.1, 0.9];
+///     let results = z::test_two_samples_weighted(group1, weights1, group2, weights2, Weights::Reliability, Alternative::less(0.0), MissingPolicy::Propagate).unwrap();
+///
+///     assert_eq!(results.test_type, "(2-Sample) One-Sided Z-Test for Mean (Lower-Tailed)");
+///     assert!(results.p < 0.001);
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn test_two_samples_weighted<Number: Observation, Weight: ToPrimitive + Copy>(data1: impl AsRef<[Number]>, weights1: impl AsRef<[Weight]>, data2: impl AsRef<[Number]>, weights2: impl AsRef<[Weight]>, kind: Weights, alternative: impl Into<Alternative>, missing: MissingPolicy) -> Option<ZResult> {
+    let (data1, weights1, omitted1) = clean_weighted(data1.as_ref(), weights1.as_ref(), missing);
+    let (data2, weights2, omitted2) = clean_weighted(data2.as_ref(), weights2.as_ref(), missing);
+    let (mean1, variance1, n1) = weighted_summary(&data1, &weights1, kind)?;
+    let (mean2, variance2, n2) = weighted_summary(&data2, &weights2, kind)?;
+    let (variance1, variance2) = match (variance1, variance2) {
+        (Some(variance1), Some(variance2)) => (variance1, variance2),
+        _ => panic!("\n[HYTE-Panic] Each sample must have a sample size greater than 1!\n"),
+    };
+    if variance1 == 0.0 && variance2 == 0.0 { panic!("\n[HYTE-Panic] Both samples have zero variance, so the test statistic is undefined!\n") };
+
+    let alternative = alternative.into().or_null(0.0);
+    let difference = alternative.null_value.unwrap();
+    let statistic = (mean1 - mean2 - difference) / (variance1 / n1 + variance2 / n2).sqrt();
+    let tail = alternative.tails();
+    let (p, log_p) = symmetric_p(statistic, &tail, normal_tail);
+    let test_type = tailed(TWO_SAMPLE, &tail);
+
+    let results = ZResult {
+        test_type,
+        statistic,
+        p,
+        log_p,
+        hypotheses: alternative.statement("μ₁ - μ₂"),
+        omitted: omitted1 + omitted2,
+    };
+
+    Some(results)
 }
 
 /// Calculates Z-score and p-value, given relevant numerical summaries. 
 /// It returns an `Option<ZResult>`, which if successful, can unwrap a
 /// `ZResult` with Z-score and p-value as fields `statistic` and `p` respectively.
 /// 
+/// A `pop_sd` of 0 is accepted as a degenerate case: the statistic is infinite with a p-value of 0 or 1,
+/// or NaN when `observed_mean` equals `expected_mean`.
+/// 
 /// # Examples
 /// 
 /// The following is an example of how you can perform a lower-tailed 1-sample Z-test.
@@ -64,7 +202,7 @@ pub fn test<Number: Into<f64> + Copy, Number2: Into<f64> + Copy>(data: Vec<Numbe
 /// use hyte::utils::Tails;
 ///
 /// fn main() {
-///     let unwrapped_results = z::test_dataless(1.2, 1.0, 30, 0.5, Tails::LOWER, true);
+///     let unwrapped_results = z::test_dataless(1.2, 1.0, 30, 0.5, Tails::LOWER);
 /// 
 ///     assert_ne!(unwrapped_results, None);
 ///    
@@ -77,67 +215,56 @@ pub fn test<Number: Into<f64> + Copy, Number2: Into<f64> + Copy>(data: Vec<Numbe
 ///     println!("{:#?}", results);
 /// }
 /// ```
-pub fn test_dataless<Number: Into<f64> + Copy>(observed_mean: Number, expected_mean: Number, sample_size: u32, pop_sd: Number, tail: Tails, print_output: bool) -> Option<ZResult> {
-    if sample_size == 0 { panic!("\n[HYTE-Panic] Sample size must be greater than 0!\n") };
-    if pop_sd.into() < 0.0 { panic!("\n[HYTE-Panic] Standard deviation must not be a negative number!\n") };
+pub fn test_dataless<Number: ToPrimitive>(observed_mean: Number, expected_mean: Number, sample_size: impl ToPrimitive, pop_sd: Number, alternative: impl Into<Alternative>) -> Option<ZResult> {
+    dataless(to_f64(observed_mean), to_f64(expected_mean), sample_size.to_u64().unwrap_or(0) as f64, to_f64(pop_sd), alternative.into())
+}
+
+fn dataless(observed_mean: f64, expected_mean: f64, sample_size: f64, pop_sd: f64, alternative: Alternative) -> Option<ZResult> {
+    let alternative = alternative.with_null(expected_mean);
+    let tail = alternative.tails();
+    if sample_size <= 0.0 { panic!("\n[HYTE-Panic] Sample size must be greater than 0!\n") };
+    if pop_sd < 0.0 { panic!("\n[HYTE-Panic] Standard deviation must not be a negative number!\n") };
     
-    let statistic: f64 = (observed_mean.into() - expected_mean.into()) / (pop_sd.into() / (sample_size as f64).sqrt());
-    let standard_normal = Normal::new(0.0, 1.0).unwrap();
-    let p: f64;
-    let test_type: &'static str;
-
-    match tail {
-        Tails::LOWER => {
-            p = standard_normal.cdf(statistic);
-            test_type = "One-Sided Z-Test for Mean (Lower-Tailed)";
-        },
-        Tails::UPPER => {
-            p = 1.0 - standard_normal.cdf(statistic);
-            test_type = "One-Sided Z-Test for Mean (Upper-Tailed)";
-        },
-        Tails::BOTH => {
-            p = 2.0 * standard_normal.cdf(-statistic.abs());
-            test_type = "Two-Sided Z-Test for Mean";
-        },
-    }
+    let statistic: f64 = (observed_mean - expected_mean) / (pop_sd / sample_size.sqrt());
+    let (p, log_p) = symmetric_p(statistic, &tail, normal_tail);
+    let test_type = tailed(ONE_SAMPLE, &tail);
 
     let results = ZResult {
         test_type,
         statistic,
         p,
+        log_p,
+        hypotheses: alternative.statement("μ"),
+        omitted: 0,
     };
 
-    if print_output {
-        match tail {
-            Tails::LOWER => println!("\n----------------- HYTE -----------------\n\n{}\n\nZ test statistic = {:.2}\np-value = {:.3e}\n\n----------------- HYTE -----------------\n", results.test_type, results.statistic, results.p),
-            Tails::UPPER => println!("\n----------------- HYTE -----------------\n\n{}\n\nZ test statistic = {:.2}\np-value = {:.3e}\n\n----------------- HYTE -----------------\n", results.test_type, results.statistic, results.p),
-            Tails::BOTH => println!("\n---------- HYTE ----------\n\n{}\n\nZ test statistic = {:.2}\np-value = {:.3e}\n\n---------- HYTE ----------\n", results.test_type, results.statistic, results.p),
-        }
-    }
-
     Some(results)
 }
 
 impl ZResult {
     /// Concludes if a `ZResult` instance should be rejected given a specified significance value, by returning a `Conclusion` variant `Reject` or `DoNotReject`
-    pub fn conclude(&self, significance_level: f64, print_output: bool) -> Conclusion {
-        if self.p < significance_level {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = ({:.3e})\n\np-value < s. l, therefore reject H_0\n\nThere is sufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p, significance_level);};
-            Conclusion::Reject
-        } else {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = ({:.3e})\n\np-value > s. l, therefore do not reject H_0\n\nThere is insufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p, significance_level);};
-            Conclusion::DoNotReject
-        }
+    pub fn conclude(&self, significance_level: f64) -> Conclusion {
+        conclude(self.p, significance_level)
     }
 
     /// Concludes if a `ZResult` instance should be rejected using a significance level of 0.05 by convention, by returning a `Conclusion` variant `Reject` or `DoNotReject`
-    pub fn conclude_by_convention(&self, print_output: bool) -> Conclusion {
-        if self.p < 0.05 {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = 0.5\n\np-value < s. l, therefore reject H_0\n\nThere is sufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p);};
-            Conclusion::Reject
-        } else {
-            if print_output {println!("\n---------------------------- HYTE ----------------------------\n\nStatistical Conclusion\n\np-value = ({:.3e})\nsignificance level = 0.5\n\np-value > s. l, therefore do not reject H_0\n\nThere is insufficient evidence to reject the null hypothesis.\n\n---------------------------- HYTE ----------------------------\n", self.p);};
-            Conclusion::DoNotReject
-        }
+    pub fn conclude_by_convention(&self) -> Conclusion {
+        conclude(self.p, 0.05)
+    }
+}
+
+impl PValue for ZResult {
+    fn p_value(&self) -> f64 {
+        self.p
     }
-}
\ No newline at end of file
+}
+
+impl Reportable for ZResult {
+    fn report(&self) -> Report {
+        Report::new(self.test_type)
+            .hypotheses(&self.hypotheses)
+            .row("Z test statistic", format!("{:.2}", self.statistic))
+            .row("p-value", report::p_value(self.p))
+            .apa(format!("z = {:.2}, {}", self.statistic, report::apa_p(self.p)))
+    }
+}